@@ -0,0 +1,52 @@
+/* Interface C de cache_lru_project (feature `ffi`).
+ *
+ * Cache LRU de tableaux d'octets. Les clés et valeurs passées sont copiées ;
+ * les valeurs retournées par cache_get appartiennent au cache et restent
+ * valides jusqu'au prochain appel le modifiant. Un même cache ne doit pas
+ * être utilisé par plusieurs threads à la fois.
+ *
+ * Construction : cargo rustc --lib --release --features ffi --crate-type cdylib
+ * (ou staticlib). Une erreur interne est signalée par NULL ou -1.
+ */
+#ifndef LRU_CACHE_H
+#define LRU_CACHE_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct LruCache LruCache;
+
+/* Cache de `capacity` entrées ; NULL si `capacity` vaut 0. */
+LruCache *cache_new(size_t capacity);
+
+/* Recharge un cache sauvegardé par cache_save (fichier absent : cache vide).
+ * NULL si le fichier est illisible ou `capacity` vaut 0. */
+LruCache *cache_load(size_t capacity, const char *path);
+
+/* 0 en cas de succès, -1 en cas d'erreur. */
+int cache_put(LruCache *cache, const uint8_t *key, size_t key_len,
+              const uint8_t *value, size_t value_len);
+
+/* 1 et renseigne *value / *value_len si la clé est présente, 0 sinon,
+ * -1 en cas d'erreur. */
+int cache_get(LruCache *cache, const uint8_t *key, size_t key_len,
+              const uint8_t **value, size_t *value_len);
+
+/* 1 si la clé était présente, 0 sinon, -1 en cas d'erreur. */
+int cache_remove(LruCache *cache, const uint8_t *key, size_t key_len);
+
+/* 0 en cas de succès, -1 en cas d'erreur. */
+int cache_save(const LruCache *cache, const char *path);
+
+/* Libère le cache ; NULL est accepté. */
+void cache_free(LruCache *cache);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LRU_CACHE_H */
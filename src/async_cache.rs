@@ -0,0 +1,502 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+
+use crate::cache::{Cache, LruCache};
+use crate::listener::CacheEvent;
+
+/// Verrou asynchrone minimal : attendre le verrou suspend la tâche au lieu
+/// de bloquer le thread de l'exécuteur.
+///
+/// # Architecture
+/// * **Jeton** : `locked` est l'accès exclusif, obtenu de manière asynchrone.
+/// * **Données** : Gardées par un `Mutex` std, jamais contendu puisque seul
+///   le détenteur du jeton le prend.
+/// * **Réveil** : Libérer le jeton réveille toutes les tâches en attente ;
+///   une tâche abandonnée entre-temps ne peut donc pas bloquer les autres.
+struct AsyncMutex<T> {
+    state: Mutex<LockState>,
+    data: Mutex<T>,
+}
+
+struct LockState {
+    locked: bool,
+    waiters: Vec<Waker>,
+}
+
+struct AsyncMutexGuard<'a, T> {
+    // Ordre des champs = ordre de destruction : les données avant le jeton.
+    data: MutexGuard<'a, T>,
+    _token: Token<'a, T>,
+}
+
+struct Token<'a, T>(&'a AsyncMutex<T>);
+
+impl<T> AsyncMutex<T> {
+    fn new(data: T) -> Self {
+        AsyncMutex {
+            state: Mutex::new(LockState {
+                locked: false,
+                waiters: Vec::new(),
+            }),
+            data: Mutex::new(data),
+        }
+    }
+
+    async fn lock(&self) -> AsyncMutexGuard<'_, T> {
+        let token = std::future::poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.locked {
+                state.waiters.push(cx.waker().clone());
+                Poll::Pending
+            } else {
+                state.locked = true;
+                Poll::Ready(Token(self))
+            }
+        })
+        .await;
+        AsyncMutexGuard {
+            data: self.data.lock().unwrap_or_else(PoisonError::into_inner),
+            _token: token,
+        }
+    }
+
+    fn into_inner(self) -> T {
+        self.data.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Drop for Token<'_, T> {
+    fn drop(&mut self) {
+        let mut state = self.0.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.locked = false;
+        for waker in state.waiters.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> std::ops::Deref for AsyncMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data
+    }
+}
+
+impl<T> std::ops::DerefMut for AsyncMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+}
+
+/// État d'un chargement en cours pour une clé.
+enum State<V> {
+    Running(Vec<Waker>),
+    Done(V),
+    /// Le chargement a été annulé (future abandonnée) ou a paniqué.
+    Abandoned,
+}
+
+type Flight<V> = Arc<Mutex<State<V>>>;
+
+/// Version asynchrone et partageable d'un [`LruCache`], pour les services
+/// dont les chargements sont eux-mêmes asynchrones.
+///
+/// Le cache est protégé par un verrou asynchrone, jamais tenu pendant un
+/// chargement. Les chargements simultanés d'une même clé sont dédupliqués :
+/// une seule future d'initialisation s'exécute, les autres tâches attendent
+/// son résultat. Si cette future est abandonnée (annulation, timeout), une
+/// des tâches en attente prend le relais.
+///
+/// Ne dépend d'aucun runtime : fonctionne avec tokio, async-std, etc.
+/// Les futures retournées sont `Send` dès que `K` et `V` le sont.
+pub struct AsyncLruCache<K, V> {
+    inner: AsyncMutex<LruCache<K, V>>,
+    inflight: Mutex<HashMap<K, Flight<V>>>,
+}
+
+impl<K, V> AsyncLruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Crée un cache asynchrone vide.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn new(capacity: usize) -> Self {
+        Self::from_cache(LruCache::new(capacity))
+    }
+
+    /// Partage un cache existant (ex: construit via [`LruCache::builder`]).
+    pub fn from_cache(cache: LruCache<K, V>) -> Self {
+        AsyncLruCache {
+            inner: AsyncMutex::new(cache),
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Retourne un clone de la valeur et la marque comme récente.
+    pub async fn get(&self, key: &K) -> Option<V> {
+        let mut cache = self.inner.lock().await;
+        cache.get(key).cloned()
+    }
+
+    /// Insère ou met à jour une valeur.
+    pub async fn put(&self, key: K, value: V) {
+        let mut cache = self.inner.lock().await;
+        cache.put(key, value);
+    }
+
+    /// Supprime une clé et retourne sa valeur.
+    pub async fn remove(&self, key: &K) -> Option<V> {
+        let mut cache = self.inner.lock().await;
+        cache.remove(key)
+    }
+
+    /// Nombre d'entrées.
+    pub async fn len(&self) -> usize {
+        self.inner.lock().await.len()
+    }
+
+    /// Indique si le cache est vide.
+    pub async fn is_empty(&self) -> bool {
+        self.inner.lock().await.is_empty()
+    }
+
+    /// Retourne la valeur de `key`, en l'initialisant avec la future produite
+    /// par `init` si elle est absente.
+    ///
+    /// Un seul `init` s'exécute à la fois par clé.
+    pub async fn get_or_insert_with<F, Fut>(&self, key: K, init: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let mut init = Some(init);
+        loop {
+            if let Some(value) = self.get(&key).await {
+                return value;
+            }
+            let (flight, leader) = self.join_flight(&key);
+            if !leader {
+                if let Some(value) = (WaitFlight { flight: &flight }).await {
+                    return value;
+                }
+                // Chargement abandonné : on retente, éventuellement en tant que leader.
+                continue;
+            }
+
+            let guard = FlightGuard {
+                cache: self,
+                key: &key,
+                flight: &flight,
+                done: false,
+            };
+            // Un chargement concurrent a pu se terminer entre-temps.
+            let value = match self.get(&key).await {
+                Some(value) => value,
+                None => {
+                    let value = (init.take().expect("initialisation déjà consommée"))().await;
+                    self.put(key.clone(), value.clone()).await;
+                    value
+                }
+            };
+            guard.complete(value.clone());
+            return value;
+        }
+    }
+
+    /// Retourne un flux asynchrone recevant un [`CacheEvent`] pour chaque
+    /// entrée qui quitte le cache (éviction, expiration, remplacement,
+    /// suppression), pour y réagir sans sonder le cache.
+    ///
+    /// Une expiration est publiée quand le cache retire l'entrée : à sa
+    /// lecture, par `purge_expired` ou par la roue temporelle. Le flux
+    /// n'est pas borné et se termine quand le cache est détruit ; un flux
+    /// abandonné n'accumule plus rien. Plusieurs flux peuvent coexister.
+    pub async fn event_stream(&self) -> EventStream<K>
+    where
+        K: Send + 'static,
+        V: 'static,
+    {
+        let queue = Arc::new(Mutex::new(EventQueue {
+            events: VecDeque::new(),
+            waker: None,
+            closed: false,
+        }));
+        let publisher = Publisher(Arc::clone(&queue));
+        let mut cache = self.inner.lock().await;
+        cache.add_event_listener(move |event| publisher.publish(event));
+        EventStream { queue }
+    }
+
+    /// Récupère le cache, une fois qu'il n'est plus partagé.
+    pub fn into_inner(self) -> LruCache<K, V> {
+        self.inner.into_inner()
+    }
+
+    /// Rejoint le chargement en cours pour `key`, ou en devient le leader.
+    fn join_flight(&self, key: &K) -> (Flight<V>, bool) {
+        let mut inflight = self.inflight.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(flight) = inflight.get(key) {
+            return (Arc::clone(flight), false);
+        }
+        let flight = Arc::new(Mutex::new(State::Running(Vec::new())));
+        inflight.insert(key.clone(), Arc::clone(&flight));
+        (flight, true)
+    }
+}
+
+/// Future d'attente du résultat d'un chargement (`None` s'il est abandonné).
+struct WaitFlight<'a, V> {
+    flight: &'a Flight<V>,
+}
+
+impl<V: Clone> Future for WaitFlight<'_, V> {
+    type Output = Option<V>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<V>> {
+        let mut state = self.flight.lock().unwrap_or_else(PoisonError::into_inner);
+        match &mut *state {
+            State::Running(wakers) => {
+                wakers.push(cx.waker().clone());
+                Poll::Pending
+            }
+            State::Done(value) => Poll::Ready(Some(value.clone())),
+            State::Abandoned => Poll::Ready(None),
+        }
+    }
+}
+
+/// Publie le résultat du leader, ou signale l'abandon si sa future est détruite avant.
+struct FlightGuard<'a, K: Hash + Eq, V> {
+    cache: &'a AsyncLruCache<K, V>,
+    key: &'a K,
+    flight: &'a Flight<V>,
+    done: bool,
+}
+
+impl<K: Hash + Eq, V> FlightGuard<'_, K, V> {
+    fn complete(mut self, value: V) {
+        self.finish(State::Done(value));
+        self.done = true;
+    }
+
+    fn finish(&self, outcome: State<V>) {
+        self.cache
+            .inflight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(self.key);
+        let previous = std::mem::replace(
+            &mut *self.flight.lock().unwrap_or_else(PoisonError::into_inner),
+            outcome,
+        );
+        if let State::Running(wakers) = previous {
+            wakers.into_iter().for_each(Waker::wake);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Drop for FlightGuard<'_, K, V> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.finish(State::Abandoned);
+        }
+    }
+}
+
+/// Événements en attente de lecture par un [`EventStream`].
+struct EventQueue<K> {
+    events: VecDeque<CacheEvent<K>>,
+    waker: Option<Waker>,
+    /// Le cache a été détruit : plus aucun événement n'arrivera.
+    closed: bool,
+}
+
+type SharedQueue<K> = Arc<Mutex<EventQueue<K>>>;
+
+fn lock_queue<K>(queue: &SharedQueue<K>) -> MutexGuard<'_, EventQueue<K>> {
+    queue.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Côté cache d'un [`EventStream`], détenu par le listener : sa destruction
+/// avec le cache termine le flux.
+struct Publisher<K>(SharedQueue<K>);
+
+impl<K> Publisher<K> {
+    /// Retourne `false` si le flux a été abandonné.
+    fn publish(&self, event: CacheEvent<K>) -> bool {
+        // Seul détenteur restant : le flux a été abandonné.
+        if Arc::strong_count(&self.0) == 1 {
+            return false;
+        }
+        let mut queue = lock_queue(&self.0);
+        queue.events.push_back(event);
+        if let Some(waker) = queue.waker.take() {
+            waker.wake();
+        }
+        true
+    }
+}
+
+impl<K> Drop for Publisher<K> {
+    fn drop(&mut self) {
+        let mut queue = lock_queue(&self.0);
+        queue.closed = true;
+        if let Some(waker) = queue.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Flux des retraits d'entrées d'un [`AsyncLruCache`] (voir
+/// [`AsyncLruCache::event_stream`]).
+///
+/// `poll_next` a la signature du trait `Stream` de `futures`, dont ce crate
+/// ne dépend pas : un adaptateur d'une ligne suffit pour profiter de
+/// `StreamExt` ou de `tokio_stream`.
+pub struct EventStream<K> {
+    queue: SharedQueue<K>,
+}
+
+impl<K> EventStream<K> {
+    /// Prochain événement, `None` une fois le cache détruit et les
+    /// événements restants consommés.
+    pub async fn next(&mut self) -> Option<CacheEvent<K>> {
+        std::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Version bas niveau de [`next`](Self::next), pour les exécuteurs et adaptateurs.
+    pub fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<CacheEvent<K>>> {
+        let mut queue = lock_queue(&self.queue);
+        if let Some(event) = queue.events.pop_front() {
+            return Poll::Ready(Some(event));
+        }
+        if queue.closed {
+            return Poll::Ready(None);
+        }
+        queue.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+    use std::time::Duration;
+
+    /// Exécuteur minimal : bloque le thread courant jusqu'à la fin de la future.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct Unpark(Thread);
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn test_basic_operations() {
+        let cache = AsyncLruCache::new(2);
+        block_on(async {
+            cache.put("a", 1).await;
+            cache.put("b", 2).await;
+            assert_eq!(cache.get(&"a").await, Some(1));
+            cache.put("c", 3).await;
+            assert_eq!(cache.get(&"b").await, None);
+            assert_eq!(cache.remove(&"a").await, Some(1));
+            assert_eq!(cache.len().await, 1);
+        });
+        assert_send(&cache.get_or_insert_with("k", || async { 0 }));
+    }
+
+    #[test]
+    fn test_concurrent_loads_are_deduplicated() {
+        let cache = Arc::new(AsyncLruCache::new(10));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(8));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (cache, calls, barrier) = (cache.clone(), calls.clone(), barrier.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    block_on(cache.get_or_insert_with("k", || async {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(50));
+                        42
+                    }))
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 42);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_cancelled_load_is_taken_over() {
+        let cache = AsyncLruCache::new(10);
+        {
+            // Le leader reste bloqué sur son chargement, puis est abandonné.
+            let mut leader =
+                std::pin::pin!(cache.get_or_insert_with("k", std::future::pending::<u32>));
+            let mut cx = Context::from_waker(Waker::noop());
+            assert!(leader.as_mut().poll(&mut cx).is_pending());
+        }
+        assert_eq!(block_on(cache.get_or_insert_with("k", || async { 7 })), 7);
+    }
+
+    #[test]
+    fn test_event_stream_reports_removals() {
+        use crate::RemovalCause;
+
+        let cache = Arc::new(AsyncLruCache::new(1));
+        let mut events = block_on(cache.event_stream());
+        let consumer = thread::spawn(move || {
+            block_on(async {
+                let mut causes = Vec::new();
+                while let Some(event) = events.next().await {
+                    causes.push((event.key, event.cause));
+                }
+                causes
+            })
+        });
+        block_on(async {
+            cache.put("a", 1).await;
+            cache.put("a", 2).await;
+            cache.put("b", 3).await;
+            cache.remove(&"b").await;
+        });
+        drop(cache);
+        assert_eq!(
+            consumer.join().unwrap(),
+            [
+                ("a", RemovalCause::Replaced),
+                ("a", RemovalCause::Evicted),
+                ("b", RemovalCause::Explicit),
+            ]
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::{Arc, PoisonError, RwLock, Weak};
+use std::thread;
+use std::time::Duration;
+
+use crate::cache::LruCache;
+
+/// Cache partagé vu depuis le thread de sauvegarde.
+type WeakCache<K, V> = Weak<RwLock<LruCache<K, V>>>;
+
+/// Sauvegarde périodique configurée par
+/// [`auto_save_every`](crate::LruCacheBuilder::auto_save_every).
+pub(crate) struct AutoSave<K, V> {
+    interval: Duration,
+    /// Lance le thread et prépare la sauvegarde finale : capturé là où `K`
+    /// et `V` sont connus `Send + Sync + 'static`.
+    spawn: fn(WeakCache<K, V>, Duration) -> FinalSave,
+}
+
+/// Sauvegarde finale d'un cache à sauvegarde périodique, exécutée à la
+/// destruction de ce guard (tenu par le [`SyncLruCache`](crate::SyncLruCache)).
+///
+/// Le type est effacé : un `Drop` générique sur `LruCache<K, V>` imposerait
+/// à tous les caches que leurs clés et valeurs lui survivent.
+pub(crate) struct FinalSave(Option<Box<dyn FnOnce() + Send + Sync>>);
+
+impl FinalSave {
+    /// Renonce à la sauvegarde finale.
+    pub(crate) fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for FinalSave {
+    fn drop(&mut self) {
+        if let Some(save) = self.0.take() {
+            // Jamais de panique ici : elle interromprait le processus si le
+            // cache est détruit pendant une autre panique.
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(save));
+        }
+    }
+}
+
+impl<K, V> AutoSave<K, V>
+where
+    K: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+    pub(crate) fn new(interval: Duration) -> Self {
+        AutoSave {
+            interval,
+            spawn: |cache, interval| {
+                let last = Weak::clone(&cache);
+                thread::Builder::new()
+                    .name("lru-auto-save".into())
+                    .spawn(move || run(cache, interval))
+                    .expect("Erreur création du thread de sauvegarde");
+                FinalSave(Some(Box::new(move || {
+                    save_if_dirty(&last);
+                })))
+            },
+        }
+    }
+}
+
+impl<K, V> AutoSave<K, V> {
+    /// Démarre la sauvegarde périodique du cache partagé.
+    ///
+    /// Le thread ne garde qu'une référence faible : il s'arrête de lui-même
+    /// au premier réveil suivant la destruction du cache. Le guard retourné
+    /// fait la sauvegarde finale ; il doit être détruit avant le cache.
+    pub(crate) fn start(&self, cache: &Arc<RwLock<LruCache<K, V>>>) -> FinalSave {
+        (self.spawn)(Arc::downgrade(cache), self.interval)
+    }
+}
+
+fn run<K, V>(cache: WeakCache<K, V>, interval: Duration) {
+    loop {
+        thread::sleep(interval);
+        if !save_if_dirty(&cache) {
+            return;
+        }
+    }
+}
+
+/// Sauvegarde le cache s'il a changé. Retourne `false` s'il a été détruit.
+fn save_if_dirty<K, V>(cache: &WeakCache<K, V>) -> bool {
+    let Some(shared) = cache.upgrade() else {
+        return false;
+    };
+    // Instantané sous verrou partagé ; l'écriture sur disque se fait hors
+    // verrou, et sans retenir le cache pour ne pas retarder sa destruction.
+    let snapshot = {
+        let guard = shared.read().unwrap_or_else(PoisonError::into_inner);
+        guard.save_target.as_ref().and_then(|target| target.dirty_snapshot(&guard))
+    };
+    drop(shared);
+    if let Some(snapshot) = snapshot {
+        // Best-effort : la prochaine période retentera en cas d'échec.
+        let _ = snapshot.write();
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{Cache, LruCache, SyncLruCache};
+
+    use super::*;
+
+    #[test]
+    fn test_auto_save_writes_only_when_dirty() {
+        let path = std::env::temp_dir().join(format!("lru_autosave_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        let cache: LruCache<u32, u32> = LruCache::builder(3)
+            .auto_save_every(Duration::from_millis(10))
+            .build_persistent(path)
+            .unwrap();
+        let cache = SyncLruCache::from_cache(cache);
+        cache.put(1, 10);
+        thread::sleep(Duration::from_millis(100));
+        let reloaded: LruCache<u32, u32> = LruCache::new_persistent(3, path).unwrap();
+        assert_eq!(reloaded.to_vec(), vec![(1, 10)]);
+
+        // Cache inchangé : le fichier n'est pas réécrit.
+        fs::remove_file(path).unwrap();
+        cache.get(&1);
+        thread::sleep(Duration::from_millis(50));
+        assert!(fs::metadata(path).is_err());
+
+        // Sauvegarde finale à la destruction.
+        cache.put(2, 20);
+        drop(cache);
+        let reloaded: LruCache<u32, u32> = LruCache::new_persistent(3, path).unwrap();
+        assert_eq!(reloaded.len(), 2);
+        fs::remove_file(path).unwrap();
+    }
+}
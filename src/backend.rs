@@ -0,0 +1,224 @@
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufReader};
+
+use crate::cache::{Cache, LruCache};
+use crate::codec::{DisplayCodec, KeyCodec, ValueCodec};
+use crate::error::CacheError;
+use crate::persistence::{read_text, write_atomically, write_text};
+
+/// Stockage des entrées d'un cache entre deux exécutions (fichier, base
+/// de données, stockage objet, format maison...).
+///
+/// Les entrées sont échangées de la moins récente à la plus récente :
+/// les réinsérer dans cet ordre restaure la récence.
+///
+/// # Exemple : SQLite
+/// Une table unique, alimentée par des UPSERT, avec la crate `rusqlite`
+/// (non incluse) :
+///
+/// ```ignore
+/// use rusqlite::{params, Connection};
+/// use cache_lru_project::PersistenceBackend;
+///
+/// struct SqliteBackend(Connection);
+///
+/// impl SqliteBackend {
+///     fn open(path: &str) -> rusqlite::Result<Self> {
+///         let conn = Connection::open(path)?;
+///         conn.execute_batch(
+///             "CREATE TABLE IF NOT EXISTS cache (
+///                  key TEXT PRIMARY KEY, value TEXT NOT NULL, position INTEGER NOT NULL)",
+///         )?;
+///         Ok(SqliteBackend(conn))
+///     }
+/// }
+///
+/// impl PersistenceBackend<String, String> for SqliteBackend {
+///     type Error = rusqlite::Error;
+///
+///     fn save(&mut self, entries: &[(&String, &String)]) -> rusqlite::Result<()> {
+///         let tx = self.0.transaction()?;
+///         tx.execute("DELETE FROM cache", [])?;
+///         for (position, (key, value)) in entries.iter().enumerate() {
+///             tx.execute(
+///                 "INSERT INTO cache (key, value, position) VALUES (?1, ?2, ?3)
+///                  ON CONFLICT(key) DO UPDATE SET value = ?2, position = ?3",
+///                 params![key, value, position as i64],
+///             )?;
+///         }
+///         tx.commit()
+///     }
+///
+///     fn load(&mut self) -> rusqlite::Result<Vec<(String, String)>> {
+///         let mut stmt = self.0.prepare("SELECT key, value FROM cache ORDER BY position")?;
+///         let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+///         rows.collect()
+///     }
+/// }
+/// ```
+pub trait PersistenceBackend<K, V> {
+    /// Erreur remontée par le stockage.
+    type Error;
+
+    /// Remplace le contenu sauvegardé par `entries`.
+    fn save(&mut self, entries: &[(&K, &V)]) -> Result<(), Self::Error>;
+
+    /// Entrées sauvegardées (vide si rien ne l'a encore été).
+    fn load(&mut self) -> Result<Vec<(K, V)>, Self::Error>;
+}
+
+/// [`PersistenceBackend`] sur un fichier au format texte de
+/// [`LruCache::save_to_file`] : écriture atomique, lecture stricte.
+///
+/// Clés et valeurs sont converties en texte par des codecs : par défaut
+/// [`DisplayCodec`] (`Display` / `FromStr`), remplaçables via
+/// [`with_codecs`](Self::with_codecs) pour d'autres types.
+#[derive(Clone, Debug)]
+pub struct FileBackend<KC = DisplayCodec, VC = DisplayCodec> {
+    path: String,
+    key_codec: KC,
+    value_codec: VC,
+}
+
+impl FileBackend {
+    /// Sauvegarde dans `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self::with_codecs(path, DisplayCodec, DisplayCodec)
+    }
+}
+
+impl<KC, VC> FileBackend<KC, VC> {
+    /// Sauvegarde dans `path`, en encodant clés et valeurs avec les codecs donnés.
+    pub fn with_codecs(path: impl Into<String>, key_codec: KC, value_codec: VC) -> Self {
+        FileBackend {
+            path: path.into(),
+            key_codec,
+            value_codec,
+        }
+    }
+
+    /// Chemin du fichier.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl<K, V, KC, VC> PersistenceBackend<K, V> for FileBackend<KC, VC>
+where
+    KC: KeyCodec<K>,
+    VC: ValueCodec<V>,
+{
+    type Error = CacheError;
+
+    fn save(&mut self, entries: &[(&K, &V)]) -> Result<(), CacheError> {
+        let entries = entries.iter().map(|&(key, value)| (key, value));
+        let codecs = (&self.key_codec, &self.value_codec);
+        write_atomically(&self.path, |file| write_text(file, None, entries.len(), entries, codecs))?;
+        Ok(())
+    }
+
+    fn load(&mut self) -> Result<Vec<(K, V)>, CacheError> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        read_text(
+            |_| Vec::new(),
+            |entries: &mut Vec<(K, V)>, key, value| entries.push((key, value)),
+            BufReader::new(file),
+            (&self.key_codec, &self.value_codec),
+            Err,
+        )
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Sauvegarde les entrées non expirées dans `backend`.
+    pub fn save_to_backend<B: PersistenceBackend<K, V>>(&self, backend: &mut B) -> Result<(), B::Error> {
+        let entries: Vec<(&K, &V)> = self.iter().rev().collect();
+        backend.save(&entries)
+    }
+
+    /// Crée un cache de capacité `capacity` rempli depuis `backend`.
+    ///
+    /// Si le stockage contient plus d'entrées que la capacité, seules les
+    /// plus récentes sont conservées.
+    pub fn from_backend<B: PersistenceBackend<K, V>>(capacity: usize, backend: &mut B) -> Result<Self, B::Error> {
+        let mut cache = LruCache::new(capacity);
+        for (key, value) in backend.load()? {
+            cache.put(key, value);
+        }
+        Ok(cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::codec::HexCodec;
+
+    /// Stockage en mémoire, ordonné par position.
+    #[derive(Default)]
+    struct MemoryBackend(BTreeMap<usize, (String, u32)>);
+
+    impl PersistenceBackend<String, u32> for MemoryBackend {
+        type Error = std::convert::Infallible;
+
+        fn save(&mut self, entries: &[(&String, &u32)]) -> Result<(), Self::Error> {
+            self.0 = entries.iter().map(|(k, v)| ((*k).clone(), **v)).enumerate().collect();
+            Ok(())
+        }
+
+        fn load(&mut self) -> Result<Vec<(String, u32)>, Self::Error> {
+            Ok(self.0.values().cloned().collect())
+        }
+    }
+
+    #[test]
+    fn test_custom_backend_round_trip() {
+        let mut cache = LruCache::new(3);
+        cache.put("a".to_string(), 1);
+        cache.put("b".to_string(), 2);
+        cache.get(&"a".to_string());
+        let mut backend = MemoryBackend::default();
+        cache.save_to_backend(&mut backend).unwrap();
+
+        let Ok(loaded) = LruCache::from_backend(3, &mut backend);
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+    }
+
+    #[test]
+    fn test_file_backend() {
+        let path = std::env::temp_dir().join(format!("lru_backend_{}.txt", std::process::id()));
+        let mut backend = FileBackend::new(path.to_str().unwrap());
+        assert!(LruCache::<u32, String>::from_backend(2, &mut backend).unwrap().is_empty());
+
+        let mut cache = LruCache::new(5);
+        cache.put(1, "un".to_string());
+        cache.put(2, "deux".to_string());
+        cache.put(3, "trois".to_string());
+        cache.save_to_backend(&mut backend).unwrap();
+        let loaded: LruCache<u32, String> = LruCache::from_backend(2, &mut backend).unwrap();
+        assert_eq!(loaded.to_vec(), vec![(2, "deux".to_string()), (3, "trois".to_string())]);
+
+        // Le fichier reste lisible par `new_persistent`.
+        let reloaded: LruCache<u32, String> = LruCache::new_persistent(5, backend.path()).unwrap();
+        assert_eq!(reloaded.to_vec(), cache.to_vec());
+
+        // Des octets, sans `Display` ni `FromStr`.
+        let mut bytes_backend = FileBackend::with_codecs(path.to_str().unwrap(), DisplayCodec, HexCodec);
+        let mut blobs = LruCache::new(2);
+        blobs.put(1u32, vec![0u8, 10, 255]);
+        blobs.save_to_backend(&mut bytes_backend).unwrap();
+        let loaded: LruCache<u32, Vec<u8>> = LruCache::from_backend(2, &mut bytes_backend).unwrap();
+        assert_eq!(loaded.to_vec(), blobs.to_vec());
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,215 @@
+//! Outil d'inspection des fichiers de cache (format texte de `save_to_file`).
+//!
+//! ```text
+//! cache-cli [--capacity N] FICHIER              # session interactive
+//! cache-cli [--capacity N] FICHIER COMMANDE...  # une seule commande
+//! cache-cli simulate TRACE [--policy lru|all] [--capacity 100,1000,...]
+//! ```
+//!
+//! `simulate` rejoue une trace d'accès (une clé par ligne, ou CSV
+//! `horodatage,clé`) et affiche le taux de hits pour chaque capacité ;
+//! `--policy all` compare toutes les politiques.
+//!
+//! La capacité est celle enregistrée dans le fichier, sinon `--capacity`
+//! (100 par défaut). En mode commande unique, le fichier est réécrit si la
+//! commande l'a modifié ; en session, seulement via `save`.
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process;
+
+use cache_lru_project::simulate::{compare_policies, hit_ratio_curve, read_trace};
+use cache_lru_project::{Cache, LruCache, PolicyKind};
+
+const HELP: &str = "\
+get CLÉ           affiche la valeur et la marque comme récente
+peek CLÉ          affiche la valeur sans toucher à la récence
+put CLÉ VALEUR    insère ou remplace (la valeur est le reste de la ligne)
+remove CLÉ        supprime l'entrée
+list              liste les entrées, de la plus ancienne à la plus récente
+stats             affiche le nombre d'entrées et la capacité
+save              réécrit le fichier
+quit              quitte la session";
+
+struct Session {
+    cache: LruCache<String, String>,
+    path: String,
+    /// Modifié depuis le dernier chargement ou la dernière sauvegarde.
+    dirty: bool,
+}
+
+impl Session {
+    fn open(path: &str, capacity: usize) -> Result<Self, String> {
+        let cache = LruCache::load_strict(capacity, path).map_err(|err| format!("{path} : {err}"))?;
+        Ok(Session {
+            cache,
+            path: path.to_string(),
+            dirty: false,
+        })
+    }
+
+    /// Exécute une commande ; retourne `false` pour quitter.
+    fn execute(&mut self, line: &str, out: &mut impl Write) -> io::Result<bool> {
+        let (command, rest) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        match (command, rest) {
+            ("", _) => {}
+            ("get" | "peek", key) if !key.is_empty() => {
+                let value = match command {
+                    "get" => self.cache.get(&key.to_string()),
+                    _ => self.cache.peek(&key.to_string()),
+                };
+                let found = value.is_some();
+                writeln!(out, "{}", value.map_or("(absente)", String::as_str))?;
+                // `get` modifie l'ordre de récence enregistré.
+                self.dirty |= command == "get" && found;
+            }
+            ("put", rest) if rest.contains(' ') => {
+                let (key, value) = rest.split_once(' ').expect("espace présent");
+                self.cache.put(key.to_string(), value.to_string());
+                self.dirty = true;
+            }
+            ("remove", key) if !key.is_empty() => match self.cache.remove(&key.to_string()) {
+                Some(value) => {
+                    writeln!(out, "{value}")?;
+                    self.dirty = true;
+                }
+                None => writeln!(out, "(absente)")?,
+            },
+            ("list", "") => {
+                for (i, (key, value)) in self.cache.iter().rev().enumerate() {
+                    writeln!(out, "{i:>4}  {key} = {value}")?;
+                }
+            }
+            ("stats", "") => writeln!(
+                out,
+                "{} entrées / capacité {}{}",
+                self.cache.len(),
+                self.cache.capacity(),
+                if self.dirty { " (non sauvegardé)" } else { "" }
+            )?,
+            ("save", "") => {
+                self.cache.save_to_file(&self.path)?;
+                self.dirty = false;
+                writeln!(out, "sauvegardé dans {}", self.path)?;
+            }
+            ("quit" | "exit", "") => return Ok(false),
+            ("help", "") => writeln!(out, "{HELP}")?,
+            _ => writeln!(out, "commande invalide (voir `help`)")?,
+        }
+        Ok(true)
+    }
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage : cache-cli [--capacity N] FICHIER [COMMANDE...]\n       \
+         cache-cli simulate TRACE [--policy NOM|all] [--capacity N,N...]\n\n{HELP}"
+    );
+    process::exit(2);
+}
+
+/// Sous-commande `simulate` : rejoue une trace pour chaque capacité demandée.
+fn simulate(args: &[String]) -> io::Result<()> {
+    let Some((trace_path, options)) = args.split_first() else { usage() };
+    let mut policies = vec![PolicyKind::Lru];
+    let mut capacities = vec![100];
+    for option in options.chunks(2) {
+        match option {
+            [flag, value] if flag == "--policy" && value == "all" => policies = PolicyKind::ALL.to_vec(),
+            [flag, value] if flag == "--policy" => {
+                policies = vec![value.parse().unwrap_or_else(|err: String| {
+                    eprintln!("{err}");
+                    process::exit(2);
+                })];
+            }
+            [flag, value] if flag == "--capacity" => {
+                capacities = value
+                    .split(',')
+                    .map(|n| n.trim().parse().ok().filter(|&n| n > 0))
+                    .collect::<Option<_>>()
+                    .unwrap_or_else(|| usage());
+            }
+            _ => usage(),
+        }
+    }
+    let trace = read_trace(BufReader::new(File::open(trace_path)?))?;
+    if let [policy] = policies[..] {
+        for report in hit_ratio_curve(&trace, &capacities, policy) {
+            println!("{report}");
+        }
+    } else {
+        for capacity in capacities {
+            println!("{}", compare_policies(&trace, capacity, &policies));
+        }
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "simulate") {
+        return simulate(&args[1..]);
+    }
+    let mut capacity = 100;
+    if args.first().is_some_and(|arg| arg == "--capacity") {
+        capacity = args.get(1).and_then(|n| n.parse().ok()).filter(|&n| n > 0).unwrap_or_else(|| usage());
+        args.drain(..2);
+    }
+    let Some(path) = args.first().cloned() else { usage() };
+    let mut session = Session::open(&path, capacity).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let mut stdout = io::stdout();
+
+    if args.len() > 1 {
+        session.execute(&args[1..].join(" "), &mut stdout)?;
+        if session.dirty {
+            session.cache.save_to_file(&path)?;
+        }
+        return Ok(());
+    }
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        write!(stdout, "> ")?;
+        stdout.flush()?;
+        let Some(line) = lines.next().transpose()? else { break };
+        if !session.execute(&line, &mut stdout)? {
+            break;
+        }
+    }
+    if session.dirty {
+        eprintln!("modifications non sauvegardées");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_commands() {
+        let path = env::temp_dir().join(format!("lru_cli_{}.txt", process::id()));
+        let path = path.to_str().unwrap();
+        let mut session = Session::open(path, 2).unwrap();
+        let mut out = Vec::new();
+        for line in ["put a 1 2 3", "put b x", "get a", "put c y", "peek b", "list", "stats", "save"] {
+            assert!(session.execute(line, &mut out).unwrap());
+        }
+        assert!(!session.execute("quit", &mut out).unwrap());
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out.lines().take(5).collect::<Vec<_>>(),
+            ["1 2 3", "(absente)", "   0  a = 1 2 3", "   1  c = y", "2 entrées / capacité 2 (non sauvegardé)"]
+        );
+
+        let mut reopened = Session::open(path, 10).unwrap();
+        assert_eq!(reopened.cache.capacity(), 2);
+        assert_eq!(reopened.cache.get(&"c".to_string()).map(String::as_str), Some("y"));
+        std::fs::remove_file(path).unwrap();
+    }
+}
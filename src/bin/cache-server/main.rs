@@ -0,0 +1,216 @@
+//! Serveur de cache : un `SyncLruCache<String, String>` exposé en TCP.
+//!
+//! ```text
+//! cache-server [--addr 127.0.0.1:7878] [--capacity 1000] [--ttl SECONDES]
+//!              [--file CHEMIN] [--save-interval SECONDES]
+//!              [--protocol line|memcached]
+//! ```
+//!
+//! Avec `--protocol memcached`, le serveur parle le protocole texte de
+//! memcached (voir le module `memcached`). Par défaut, protocole ligne par
+//! ligne (réponses terminées par `\n`) :
+//!
+//! | Requête            | Réponse                                  |
+//! |--------------------|------------------------------------------|
+//! | `GET clé`          | `VALUE valeur` ou `NOT_FOUND`            |
+//! | `PUT clé valeur`   | `OK` (la valeur est le reste de la ligne)|
+//! | `DELETE clé`       | `DELETED` ou `NOT_FOUND`                 |
+//! | `STATS`            | `STATS len=… capacity=… hits=… misses=…` |
+//! | `SAVE`             | `OK` (nécessite `--file`)                |
+//! | `QUIT`             | ferme la connexion                       |
+//!
+//! Toute autre requête reçoit `ERROR message`.
+
+use std::env;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::net::TcpListener;
+use std::process;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use cache_lru_project::{LruCache, SyncLruCache};
+
+mod memcached;
+
+type Server = SyncLruCache<String, String>;
+
+struct Config {
+    addr: String,
+    capacity: usize,
+    ttl: Option<Duration>,
+    file: Option<String>,
+    save_interval: Duration,
+    memcached: bool,
+}
+
+impl Config {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut config = Config {
+            addr: "127.0.0.1:7878".to_string(),
+            capacity: 1000,
+            ttl: None,
+            file: None,
+            save_interval: Duration::from_secs(30),
+            memcached: false,
+        };
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or_else(|| format!("valeur manquante pour {flag}"))?;
+            let seconds = || {
+                value
+                    .parse()
+                    .map(Duration::from_secs)
+                    .map_err(|_| format!("{flag} : nombre de secondes attendu"))
+            };
+            match flag.as_str() {
+                "--addr" => config.addr = value.clone(),
+                "--capacity" => {
+                    config.capacity = value
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or("--capacity : entier > 0 attendu")?;
+                }
+                "--ttl" => config.ttl = Some(seconds()?),
+                "--file" => config.file = Some(value.clone()),
+                "--save-interval" => config.save_interval = seconds()?,
+                "--protocol" => {
+                    config.memcached = match value.as_str() {
+                        "line" => false,
+                        "memcached" => true,
+                        _ => return Err("--protocol : line ou memcached attendu".to_string()),
+                    };
+                }
+                _ => return Err(format!("option inconnue : {flag}")),
+            }
+        }
+        Ok(config)
+    }
+
+    fn build_cache(&self) -> io::Result<Server> {
+        let mut builder = LruCache::builder(self.capacity).record_stats();
+        if let Some(ttl) = self.ttl {
+            builder = builder.ttl(ttl);
+        }
+        let cache = match &self.file {
+            Some(file) => builder.auto_save_every(self.save_interval).build_persistent(file)?,
+            None => builder.build(),
+        };
+        Ok(SyncLruCache::from_cache(cache))
+    }
+}
+
+/// Exécute une requête ; `None` demande la fermeture de la connexion.
+fn respond(cache: &Server, line: &str) -> Option<String> {
+    let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let response = match (command.to_ascii_uppercase().as_str(), rest) {
+        ("GET", key) if !key.is_empty() => match cache.get(&key.to_string()) {
+            Some(value) => format!("VALUE {value}"),
+            None => "NOT_FOUND".to_string(),
+        },
+        ("PUT", rest) => match rest.split_once(' ') {
+            Some((key, value)) if !key.is_empty() => {
+                cache.put(key.to_string(), value.to_string());
+                "OK".to_string()
+            }
+            _ => "ERROR usage : PUT clé valeur".to_string(),
+        },
+        ("DELETE", key) if !key.is_empty() => match cache.remove(&key.to_string()) {
+            Some(_) => "DELETED".to_string(),
+            None => "NOT_FOUND".to_string(),
+        },
+        ("STATS", "") => {
+            let stats = cache.read().stats();
+            format!(
+                "STATS len={} capacity={} hits={} misses={} evictions={} expirations={}",
+                cache.len(),
+                cache.capacity(),
+                stats.hits,
+                stats.misses,
+                stats.evictions,
+                stats.expirations
+            )
+        }
+        ("SAVE", "") => match cache.write().save() {
+            Ok(()) => "OK".to_string(),
+            Err(err) => format!("ERROR {err}"),
+        },
+        ("QUIT", "") => return None,
+        _ => format!("ERROR requête invalide : {line}"),
+    };
+    Some(response)
+}
+
+/// Sert une connexion jusqu'à `QUIT` ou sa fermeture.
+fn serve(cache: &Server, reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+    for line in reader.lines() {
+        let line = line?;
+        let Some(response) = respond(cache, line.trim_end_matches('\r')) else {
+            break;
+        };
+        writeln!(writer, "{response}")?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+fn main() {
+    let config = Config::parse(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let cache = Arc::new(config.build_cache().expect("Erreur création cache"));
+    let listener = TcpListener::bind(&config.addr).expect("Erreur ouverture du port");
+    println!("cache-server à l'écoute sur {}", config.addr);
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        let cache = Arc::clone(&cache);
+        let memcached = config.memcached;
+        thread::spawn(move || {
+            let reader = BufReader::new(stream.try_clone()?);
+            let writer = BufWriter::new(stream);
+            if memcached {
+                memcached::serve(&cache, reader, writer)
+            } else {
+                serve(&cache, reader, writer)
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol() {
+        let args = ["--capacity", "2", "--ttl", "60"].map(String::from);
+        let cache = Config::parse(args.into_iter()).unwrap().build_cache().unwrap();
+        let requests = "PUT a valeur avec espaces\r\nPUT b 2\nGET a\nPUT c 3\nGET b\n\
+                        DELETE a\nDELETE a\nSTATS\nSAVE\nBOGUS\nQUIT\nGET c\n";
+        let mut out = Vec::new();
+        serve(&cache, requests.as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            &lines[..7],
+            [
+                "OK",
+                "OK",
+                "VALUE valeur avec espaces",
+                "OK",
+                "NOT_FOUND",
+                "DELETED",
+                "NOT_FOUND"
+            ]
+        );
+        assert_eq!(lines[7], "STATS len=1 capacity=2 hits=1 misses=1 evictions=1 expirations=0");
+        assert!(lines[8].starts_with("ERROR"));
+        assert!(lines[9].starts_with("ERROR requête invalide"));
+        // `QUIT` ferme la connexion : le dernier `GET` n'est pas traité.
+        assert_eq!(lines.len(), 10);
+
+        assert!(Config::parse(["--capacity", "0"].map(String::from).into_iter()).is_err());
+    }
+}
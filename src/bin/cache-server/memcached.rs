@@ -0,0 +1,179 @@
+//! Sous-ensemble du protocole texte de memcached, pour les clients existants.
+//!
+//! Commandes : `get` (plusieurs clés), `set`, `delete`, `flush_all`,
+//! `stats`, `version` et `quit`. Les valeurs doivent être en UTF-8 (le
+//! cache stocke des `String`) et les `flags` ne sont pas conservés : ils
+//! sont toujours relus à 0. Faute de valeur « cas » par entrée, `gets` et
+//! `cas` reçoivent `ERROR`, comme toute commande inconnue.
+
+use std::io::{self, BufRead, Read, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::Server;
+
+/// Taille maximale d'une valeur reçue par `set` (1 Mio, comme memcached).
+const MAX_ITEM_SIZE: usize = 1024 * 1024;
+
+/// Au-delà de 30 jours, `exptime` est une date Unix et non une durée.
+const RELATIVE_EXPTIME_LIMIT: i64 = 60 * 60 * 24 * 30;
+
+/// Durée de vie demandée par `exptime` (`None` = illimitée), ou `Err` si
+/// l'entrée est déjà expirée.
+fn lifetime(exptime: i64) -> Result<Option<Duration>, ()> {
+    let seconds = if exptime > RELATIVE_EXPTIME_LIMIT {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        exptime - now as i64
+    } else {
+        exptime
+    };
+    match (exptime, seconds) {
+        (0, _) => Ok(None),
+        (_, seconds) if seconds > 0 => Ok(Some(Duration::from_secs(seconds as u64))),
+        _ => Err(()),
+    }
+}
+
+/// Sert une connexion memcached jusqu'à `quit` ou sa fermeture.
+pub fn serve(cache: &Server, mut reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(());
+        }
+        let text = String::from_utf8_lossy(&line);
+        let mut words = text.split_ascii_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+        let args: Vec<&str> = words.collect();
+        let noreply = args.last() == Some(&"noreply");
+        let response = match command {
+            "get" if !args.is_empty() => {
+                let mut out = String::new();
+                for key in &args {
+                    if let Some(value) = cache.get(&key.to_string()) {
+                        out.push_str(&format!("VALUE {key} 0 {}\r\n{value}\r\n", value.len()));
+                    }
+                }
+                out + "END"
+            }
+            "set" if args.len() >= 4 => {
+                let (Ok(exptime), Ok(len)) = (args[2].parse::<i64>(), args[3].parse::<usize>()) else {
+                    write!(writer, "CLIENT_ERROR bad command line format\r\n")?;
+                    writer.flush()?;
+                    continue;
+                };
+                let Some(chunk) = len.checked_add(2).filter(|_| len <= MAX_ITEM_SIZE) else {
+                    // Charge utile consommée sans être allouée.
+                    let mut payload = reader.by_ref().take((len as u64).saturating_add(2));
+                    io::copy(&mut payload, &mut io::sink())?;
+                    write!(writer, "SERVER_ERROR object too large for cache\r\n")?;
+                    writer.flush()?;
+                    continue;
+                };
+                let mut data = vec![0; chunk];
+                reader.read_exact(&mut data)?;
+                if !data.ends_with(b"\r\n") {
+                    "CLIENT_ERROR bad data chunk".to_string()
+                } else {
+                    data.truncate(len);
+                    match String::from_utf8(data) {
+                        Err(_) => "CLIENT_ERROR value is not UTF-8".to_string(),
+                        Ok(value) => {
+                            let key = args[0].to_string();
+                            match lifetime(exptime) {
+                                Ok(None) => cache.put(key, value),
+                                Ok(Some(ttl)) => cache.write().put_with_ttl(key, value, ttl),
+                                Err(()) => {
+                                    cache.remove(&key);
+                                }
+                            }
+                            "STORED".to_string()
+                        }
+                    }
+                }
+            }
+            "delete" if !args.is_empty() => match cache.remove(&args[0].to_string()) {
+                Some(_) => "DELETED".to_string(),
+                None => "NOT_FOUND".to_string(),
+            },
+            "flush_all" => {
+                cache.clear();
+                "OK".to_string()
+            }
+            "stats" => {
+                let stats = cache.read().stats();
+                format!(
+                    "STAT pid {}\r\nSTAT curr_items {}\r\nSTAT limit_maxitems {}\r\n\
+                     STAT get_hits {}\r\nSTAT get_misses {}\r\nSTAT evictions {}\r\n\
+                     STAT expired_unfetched {}\r\nEND",
+                    std::process::id(),
+                    cache.len(),
+                    cache.capacity(),
+                    stats.hits,
+                    stats.misses,
+                    stats.evictions,
+                    stats.expirations
+                )
+            }
+            "version" => format!("VERSION {}", env!("CARGO_PKG_VERSION")),
+            "quit" => return Ok(()),
+            _ => "ERROR".to_string(),
+        };
+        if !noreply {
+            write!(writer, "{response}\r\n")?;
+            writer.flush()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+
+    #[test]
+    fn test_memcached_session() {
+        let cache = Config::parse(std::iter::empty()).unwrap().build_cache().unwrap();
+        let requests = "set a 0 0 5\r\nhello\r\nset b 3 60 0 noreply\r\n\r\nset c 0 -1 1\r\nx\r\n\
+                        get a b c\r\ndelete a\r\ndelete a\r\nbogus\r\nflush_all\r\nget b\r\n\
+                        quit\r\nget a\r\n";
+        let mut out = Vec::new();
+        serve(&cache, requests.as_bytes(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "STORED\r\nSTORED\r\nVALUE a 0 5\r\nhello\r\nVALUE b 0 0\r\n\r\nEND\r\n\
+             DELETED\r\nNOT_FOUND\r\nERROR\r\nOK\r\nEND\r\n"
+        );
+    }
+
+    #[test]
+    fn test_memcached_gets_is_unsupported() {
+        let cache = Config::parse(std::iter::empty()).unwrap().build_cache().unwrap();
+        let requests = "set a 0 0 1\r\nx\r\ngets a\r\nget a\r\n";
+        let mut out = Vec::new();
+        serve(&cache, requests.as_bytes(), &mut out).unwrap();
+        // Pas de `VALUE` sans cas unique : le client sait que `gets` manque.
+        assert_eq!(String::from_utf8(out).unwrap(), "STORED\r\nERROR\r\nVALUE a 0 1\r\nx\r\nEND\r\n");
+    }
+
+    #[test]
+    fn test_memcached_rejects_oversized_value() {
+        let cache = Config::parse(std::iter::empty()).unwrap().build_cache().unwrap();
+        let payload = "x".repeat(MAX_ITEM_SIZE + 1);
+        let requests = format!(
+            "set big 0 0 {}\r\n{payload}\r\nset huge 0 0 {}\r\nget big\r\n",
+            payload.len(),
+            usize::MAX
+        );
+        let mut out = Vec::new();
+        serve(&cache, requests.as_bytes(), &mut out).unwrap();
+        // La charge de `huge` avale le reste de la connexion sans allocation.
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "SERVER_ERROR object too large for cache\r\nSERVER_ERROR object too large for cache\r\n"
+        );
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,326 @@
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufReader, ErrorKind, Read, Write};
+
+use crate::cache::{Cache, LruCache};
+use crate::crc::Crc32;
+use crate::error::CacheError;
+use crate::persistence::write_atomically;
+
+/// Signature des fichiers binaires, suivie de la version du format.
+/// Depuis la version 2, le fichier se termine par le CRC-32 (little-endian)
+/// de tout ce qui précède.
+const MAGIC: &[u8; 4] = b"LRUB";
+const VERSION: u8 = 2;
+
+/// Encodage binaire compact d'un type, pour [`LruCache::save_binary`].
+///
+/// Les entiers sont encodés en varint (LEB128, zigzag pour les signés),
+/// les chaînes et séquences sont préfixées par leur longueur.
+pub trait BinaryCodec: Sized {
+    /// Ajoute la représentation de `self` à `out`.
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Lit une valeur au début de `input` et avance au-delà.
+    fn decode(input: &mut &[u8]) -> io::Result<Self>;
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.to_owned())
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    if input.len() < len {
+        return Err(invalid("fichier binaire tronqué"));
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+fn write_varint(mut n: u64, out: &mut Vec<u8>) {
+    while n >= 0x80 {
+        out.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+fn read_varint(input: &mut &[u8]) -> io::Result<u64> {
+    let mut n = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = take(input, 1)?[0];
+        n |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(n);
+        }
+    }
+    Err(invalid("varint trop long"))
+}
+
+fn read_len(input: &mut &[u8]) -> io::Result<usize> {
+    usize::try_from(read_varint(input)?).map_err(|_| invalid("longueur hors limites"))
+}
+
+macro_rules! unsigned_codec {
+    ($($t:ty),*) => {
+        $(impl BinaryCodec for $t {
+            fn encode(&self, out: &mut Vec<u8>) {
+                write_varint(*self as u64, out);
+            }
+
+            fn decode(input: &mut &[u8]) -> io::Result<Self> {
+                <$t>::try_from(read_varint(input)?).map_err(|_| invalid("entier hors limites"))
+            }
+        })*
+    };
+}
+
+macro_rules! signed_codec {
+    ($($t:ty),*) => {
+        $(impl BinaryCodec for $t {
+            fn encode(&self, out: &mut Vec<u8>) {
+                let n = *self as i64;
+                write_varint(((n << 1) ^ (n >> 63)) as u64, out);
+            }
+
+            fn decode(input: &mut &[u8]) -> io::Result<Self> {
+                let n = read_varint(input)?;
+                let n = (n >> 1) as i64 ^ -((n & 1) as i64);
+                <$t>::try_from(n).map_err(|_| invalid("entier hors limites"))
+            }
+        })*
+    };
+}
+
+unsigned_codec!(u8, u16, u32, u64, usize);
+signed_codec!(i8, i16, i32, i64, isize);
+
+impl BinaryCodec for f64 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn decode(input: &mut &[u8]) -> io::Result<Self> {
+        let bytes = take(input, 8)?;
+        Ok(f64::from_le_bytes(bytes.try_into().expect("8 octets")))
+    }
+}
+
+impl BinaryCodec for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(u8::from(*self));
+    }
+
+    fn decode(input: &mut &[u8]) -> io::Result<Self> {
+        match take(input, 1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid("booléen invalide")),
+        }
+    }
+}
+
+impl BinaryCodec for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        write_varint(self.len() as u64, out);
+        out.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(input: &mut &[u8]) -> io::Result<Self> {
+        let len = read_len(input)?;
+        let bytes = take(input, len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| invalid("chaîne UTF-8 invalide"))
+    }
+}
+
+impl<T: BinaryCodec> BinaryCodec for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        write_varint(self.len() as u64, out);
+        for item in self {
+            item.encode(out);
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> io::Result<Self> {
+        let len = read_len(input)?;
+        // Chaque élément occupe au moins un octet : borne l'allocation.
+        let mut items = Vec::with_capacity(len.min(input.len()));
+        for _ in 0..len {
+            items.push(T::decode(input)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<T: BinaryCodec> BinaryCodec for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.encode(out);
+            }
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> io::Result<Self> {
+        match bool::decode(input)? {
+            false => Ok(None),
+            true => T::decode(input).map(Some),
+        }
+    }
+}
+
+impl<A: BinaryCodec, B: BinaryCodec> BinaryCodec for (A, B) {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.0.encode(out);
+        self.1.encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> io::Result<Self> {
+        Ok((A::decode(input)?, B::decode(input)?))
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + BinaryCodec,
+    V: BinaryCodec,
+{
+    /// Sauvegarde le cache dans un fichier binaire compact.
+    ///
+    /// Comme [`save_to_file`](Self::save_to_file), les entrées sont écrites
+    /// du Tail vers le Head (récence préservée) ; la capacité est aussi
+    /// enregistrée. Les entrées expirées sont ignorées. L'écriture est atomique.
+    pub fn save_binary(&self, path: &str) -> io::Result<()> {
+        let mut out = Vec::from(*MAGIC);
+        out.push(VERSION);
+        write_varint(self.capacity() as u64, &mut out);
+        write_varint(self.iter().count() as u64, &mut out);
+        for (key, value) in self.iter().rev() {
+            key.encode(&mut out);
+            value.encode(&mut out);
+        }
+        let mut crc = Crc32::new();
+        crc.update(&out);
+        out.extend_from_slice(&crc.finish().to_le_bytes());
+        write_atomically(path, |file| file.write_all(&out))
+    }
+
+    /// Recharge un cache sauvegardé par [`save_binary`](Self::save_binary),
+    /// avec la capacité enregistrée.
+    ///
+    /// Si le fichier n'existe pas, retourne un cache vide de capacité
+    /// `capacity`. Un fichier illisible ou altéré (somme de contrôle
+    /// différente) est une erreur `ErrorKind::InvalidData`.
+    pub fn load_binary(capacity: usize, path: &str) -> io::Result<Self> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(LruCache::new(capacity)),
+            Err(err) => return Err(err),
+        };
+        let mut bytes = Vec::new();
+        BufReader::new(file).read_to_end(&mut bytes)?;
+        let mut input = bytes.as_slice();
+
+        if take(&mut input, MAGIC.len())? != MAGIC {
+            return Err(invalid("signature de fichier inconnue"));
+        }
+        let version = take(&mut input, 1)?[0];
+        match version {
+            // Version 1 : pas de somme de contrôle.
+            1 => {}
+            VERSION => {
+                let Some(split) = bytes.len().checked_sub(4).filter(|&n| n > MAGIC.len()) else {
+                    return Err(invalid("fichier binaire tronqué"));
+                };
+                let (data, stored) = bytes.split_at(split);
+                let stored = u32::from_le_bytes(stored.try_into().expect("4 octets"));
+                let mut crc = Crc32::new();
+                crc.update(data);
+                if crc.finish() != stored {
+                    return Err(CacheError::Corrupted(format!(
+                        "somme de contrôle {stored:08x} attendue, {:08x} calculée",
+                        crc.finish()
+                    ))
+                    .into());
+                }
+                input = &data[MAGIC.len() + 1..];
+            }
+            _ => return Err(CacheError::UnsupportedVersion(u32::from(version)).into()),
+        }
+        let saved_capacity = read_len(&mut input)?;
+        if saved_capacity == 0 {
+            return Err(invalid("capacité nulle"));
+        }
+        let count = read_len(&mut input)?;
+        let mut cache = LruCache::new(saved_capacity);
+        for _ in 0..count {
+            let key = K::decode(&mut input)?;
+            let value = V::decode(&mut input)?;
+            cache.put(key, value);
+        }
+        if !input.is_empty() {
+            return Err(invalid("données en trop après les entrées"));
+        }
+        Ok(cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: BinaryCodec + PartialEq + std::fmt::Debug>(value: T) {
+        let mut out = Vec::new();
+        value.encode(&mut out);
+        let mut input = out.as_slice();
+        assert_eq!(T::decode(&mut input).unwrap(), value);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_codecs_round_trip() {
+        round_trip(0u64);
+        round_trip(u64::MAX);
+        round_trip(i64::MIN);
+        round_trip(-1i32);
+        round_trip(2.5f64);
+        round_trip(String::from("clé=valeur\nsur deux lignes"));
+        round_trip(vec![0u8, 255, 10]);
+        round_trip(Some((true, -7i16)));
+        round_trip(None::<String>);
+    }
+
+    #[test]
+    fn test_save_and_load_binary() {
+        let path = std::env::temp_dir().join(format!("lru_binary_{}.bin", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut cache = LruCache::new(3);
+        cache.put("a".to_string(), vec![0u8, 1, 2]);
+        cache.put("b=c".to_string(), b"\n\xff".to_vec());
+        cache.put("d".to_string(), vec![]);
+        cache.get(&"a".to_string());
+        cache.save_binary(path).unwrap();
+
+        let mut loaded: LruCache<String, Vec<u8>> = LruCache::load_binary(10, path).unwrap();
+        assert_eq!(loaded.capacity(), 3);
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+        loaded.put("e".to_string(), vec![]);
+        assert!(!loaded.contains(&"b=c".to_string()));
+
+        let mut bytes = std::fs::read(path).unwrap();
+        bytes[7] ^= 1;
+        std::fs::write(path, &bytes).unwrap();
+        let err = LruCache::<String, Vec<u8>>::load_binary(3, path).err().unwrap();
+        assert!(err.to_string().contains("somme de contrôle"));
+
+        std::fs::write(path, b"LRUB\x01\x03\x05").unwrap();
+        let err = LruCache::<String, Vec<u8>>::load_binary(3, path)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,142 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::cache::LruCache;
+
+/// Nombre d'accès mémorisés entre deux écritures.
+const CAPACITY: usize = 128;
+
+/// Tampon d'accès en lecture, alimenté sous `&self` et vidé sous `&mut self`.
+///
+/// # Architecture
+/// * **Enregistrement** : Un `fetch_add` réserve une case, qui reçoit
+///   l'index du nœud et la date de l'accès. Aucun verrou n'est pris.
+/// * **Perte** : Une fois plein, les accès suivants sont ignorés : la
+///   récence devient approximative, jamais incohérente.
+/// * **Validité** : Les index restent valides jusqu'au vidage, car toute
+///   opération qui déplace des nœuds exige `&mut self` et vide le tampon avant.
+pub(crate) struct ReadBuffer {
+    epoch: Instant,
+    len: AtomicUsize,
+    /// `(index, nanos depuis epoch)` de chaque accès.
+    slots: Box<[(AtomicUsize, AtomicU64)]>,
+}
+
+impl ReadBuffer {
+    pub(crate) fn new(epoch: Instant) -> Self {
+        ReadBuffer {
+            epoch,
+            len: AtomicUsize::new(0),
+            slots: (0..CAPACITY)
+                .map(|_| (AtomicUsize::new(0), AtomicU64::new(0)))
+                .collect(),
+        }
+    }
+
+    /// Mémorise un accès au nœud `index` à l'instant `at`.
+    pub(crate) fn record(&self, index: usize, at: Instant) {
+        let slot = self.len.fetch_add(1, Ordering::Relaxed);
+        if let Some((idx, nanos)) = self.slots.get(slot) {
+            let elapsed = at.saturating_duration_since(self.epoch).as_nanos();
+            idx.store(index, Ordering::Relaxed);
+            nanos.store(u64::try_from(elapsed).unwrap_or(u64::MAX), Ordering::Relaxed);
+        }
+    }
+
+    /// Rejoue les accès mémorisés, dans l'ordre, puis vide le tampon.
+    pub(crate) fn drain(&mut self, mut apply: impl FnMut(usize, Instant)) {
+        let len = std::mem::take(self.len.get_mut()).min(CAPACITY);
+        for (idx, nanos) in &mut self.slots[..len] {
+            apply(*idx.get_mut(), self.epoch + Duration::from_nanos(*nanos.get_mut()));
+        }
+    }
+
+    /// Oublie les accès mémorisés (les index ne sont plus valides).
+    pub(crate) fn clear(&mut self) {
+        *self.len.get_mut() = 0;
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Lit une valeur sous `&self`, en différant sa promotion.
+    ///
+    /// Avec [`buffered_reads`](crate::LruCacheBuilder::buffered_reads), l'accès
+    /// est mémorisé puis appliqué à la récence (et au délai d'inactivité) lors
+    /// de la prochaine opération `&mut self`. Plusieurs threads peuvent
+    /// donc lire en parallèle sous un verrou partagé. Sans cette option,
+    /// équivaut à [`peek`](crate::Cache::peek).
+    ///
+    /// Une entrée expirée est vue comme absente, mais pas supprimée.
+    pub fn get_shared<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_shared(key)?;
+        Some(&self.arena[index].value)
+    }
+
+    /// Index d'une entrée non expirée, dont l'accès est mémorisé comme
+    /// pour [`get_shared`](Self::get_shared).
+    pub(crate) fn find_shared<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        let now = self.clock.now();
+        if self.arena[index].is_expired(now, self.tti) {
+            return None;
+        }
+        if let Some(reads) = &self.reads {
+            reads.record(index, now);
+        }
+        Some(index)
+    }
+
+    /// Applique à la liste les lectures partagées mémorisées.
+    pub(crate) fn apply_buffered_reads(&mut self) {
+        let Some(mut reads) = self.reads.take() else {
+            return;
+        };
+        reads.drain(|index, at| {
+            let node = &mut self.arena[index];
+            node.last_access = node.last_access.max(at);
+            node.hits += 1;
+            self.move_to_head(index);
+        });
+        self.reads = Some(reads);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, LruCache};
+
+    #[test]
+    fn test_shared_reads_promote_on_next_write() {
+        let mut cache = LruCache::builder(2).buffered_reads().build();
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.get_shared("a"), Some(&1));
+        // "a" est promu avant l'éviction : c'est "b" qui sort.
+        cache.put("c", 3);
+        assert_eq!(cache.peek(&"a"), Some(&1));
+        assert_eq!(cache.peek(&"b"), None);
+    }
+
+    #[test]
+    fn test_shared_reads_without_buffer_do_not_promote() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.get_shared("a"), Some(&1));
+        cache.put("c", 3);
+        assert_eq!(cache.peek(&"a"), None);
+    }
+}
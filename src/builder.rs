@@ -0,0 +1,387 @@
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::autosave::AutoSave;
+use crate::buffer::ReadBuffer;
+use crate::cache::LruCache;
+use crate::clock::Clock;
+use crate::doorkeeper::Doorkeeper;
+use crate::ghost::GhostTracker;
+use crate::jitter::TtlJitter;
+use crate::memsize::{MemSize, MemoryWeigher};
+use crate::listener::{RemovalCause, RemovalListener};
+use crate::stats::CacheStats;
+use crate::weight::{BoxedWeigher, Weigher};
+
+/// Construit un [`LruCache`] avec des options facultatives.
+///
+/// # Exemple
+/// ```
+/// use std::time::Duration;
+/// use cache_lru_project::LruCache;
+///
+/// let cache: LruCache<String, u32> = LruCache::builder(100)
+///     .ttl(Duration::from_secs(60))
+///     .build();
+/// ```
+pub struct LruCacheBuilder<K, V> {
+    capacity: usize,
+    ttl: Option<Duration>,
+    tti: Option<Duration>,
+    max_lifetime: Option<Duration>,
+    refresh_after: Option<Duration>,
+    buffered_reads: bool,
+    doorkeeper: Option<usize>,
+    ghost_capacity: Option<usize>,
+    lazy_recency: bool,
+    low_watermark: Option<f64>,
+    ttl_jitter: Option<f64>,
+    record_stats: bool,
+    weigher: Option<(u64, BoxedWeigher<K, V>)>,
+    #[cfg(feature = "tracing")]
+    tracer: Option<crate::trace::Tracer<K>>,
+    #[cfg(feature = "metrics")]
+    metrics: Option<crate::metrics::Metrics>,
+    listener: Option<RemovalListener<K, V>>,
+    clock: Option<Arc<dyn Clock>>,
+    #[cfg(feature = "timer-wheel")]
+    sweep_resolution: Option<Duration>,
+    #[cfg(feature = "encryption")]
+    cipher: Option<Arc<dyn crate::encryption::Cipher>>,
+    pub(crate) auto_save: Option<AutoSave<K, V>>,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K, V> LruCacheBuilder<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Démarre la configuration d'un cache de capacité donnée.
+    pub fn new(capacity: usize) -> Self {
+        LruCacheBuilder {
+            capacity,
+            ttl: None,
+            tti: None,
+            max_lifetime: None,
+            refresh_after: None,
+            buffered_reads: false,
+            doorkeeper: None,
+            ghost_capacity: None,
+            lazy_recency: false,
+            low_watermark: None,
+            ttl_jitter: None,
+            record_stats: false,
+            weigher: None,
+            #[cfg(feature = "tracing")]
+            tracer: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            listener: None,
+            clock: None,
+            #[cfg(feature = "timer-wheel")]
+            sweep_resolution: None,
+            #[cfg(feature = "encryption")]
+            cipher: None,
+            auto_save: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Fait expirer les entrées `ttl` après leur dernière écriture.
+    ///
+    /// Les entrées expirées sont traitées comme absentes et supprimées
+    /// paresseusement lors d'un accès.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Tire la durée de vie de chaque écriture uniformément à ±`fraction`
+    /// près (0.1 = ±10 %), TTL global comme `put_with_ttl` : des entrées
+    /// chargées ensemble au démarrage n'expirent plus toutes au même instant.
+    ///
+    /// # Panics
+    /// Panique si `fraction` n'est pas dans `[0, 1]`.
+    pub fn ttl_jitter(mut self, fraction: f64) -> Self {
+        assert!((0.0..=1.0).contains(&fraction), "La fraction doit être entre 0 et 1");
+        self.ttl_jitter = Some(fraction);
+        self
+    }
+
+    /// Borne la vie de chaque entrée à `lifetime` depuis sa première
+    /// insertion : contrairement au [`ttl`](Self::ttl), les mises à jour ne
+    /// la prolongent pas.
+    ///
+    /// Combinable avec [`time_to_idle`](Self::time_to_idle) (sessions :
+    /// durée maximale et délai d'inactivité), comme avec `ttl` et
+    /// `put_with_ttl` : la première échéance atteinte l'emporte.
+    pub fn max_lifetime(mut self, lifetime: Duration) -> Self {
+        self.max_lifetime = Some(lifetime);
+        self
+    }
+
+    /// Fait expirer les entrées restées `idle` sans être lues ni écrites.
+    ///
+    /// Combinable avec [`ttl`](Self::ttl) : l'entrée expire dès que l'un
+    /// des deux seuils est franchi.
+    pub fn time_to_idle(mut self, idle: Duration) -> Self {
+        self.tti = Some(idle);
+        self
+    }
+
+    /// Demande le rechargement des entrées lues plus de `delay` après leur écriture,
+    /// tout en continuant de servir la valeur courante.
+    ///
+    /// Typiquement une fraction du TTL (ex: 80 %) ; voir
+    /// [`get_with_refresh`](LruCache::get_with_refresh).
+    pub fn refresh_after(mut self, delay: Duration) -> Self {
+        self.refresh_after = Some(delay);
+        self
+    }
+
+    /// Permet des lectures concurrentes via [`get_shared`](LruCache::get_shared) :
+    /// les accès sont mémorisés puis appliqués par lots à la prochaine écriture.
+    ///
+    /// Utilisé par [`SyncLruCache`](crate::SyncLruCache) pour lire sous un verrou partagé.
+    pub fn buffered_reads(mut self) -> Self {
+        self.buffered_reads = true;
+        self
+    }
+
+    /// Allège les lectures : un `get` marque seulement l'entrée, sans
+    /// réécrire les liens de la liste. Les entrées marquées sont remises en
+    /// Tête quand elles atteignent la Queue, au moment d'une éviction
+    /// (seconde chance, comme une horloge CLOCK).
+    ///
+    /// L'ordre devient approximatif : une entrée lue récemment reste à sa
+    /// place jusqu'à la prochaine éviction, dans `iter` comme dans les
+    /// sauvegardes. Les écritures promeuvent toujours immédiatement.
+    pub fn lazy_recency(mut self) -> Self {
+        self.lazy_recency = true;
+        self
+    }
+
+    /// Groupe les évictions : quand une insertion trouve le cache plein
+    /// (niveau haut, la capacité), les entrées sont évincées jusqu'à
+    /// redescendre à `fraction` de la capacité (niveau bas, 0.9 = 90 %).
+    /// Les insertions suivantes n'évincent plus rien jusqu'au prochain
+    /// remplissage, ce qui lisse leur latence sous forte charge d'écriture.
+    ///
+    /// Les bornes de poids restent appliquées entrée par entrée.
+    ///
+    /// # Panics
+    /// Panique si `fraction` n'est pas dans `]0, 1]`.
+    pub fn low_watermark(mut self, fraction: f64) -> Self {
+        assert!(fraction > 0.0 && fraction <= 1.0, "Le niveau bas doit être dans ]0, 1]");
+        self.low_watermark = Some(fraction);
+        self
+    }
+
+    /// Filtre les nouvelles clés par un doorkeeper (filtre de Bloom) : une
+    /// clé absente n'est insérée qu'à sa deuxième écriture parmi les `window`
+    /// dernières clés nouvelles. Un balayage de clés vues une seule fois ne
+    /// chasse alors plus les entrées utiles.
+    ///
+    /// Les mises à jour de clés présentes ne sont pas filtrées, ni le
+    /// chargement par [`build_persistent`](Self::build_persistent).
+    pub fn doorkeeper(mut self, window: usize) -> Self {
+        self.doorkeeper = Some(window);
+        self
+    }
+
+    /// Borne le cache par poids total plutôt que par nombre d'entrées : après
+    /// chaque écriture, les entrées sont évincées depuis la Queue tant que la
+    /// somme des poids dépasse `max_weight`. Une entrée plus lourde que
+    /// `max_weight` à elle seule est refusée sans rien évincer.
+    ///
+    /// La capacité en entrées passée à [`new`](Self::new) reste une borne supplémentaire.
+    pub fn max_weight(mut self, max_weight: u64, weigher: impl Weigher<K, V> + 'static) -> Self {
+        self.weigher = Some((max_weight, BoxedWeigher::new(weigher)));
+        self
+    }
+
+    /// Borne le cache par mémoire estimée (voir [`MemSize`]) : les entrées
+    /// sont évincées depuis la Queue tant que leur empreinte totale
+    /// (nœud, index et tas) dépasse `bytes`.
+    ///
+    /// Remplace un éventuel [`max_weight`](Self::max_weight).
+    pub fn max_memory_bytes(self, bytes: u64) -> Self
+    where
+        K: MemSize,
+        V: MemSize,
+    {
+        self.max_weight(bytes, MemoryWeigher)
+    }
+
+    /// Mémorise les empreintes des `size` dernières clés évincées faute de
+    /// place : un miss sur l'une d'elles compte comme hit fantôme
+    /// ([`CacheStats::ghost_hits`]) et alimente [`LruCache::suggested_capacity`].
+    ///
+    /// Les compteurs de [`LruCache::stats`] demandent aussi [`record_stats`](Self::record_stats).
+    pub fn ghost_capacity(mut self, size: usize) -> Self {
+        self.ghost_capacity = Some(size);
+        self
+    }
+
+    /// Active le suivi des statistiques d'activité (voir [`LruCache::stats`]).
+    pub fn record_stats(mut self) -> Self {
+        self.record_stats = true;
+        self
+    }
+
+    /// Publie compteurs (hits, misses, insertions, updates, evictions,
+    /// expirations, ghost_hits) et jauges (size, capacity) vers `recorder`, sous les
+    /// noms `{prefix}_hits`, `{prefix}_size`, etc.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(
+        mut self,
+        recorder: std::sync::Arc<dyn crate::metrics::MetricsRecorder>,
+        prefix: &str,
+    ) -> Self {
+        self.metrics = Some(crate::metrics::Metrics::new(recorder, prefix));
+        self
+    }
+
+    /// Émet un [`TraceEvent`](crate::trace::TraceEvent) vers `sink` pour chaque
+    /// lecture, écriture, éviction et sauvegarde, avec sa durée.
+    #[cfg(feature = "tracing")]
+    pub fn tracer(mut self, sink: std::sync::Arc<dyn crate::trace::TraceSink>) -> Self {
+        self.tracer = Some(crate::trace::Tracer::new(sink));
+        self
+    }
+
+    /// Inclut la représentation `Debug` des clés dans les événements émis
+    /// (désactivé par défaut : les clés peuvent être sensibles).
+    /// Sans effet si aucun [`tracer`](Self::tracer) n'est configuré.
+    #[cfg(feature = "tracing")]
+    pub fn trace_keys(mut self) -> Self
+    where
+        K: std::fmt::Debug,
+    {
+        self.tracer = self.tracer.map(crate::trace::Tracer::with_keys);
+        self
+    }
+
+    /// Enregistre un callback appelé pour chaque entrée qui quitte le cache,
+    /// avec la raison de son départ (voir [`RemovalCause`]).
+    ///
+    /// Le callback s'exécute pendant l'opération qui a retiré l'entrée : il
+    /// doit rester court (ex: pousser dans une file plutôt qu'écrire sur disque).
+    pub fn on_evict(
+        mut self,
+        listener: impl FnMut(&K, &V, RemovalCause) + Send + Sync + 'static,
+    ) -> Self {
+        self.listener = Some(RemovalListener::new(listener));
+        self
+    }
+
+    /// Remplace l'horloge système par une horloge injectée (ex: [`MockClock`](crate::MockClock)).
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Active l'expiration incrémentale : chaque écriture purge aussi une partie
+    /// des entrées échues, repérées par une roue temporelle de pas `resolution`.
+    ///
+    /// Sans cette option, une entrée expirée n'est supprimée que lorsqu'elle
+    /// est relue, évincée, ou via [`purge_expired`](LruCache::purge_expired).
+    #[cfg(feature = "timer-wheel")]
+    pub fn incremental_expiration(mut self, resolution: Duration) -> Self {
+        self.sweep_resolution = Some(resolution);
+        self
+    }
+
+    /// Sauvegarde le cache toutes les `interval` dans le fichier de
+    /// [`build_persistent`](Self::build_persistent), depuis un thread dédié,
+    /// une fois le cache partagé via [`SyncLruCache::from_cache`](crate::SyncLruCache::from_cache).
+    ///
+    /// Un cache non modifié depuis la dernière sauvegarde n'est pas réécrit,
+    /// et une dernière sauvegarde a lieu à sa destruction.
+    /// [`save`](LruCache::save) reste disponible pour sauvegarder à la demande.
+    pub fn auto_save_every(mut self, interval: Duration) -> Self
+    where
+        K: Send + Sync + 'static,
+        V: Send + Sync + 'static,
+    {
+        self.auto_save = Some(AutoSave::new(interval));
+        self
+    }
+
+    /// Chiffre les fichiers écrits par [`save_to_file`](LruCache::save_to_file)
+    /// et déchiffre ceux lus par [`build_persistent`](Self::build_persistent).
+    #[cfg(feature = "encryption")]
+    pub fn cipher(mut self, cipher: impl crate::encryption::Cipher + 'static) -> Self {
+        self.cipher = Some(Arc::new(cipher));
+        self
+    }
+
+    /// Construit le cache.
+    ///
+    /// # Panics
+    /// Panique si la capacité est 0.
+    pub fn build(self) -> LruCache<K, V> {
+        let mut cache = LruCache::new(self.capacity);
+        cache.ttl = self.ttl;
+        cache.tti = self.tti;
+        cache.max_lifetime = self.max_lifetime;
+        cache.refresh_after = self.refresh_after;
+        if let Some(clock) = self.clock {
+            cache.clock = clock;
+        }
+        cache.listener = self.listener;
+        if let Some((max_weight, weigher)) = self.weigher {
+            cache.max_weight = Some(max_weight);
+            cache.weigher = Some(weigher);
+        }
+        #[cfg(feature = "metrics")]
+        {
+            cache.metrics = self.metrics;
+            cache.emit_size();
+        }
+        #[cfg(feature = "tracing")]
+        {
+            cache.tracer = self.tracer;
+        }
+        if self.record_stats {
+            cache.stats = Some(CacheStats::default());
+        }
+        if let Some(window) = self.doorkeeper {
+            cache.doorkeeper = Some(Box::new(Doorkeeper::new(window)));
+        }
+        cache.lazy_recency = self.lazy_recency;
+        cache.low_watermark = self
+            .low_watermark
+            .map(|fraction| (self.capacity as f64 * fraction) as usize);
+        if let Some(fraction) = self.ttl_jitter {
+            cache.jitter = Some(Box::new(TtlJitter::new(fraction)));
+        }
+        if let Some(size) = self.ghost_capacity {
+            cache.ghosts = Some(Box::new(GhostTracker::new(size)));
+        }
+        if self.buffered_reads {
+            cache.reads = Some(Box::new(ReadBuffer::new(cache.clock.now())));
+        }
+        #[cfg(feature = "timer-wheel")]
+        if let Some(resolution) = self.sweep_resolution {
+            cache.sweeper = Some(crate::expiry::Sweeper::new(cache.clock.now(), resolution));
+        }
+        #[cfg(feature = "encryption")]
+        {
+            cache.cipher = self.cipher;
+        }
+        cache
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Démarre la configuration d'un cache via un [`LruCacheBuilder`].
+    pub fn builder(capacity: usize) -> LruCacheBuilder<K, V> {
+        LruCacheBuilder::new(capacity)
+    }
+}
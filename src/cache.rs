@@ -1,16 +1,27 @@
-use std::collections::HashMap;
-use std::fmt::{Debug, Display};
+use std::borrow::Borrow;
+use std::fmt::Debug;
 use std::hash::Hash;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use crate::clock::{Clock, SystemClock};
+use crate::index::KeyIndex;
+use crate::listener::{RemovalCause, RemovalListener};
+use crate::priority::{LevelEnds, Priority};
+use crate::stats::{CacheStats, Stat};
+use crate::weight::BoxedWeigher;
+#[cfg(feature = "tracing")]
+use crate::trace::Operation;
 
 /// Définit le comportement standard d'un Cache.
 ///
 /// Ce trait permet d'interchanger différentes implémentations de cache
 /// (ex: LRU, FIFO, LFU) sans changer le code qui l'utilise.
+///
+/// Le trait est *object-safe* : la construction passe par les constructeurs
+/// inhérents de chaque implémentation, ce qui permet de choisir la politique
+/// à l'exécution via un `Box<dyn Cache<K, V>>`.
 pub trait Cache<K, V> {
-    /// Crée un nouveau cache avec une capacité fixe.
-    fn new(capacity: usize) -> Self;
-
     /// Insère une paire clé-valeur dans le cache.
     /// Si la capacité est atteinte, l'algorithme d'éviction se déclenche.
     fn put(&mut self, key: K, value: V);
@@ -19,8 +30,41 @@ pub trait Cache<K, V> {
     /// Cette action met généralement à jour les métadonnées d'utilisation (ex: récence).
     fn get(&mut self, key: &K) -> Option<&V>;
 
+    /// Consulte une valeur **sans** mettre à jour les métadonnées d'utilisation.
+    fn peek(&self, key: &K) -> Option<&V>;
+
+    /// Indique si la clé est présente (sans effet sur la récence).
+    fn contains(&self, key: &K) -> bool {
+        self.peek(key).is_some()
+    }
+
+    /// Supprime une clé du cache et retourne sa valeur si elle était présente.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Vide entièrement le cache.
+    fn clear(&mut self);
+
     /// Retourne le nombre d'éléments actuellement stockés.
     fn len(&self) -> usize;
+
+    /// Indique si le cache ne contient aucun élément.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Retourne le nombre maximum d'éléments avant éviction.
+    fn capacity(&self) -> usize;
+
+    /// Indique si la prochaine insertion d'une nouvelle clé provoquera une éviction.
+    fn is_full(&self) -> bool {
+        self.len() >= self.capacity()
+    }
+
+    /// Parcourt toutes les entrées, sans effet sur la récence.
+    ///
+    /// L'ordre de parcours dépend de l'implémentation et ne doit pas être
+    /// considéré comme stable par le code générique.
+    fn iter(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_>;
 }
 
 /// Un nœud interne utilisé dans l'Arena (`Vec`).
@@ -35,34 +79,311 @@ pub(crate) struct Node<K, V> {
     pub(crate) prev: Option<usize>,
     /// Index du nœud suivant (plus vieux). `None` si c'est la Queue.
     pub(crate) next: Option<usize>,
+    /// Date au-delà de laquelle l'entrée est considérée comme absente.
+    pub(crate) expires_at: Option<Instant>,
+    /// Date du dernier accès (lecture ou écriture), pour l'expiration par inactivité.
+    pub(crate) last_access: Instant,
+    /// Date de première insertion de la clé.
+    pub(crate) created_at: Instant,
+    /// Nombre de lectures réussies depuis l'insertion.
+    pub(crate) hits: u64,
+    /// Poids de l'entrée (1 sans `Weigher`).
+    pub(crate) weight: u32,
+    /// Date à partir de laquelle une lecture déclenche un rechargement anticipé
+    /// (`None` = pas de rafraîchissement, ou rechargement déjà demandé).
+    pub(crate) refresh_at: Option<Instant>,
+    /// Valeur modifiée pas encore écrite dans le stockage (mode write-behind).
+    pub(crate) dirty: bool,
+    /// Entrée épinglée : jamais choisie comme victime d'éviction.
+    pub(crate) pinned: bool,
+    /// Lue depuis son dernier placement en Tête, promotion différée
+    /// (voir [`lazy_recency`](crate::LruCacheBuilder::lazy_recency)).
+    pub(crate) referenced: bool,
+    /// Niveau de priorité : les plus basses sont évincées en premier.
+    pub(crate) priority: Priority,
+    /// Voisin plus récent dans la liste de son niveau de priorité.
+    pub(crate) level_prev: Option<usize>,
+    /// Voisin plus vieux dans la liste de son niveau de priorité.
+    pub(crate) level_next: Option<usize>,
+    /// Numéro d'insertion, unique dans la vie du cache (voir `EntryHandle`).
+    pub(crate) generation: u64,
+    /// Empreintes des tags de l'entrée (`None` = aucun tag).
+    pub(crate) tags: Option<Box<[u64]>>,
+}
+
+/// Réglages propres à une écriture, en plus de la clé et de la valeur.
+#[derive(Default)]
+pub(crate) struct WriteOptions {
+    /// Date d'expiration de l'entrée (`None` = illimitée).
+    pub(crate) expires_at: Option<Instant>,
+    /// Niveau de priorité face à l'éviction.
+    pub(crate) priority: Priority,
+    /// Empreintes des tags de l'entrée (voir `put_with_tags`).
+    pub(crate) tags: Option<Box<[u64]>>,
+}
+
+impl<K, V> Node<K, V> {
+    /// Indique si l'entrée a expiré, soit par sa date d'expiration,
+    /// soit par une inactivité supérieure à `tti`.
+    pub(crate) fn is_expired(&self, now: Instant, tti: Option<Duration>) -> bool {
+        self.expires_at.is_some_and(|deadline| now >= deadline)
+            || tti.is_some_and(|tti| now.saturating_duration_since(self.last_access) >= tti)
+    }
 }
 
 /// Une implémentation d'un Cache LRU (Least Recently Used).
 ///
 /// # Architecture
 /// Ce cache utilise une approche "Arena" pour maximiser la performance et la localité du cache CPU :
-/// * **HashMap** : Associe `Clé -> Index` (pour un accès O(1)).
-/// * **Vec (Arena)** : Stocke les `Node` de manière contiguë.
+/// * **KeyIndex** : Associe `hash(Clé) -> Index` (pour un accès O(1)), sans dupliquer la clé.
+/// * **Vec (Arena)** : Stocke les `Node` de manière contiguë. C'est le seul propriétaire des clés.
 /// * **Indices** : Utilise des `usize` au lieu de pointeurs pour lier les nœuds.
 ///
-/// 
+/// Chaque clé n'est donc stockée qu'une fois, quelle que soit sa taille.
+/// Pour partager des clés longues avec le reste de l'application (URL...),
+/// utiliser des `Arc<str>` : les méthodes acceptant `Q: ?Sized` (comme
+/// [`handle_of`](Self::handle_of)) se consultent alors avec un simple `&str`.
 pub struct LruCache<K, V> {
     /// Capacité maximale du cache.
     capacity: usize,
     /// Annuaire pour trouver l'index d'une clé en O(1).
-    pub(crate) map: HashMap<K, usize>,
+    pub(crate) index: KeyIndex,
     /// Stockage physique des nœuds.
     pub(crate) arena: Vec<Node<K, V>>,
     /// Index de l'élément le plus récemment utilisé (Tête de liste).
     pub head: Option<usize>,
     /// Index de l'élément le moins récemment utilisé (Queue de liste).
     pub(crate) tail: Option<usize>,
+    /// Durée de vie des entrées à partir de leur écriture (`None` = illimitée).
+    pub(crate) ttl: Option<Duration>,
+    /// Durée d'inactivité au-delà de laquelle une entrée expire (`None` = illimitée).
+    pub(crate) tti: Option<Duration>,
+    /// Durée de vie maximale depuis la première insertion, que les mises à
+    /// jour ne prolongent pas (`None` = illimitée).
+    pub(crate) max_lifetime: Option<Duration>,
+    /// Âge à partir duquel une entrée lue est rechargée par anticipation (`None` = jamais).
+    pub(crate) refresh_after: Option<Duration>,
+    /// Source de temps pour toutes les fonctionnalités temporelles.
+    /// Partagée avec les structures auxiliaires (cache des absences...).
+    pub(crate) clock: Arc<dyn Clock>,
+    /// Entrées modifiées retirées automatiquement avant d'avoir été écrites
+    /// dans le stockage, en attente du prochain `flush` (mode write-behind).
+    pub(crate) evicted_dirty: Vec<(K, V)>,
+    /// Calcul du poids des entrées (`None` = chaque entrée pèse 1).
+    pub(crate) weigher: Option<BoxedWeigher<K, V>>,
+    /// Poids total maximal (`None` = seule la capacité en entrées s'applique).
+    pub(crate) max_weight: Option<u64>,
+    /// Somme des poids des entrées présentes.
+    pub(crate) total_weight: u64,
+    /// Nombre d'entrées présentes par niveau de [`Priority`].
+    pub(crate) priority_counts: [usize; Priority::LEVELS],
+    /// Tête et Queue de la liste de récence de chaque niveau de [`Priority`].
+    pub(crate) levels: [LevelEnds; Priority::LEVELS],
+    /// Compteurs d'activité (`None` = non suivis).
+    pub(crate) stats: Option<CacheStats>,
+    /// Export des métriques vers un recorder externe (`None` = désactivé).
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: Option<crate::metrics::Metrics>,
+    /// Instrumentation des opérations (`None` = désactivée).
+    #[cfg(feature = "tracing")]
+    pub(crate) tracer: Option<crate::trace::Tracer<K>>,
+    /// Callback appelé quand une entrée quitte le cache (`None` = aucun).
+    pub(crate) listener: Option<RemovalListener<K, V>>,
+    /// Accès en lecture partagée pas encore appliqués à la récence (`None` = désactivé).
+    pub(crate) reads: Option<Box<crate::buffer::ReadBuffer>>,
+    /// Clés connues comme absentes (cache négatif), créé au premier `put_negative`.
+    pub(crate) negatives: Option<Box<LruCache<K, ()>>>,
+    /// Expiration incrémentale pilotée par une roue temporelle (`None` = paresseuse).
+    #[cfg(feature = "timer-wheel")]
+    pub(crate) sweeper: Option<crate::expiry::Sweeper>,
+    /// Nombre de modifications du contenu, pour ne sauvegarder qu'un cache modifié.
+    pub(crate) changes: u64,
+    /// Numéro attribué à la prochaine entrée insérée.
+    pub(crate) next_generation: u64,
+    /// Filtre d'admission des nouvelles clés (`None` = toutes admises).
+    pub(crate) doorkeeper: Option<Box<crate::doorkeeper::Doorkeeper>>,
+    /// Lectures marquées sur le nœud, réordonnées seulement à l'éviction.
+    pub(crate) lazy_recency: bool,
+    /// Nombre d'entrées visé par une éviction groupée (`None` = une à la fois).
+    pub(crate) low_watermark: Option<usize>,
+    /// Étalement aléatoire des durées de vie (`None` = TTL exacts).
+    pub(crate) jitter: Option<Box<crate::jitter::TtlJitter>>,
+    /// Clés récemment évincées, pour compter les hits fantômes (`None` = non suivies).
+    pub(crate) ghosts: Option<Box<crate::ghost::GhostTracker>>,
+    /// Dernière valeur de `get_or_insert_with_ttl` que le cache ne pouvait
+    /// pas garder, conservée pour en retourner une référence.
+    pub(crate) uncached: Option<V>,
+    /// Fichier associé par `new_persistent` (`None` = aucun).
+    pub(crate) save_target: Option<Box<crate::persistence::SaveTarget<K, V>>>,
+    /// Chiffrement des fichiers de sauvegarde (`None` = texte clair).
+    #[cfg(feature = "encryption")]
+    pub(crate) cipher: Option<Arc<dyn crate::encryption::Cipher>>,
+}
+
+/// Itérateur sur les entrées d'un [`LruCache`], dans l'ordre de récence.
+///
+/// Les entrées expirées (mais pas encore purgées) sont ignorées.
+pub struct Iter<'a, K, V> {
+    arena: &'a [Node<K, V>],
+    front: Option<usize>,
+    back: Option<usize>,
+    remaining: usize,
+    now: Instant,
+    tti: Option<Duration>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let node = &self.arena[self.front?];
+            self.front = node.next;
+            self.remaining -= 1;
+            if !node.is_expired(self.now, self.tti) {
+                return Some((&node.key, &node.value));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let node = &self.arena[self.back?];
+            self.back = node.prev;
+            self.remaining -= 1;
+            if !node.is_expired(self.now, self.tti) {
+                return Some((&node.key, &node.value));
+            }
+        }
+        None
+    }
 }
 
 impl<K, V> Cache<K, V> for LruCache<K, V>
 where
-    K: Hash + Eq + Clone + Debug,
-    V: Debug,
+    K: Hash + Eq,
+{
+    /// Récupère une valeur.
+    ///
+    /// # Effets de bord
+    /// Si la clé est trouvée, l'élément est déplacé en **Tête** de liste
+    /// (marqué comme le plus récent). Une entrée expirée est supprimée
+    /// et traitée comme un échec ; sinon, son délai d'inactivité repart à zéro.
+    ///
+    /// # Complexité
+    /// O(1)
+    fn get(&mut self, key: &K) -> Option<&V> {
+        #[cfg(feature = "tracing")]
+        let started = self.trace_start();
+        let index = self.find_live(key);
+        #[cfg(feature = "tracing")]
+        self.trace(Operation::Get { hit: index.is_some() }, Some(key), started);
+        let index = index?;
+        self.promote(index);
+        Some(&self.arena[index].value)
+    }
+
+    /// Insère ou met à jour une valeur.
+    ///
+    /// * Si la clé existe : met à jour la valeur et déplace en Tête.
+    /// * Si la clé n'existe pas :
+    ///     * Si plein : supprime le LRU (Tail).
+    ///     * Insère le nouvel élément en Tête.
+    ///
+    /// Dans les deux cas, la date d'expiration est recalculée depuis le TTL.
+    /// La clé est déplacée dans l'arena : aucun clonage n'est nécessaire.
+    ///
+    /// # Complexité
+    /// O(1) amorti (grâce au `swap_remove` sur le vecteur).
+    fn put(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+
+    /// Consulte une valeur sans la promouvoir. Une entrée expirée est vue comme absente.
+    fn peek(&self, key: &K) -> Option<&V> {
+        let index = self.find(key)?;
+        let node = &self.arena[index];
+        (!node.is_expired(self.clock.now(), self.tti)).then_some(&node.value)
+    }
+
+    /// Supprime une clé et retourne sa valeur.
+    ///
+    /// # Complexité
+    /// O(1) (même stratégie `swap_remove` que l'éviction).
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.pop_entry(key).map(|(_, value)| value)
+    }
+
+    /// Vide le cache en conservant la capacité allouée (et la configuration).
+    fn clear(&mut self) {
+        if let Some(listener) = self.listener.as_mut() {
+            for node in &self.arena {
+                listener.call(&node.key, &node.value, RemovalCause::Explicit);
+            }
+        }
+        self.index.clear();
+        self.arena.clear();
+        self.total_weight = 0;
+        self.priority_counts = [0; Priority::LEVELS];
+        self.levels = [LevelEnds::default(); Priority::LEVELS];
+        self.uncached = None;
+        self.head = None;
+        self.tail = None;
+        self.changes += 1;
+        self.evicted_dirty.clear();
+        #[cfg(feature = "metrics")]
+        self.emit_size();
+        if let Some(reads) = self.reads.as_mut() {
+            reads.clear();
+        }
+        if let Some(negatives) = self.negatives.as_mut() {
+            negatives.clear();
+        }
+        #[cfg(feature = "timer-wheel")]
+        if let Some(sweeper) = self.sweeper.as_mut() {
+            sweeper.clear();
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Parcourt les entrées du plus récent (Head) au plus ancien (Tail).
+    fn iter(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        Box::new(LruCache::iter(self))
+    }
+}
+
+/// Deux caches sont égaux s'ils contiennent les mêmes paires clé → valeur
+/// non expirées, dans le même ordre de récence. La configuration
+/// (capacité, TTL...) et les métadonnées des entrées ne sont pas comparées.
+impl<K, V> PartialEq for LruCache<K, V>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Hash + Eq, V: Eq> Eq for LruCache<K, V> {}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
 {
     /// Crée un nouveau Cache LRU vide.
     ///
@@ -71,64 +392,297 @@ where
     ///
     /// # Panics
     /// Panique si `capacity` est 0.
-    fn new(capacity: usize) -> Self {
+    pub fn new(capacity: usize) -> Self {
         assert!(capacity > 0, "La capacité doit être > 0");
         LruCache {
             capacity,
-            map: HashMap::with_capacity(capacity),
+            index: KeyIndex::with_capacity(capacity),
             arena: Vec::with_capacity(capacity),
             head: None,
             tail: None,
+            ttl: None,
+            tti: None,
+            max_lifetime: None,
+            refresh_after: None,
+            clock: Arc::new(SystemClock),
+            evicted_dirty: Vec::new(),
+            reads: None,
+            listener: None,
+            weigher: None,
+            max_weight: None,
+            total_weight: 0,
+            priority_counts: [0; Priority::LEVELS],
+            levels: [LevelEnds::default(); Priority::LEVELS],
+            stats: None,
+            #[cfg(feature = "tracing")]
+            tracer: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            negatives: None,
+            #[cfg(feature = "timer-wheel")]
+            sweeper: None,
+            changes: 0,
+            next_generation: 0,
+            doorkeeper: None,
+            lazy_recency: false,
+            low_watermark: None,
+            jitter: None,
+            ghosts: None,
+            uncached: None,
+            save_target: None,
+            #[cfg(feature = "encryption")]
+            cipher: None,
         }
     }
 
-    /// Récupère une valeur.
+    /// Itérateur sur les entrées, du plus récent (Head) au plus ancien (Tail).
     ///
-    /// # Effets de bord
-    /// Si la clé est trouvée, l'élément est déplacé en **Tête** de liste
-    /// (marqué comme le plus récent).
-    ///
-    /// # Complexité
-    /// O(1)
-    fn get(&mut self, key: &K) -> Option<&V> {
-        if let Some(&index) = self.map.get(key) {
-            self.move_to_head(index);
-            return Some(&self.arena[index].value);
+    /// Utiliser `.rev()` pour parcourir du plus ancien au plus récent.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            arena: &self.arena,
+            front: self.head,
+            back: self.tail,
+            remaining: self.arena.len(),
+            now: self.clock.now(),
+            tti: self.tti,
         }
-        None
     }
 
-    /// Insère ou met à jour une valeur.
+    /// Durée de vie configurée pour les entrées (expiration après écriture).
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Durée de vie maximale configurée (expiration après la première insertion).
+    pub fn max_lifetime(&self) -> Option<Duration> {
+        self.max_lifetime
+    }
+
+    /// Délai d'inactivité configuré (expiration après le dernier accès).
+    pub fn time_to_idle(&self) -> Option<Duration> {
+        self.tti
+    }
+
+    /// Comme [`put`](Cache::put), mais rend la valeur remplacée quand la clé
+    /// était présente (même expirée), pour libérer une ressource associée.
+    /// Retourne `None` pour une nouvelle clé.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let expires_at = self.default_deadline();
+        self.put_with_options(key, value, WriteOptions { expires_at, ..Default::default() })
+    }
+
+    /// Insère ou met à jour une valeur avec sa propre durée de vie.
     ///
-    /// * Si la clé existe : met à jour la valeur et déplace en Tête.
-    /// * Si la clé n'existe pas :
-    ///     * Si plein : supprime le LRU (Tail).
-    ///     * Insère le nouvel élément en Tête.
+    /// `ttl` remplace le TTL global du cache pour cette entrée uniquement ;
+    /// un `put` ultérieur sur la même clé rétablit le TTL global.
+    /// Le délai d'inactivité (`time_to_idle`) continue de s'appliquer.
+    pub fn put_with_ttl(&mut self, key: K, value: V, ttl: Duration) {
+        let expires_at = Some(self.deadline_after(ttl));
+        self.put_with_options(key, value, WriteOptions { expires_at, ..Default::default() });
+    }
+
+    /// Comme [`remove`](Cache::remove), mais rend aussi la clé stockée,
+    /// quand elle possède des ressources à libérer (un `PathBuf` dont il
+    /// faut supprimer le fichier...).
     ///
     /// # Complexité
-    /// O(1) amorti (grâce au `swap_remove` sur le vecteur).
-    fn put(&mut self, key: K, value: V) {
-        if self.map.contains_key(&key) {
+    /// O(1) (même stratégie `swap_remove` que l'éviction).
+    pub fn pop_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        let entry = self.remove_at(index);
+        self.notify_removal(&entry.0, &entry.1, RemovalCause::Explicit);
+        #[cfg(feature = "metrics")]
+        self.emit_size();
+        Some(entry)
+    }
+
+    /// Retourne la valeur de `key` ; si elle est absente (ou expirée), la
+    /// calcule avec `make` et l'insère avec la durée de vie `ttl`, en une
+    /// seule recherche dans le cas d'un hit.
+    ///
+    /// Une entrée présente garde sa propre date d'expiration. Le
+    /// [`doorkeeper`](crate::LruCacheBuilder::doorkeeper) ne filtre pas
+    /// cette insertion. Une valeur que le cache ne peut pas garder (trop
+    /// lourde, `ttl` nul, ou aussitôt choisie par l'éviction au poids) est
+    /// retournée sans être mise en cache.
+    pub fn get_or_insert_with_ttl(&mut self, key: K, ttl: Duration, make: impl FnOnce() -> V) -> &V {
+        if let Some(index) = self.find_live(&key) {
+            self.promote(index);
+            return &self.arena[index].value;
+        }
+        let expires_at = Some(self.deadline_after(ttl));
+        match self.store_computed(key, make(), expires_at) {
+            Ok(index) => &self.arena[index].value,
+            Err(value) => self.uncached.insert(value),
+        }
+    }
+}
+
+// --- Méthodes Internes (Private) ---
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Retrouve l'index d'une clé dans l'arena.
+    pub(crate) fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.find(key, |i| &self.arena[i].key)
+    }
+
+    /// Comme `find`, mais supprime au passage l'entrée si elle a expiré.
+    pub(crate) fn find_live<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(index) = self.find(key) else {
+            self.record(Stat::Miss);
+            if let Some(ghosts) = self.ghosts.as_mut()
+                && ghosts.on_miss(self.index.hash(key))
+            {
+                self.record(Stat::GhostHit);
+            }
+            return None;
+        };
+        let node = &self.arena[index];
+        let may_expire = node.expires_at.is_some() || self.tti.is_some();
+        if may_expire && node.is_expired(self.clock.now(), self.tti) {
+            self.evict_at(index, RemovalCause::Expired);
+            self.record(Stat::Miss);
+            return None;
+        }
+        self.record(Stat::Hit);
+        Some(index)
+    }
+
+    /// Date d'expiration d'une entrée écrite maintenant, d'après le TTL global.
+    pub(crate) fn default_deadline(&mut self) -> Option<Instant> {
+        self.ttl.map(|ttl| self.deadline_after(ttl))
+    }
+
+    /// Avance `expires_at` à la fin de vie maximale d'une entrée insérée à `created_at`.
+    fn cap_lifetime(&self, expires_at: Option<Instant>, created_at: Instant) -> Option<Instant> {
+        let Some(lifetime) = self.max_lifetime else {
+            return expires_at;
+        };
+        let end_of_life = created_at + lifetime;
+        Some(expires_at.map_or(end_of_life, |deadline| deadline.min(end_of_life)))
+    }
+
+    /// Insère ou met à jour une entrée avec ses réglages explicites.
+    ///
+    /// Une mise à jour remplace tous les réglages (expiration, priorité, tags).
+    /// Retourne la valeur remplacée, expirée ou non.
+    pub(crate) fn put_with_options(&mut self, key: K, value: V, options: WriteOptions) -> Option<V> {
+        if self.doorkeeper.is_some() && !self.admit(&key) {
+            return None;
+        }
+        if self.is_oversize(&key, &value) {
+            // Refusée avant toute éviction : les autres entrées restent, seule
+            // l'ancienne valeur de la clé, désormais périmée, est retirée.
+            return self.pop_entry(&key).map(|(_, old)| old);
+        }
+        #[cfg_attr(not(feature = "timer-wheel"), allow(unused_variables))]
+        let (written, old) = self.write(key, value, options);
+        #[cfg(feature = "timer-wheel")]
+        self.sweep_after_write(written);
+        self.evict_overweight();
+        old
+    }
+
+    /// Écrit l'entrée en Tête (sans filtre d'admission, purge ni éviction
+    /// au poids) et retourne son emplacement et la valeur remplacée.
+    fn write(&mut self, key: K, value: V, options: WriteOptions) -> (usize, Option<V>) {
+        let WriteOptions {
+            expires_at,
+            priority,
+            tags,
+        } = options;
+        #[cfg(feature = "tracing")]
+        let started = self.trace_start();
+        self.apply_buffered_reads();
+        if let Some(negatives) = self.negatives.as_mut() {
+            negatives.remove(&key);
+        }
+        self.changes += 1;
+        let now = self.clock.now();
+        let refresh_at = self.refresh_after.map(|delay| now + delay);
+        let weight = self.weigh(&key, &value);
+        let (written, old) = if let Some(index) = self.find(&key) {
             // Cas 1: Mise à jour
-            let index = self.map[&key];
-            self.arena[index].value = value;
+            let old = std::mem::replace(&mut self.arena[index].value, value);
+            let expired = self.arena[index].is_expired(now, self.tti);
+            if let Some(listener) = self.listener.as_mut() {
+                let cause = if expired { RemovalCause::Expired } else { RemovalCause::Replaced };
+                listener.call(&self.arena[index].key, &old, cause);
+            }
+            if expired {
+                // L'ancienne entrée est morte : la nouvelle valeur repart
+                // comme une insertion (durée de vie, hits, génération).
+                let node = &mut self.arena[index];
+                node.created_at = now;
+                node.hits = 0;
+                node.generation = self.next_generation;
+                self.next_generation += 1;
+            }
+            let created_at = self.arena[index].created_at;
+            self.arena[index].expires_at = self.cap_lifetime(expires_at, created_at);
+            self.arena[index].last_access = now;
+            self.arena[index].refresh_at = refresh_at;
+            let old_weight = std::mem::replace(&mut self.arena[index].weight, weight);
+            self.total_weight = self.total_weight - u64::from(old_weight) + u64::from(weight);
+            if self.arena[index].priority != priority {
+                self.unlink_level(index);
+                let old_priority = std::mem::replace(&mut self.arena[index].priority, priority);
+                self.priority_counts[old_priority as usize] -= 1;
+                self.priority_counts[priority as usize] += 1;
+                self.link_level_front(index);
+            }
+            self.arena[index].tags = tags;
             self.move_to_head(index);
+            self.record(Stat::Update);
+            (index, Some(old))
         } else {
             // Cas 2: Insertion
-            if self.arena.len() >= self.capacity {
-                self.remove_lru();
-            }
+            self.make_room();
 
             let index = self.arena.len();
+            let hash = self.index.hash(&key);
             let node = Node {
-                key: key.clone(),
+                key,
                 value,
                 prev: None,
                 next: self.head,
+                expires_at: self.cap_lifetime(expires_at, now),
+                last_access: now,
+                created_at: now,
+                hits: 0,
+                weight,
+                refresh_at,
+                dirty: false,
+                pinned: false,
+                referenced: false,
+                priority,
+                level_prev: None,
+                level_next: None,
+                tags,
+                generation: self.next_generation,
             };
+            self.next_generation += 1;
 
             self.arena.push(node);
-            self.map.insert(key, index);
+            self.index.insert(index, hash);
+            self.total_weight += u64::from(weight);
+            self.priority_counts[priority as usize] += 1;
+            self.link_level_front(index);
 
             if let Some(old_head_idx) = self.head {
                 self.arena[old_head_idx].prev = Some(index);
@@ -139,40 +693,101 @@ where
             if self.tail.is_none() {
                 self.tail = Some(index);
             }
+            self.record(Stat::Insertion);
+            (index, None)
+        };
+
+        #[cfg(feature = "tracing")]
+        self.trace(Operation::Put, Some(&self.arena[written].key), started);
+        (written, old)
+    }
+
+    /// Écrit une valeur calculée pour l'appelant, sans filtre d'admission,
+    /// et retourne son emplacement.
+    ///
+    /// Une valeur que le cache ne garderait pas (plus lourde que le poids
+    /// maximal, expirée dès l'écriture, ou que l'éviction au poids choisirait
+    /// aussitôt) est rendue sans être mise en cache ; l'ancienne valeur de
+    /// la clé est alors retirée.
+    pub(crate) fn store_computed(
+        &mut self,
+        key: K,
+        value: V,
+        expires_at: Option<Instant>,
+    ) -> Result<usize, V> {
+        let now = self.clock.now();
+        let too_heavy = self.is_oversize(&key, &value);
+        let dead_on_arrival = expires_at.is_some_and(|deadline| deadline <= now)
+            || self.max_lifetime.is_some_and(|lifetime| lifetime.is_zero())
+            || self.tti.is_some_and(|tti| tti.is_zero());
+        if too_heavy || dead_on_arrival {
+            if let Some(index) = self.find(&key) {
+                let (key, old) = self.remove_at(index);
+                self.notify_removal(&key, &old, RemovalCause::Explicit);
+            }
+            return Err(value);
+        }
+        // Purge avant l'écriture : l'entrée écrite ne doit pas bouger avant
+        // l'éviction au poids, qui suit son emplacement.
+        #[cfg(feature = "timer-wheel")]
+        self.sweep_due();
+        let options = WriteOptions {
+            expires_at,
+            ..Default::default()
+        };
+        let (written, _) = self.write(key, value, options);
+        #[cfg(feature = "timer-wheel")]
+        self.schedule_expiry(written);
+        match self.evict_overweight_keeping(written) {
+            Ok(index) => Ok(index),
+            Err(index) => Err(self.remove_at(index).1),
         }
     }
 
-    fn len(&self) -> usize {
-        self.arena.len()
+    /// Détache un nœud de la liste chaînée en recousant ses voisins.
+    /// Met à jour `head` et `tail` si nécessaire.
+    fn detach(&mut self, index: usize) {
+        let prev_idx = self.arena[index].prev;
+        let next_idx = self.arena[index].next;
+
+        match prev_idx {
+            Some(prev) => self.arena[prev].next = next_idx,
+            None => self.head = next_idx,
+        }
+        match next_idx {
+            Some(next) => self.arena[next].prev = prev_idx,
+            None => self.tail = prev_idx,
+        }
+
+        self.arena[index].prev = None;
+        self.arena[index].next = None;
+    }
+
+    /// Marque un accès en lecture : repart le délai d'inactivité et promeut en Tête.
+    pub(crate) fn promote(&mut self, index: usize) {
+        self.apply_buffered_reads();
+        let node = &mut self.arena[index];
+        node.last_access = self.clock.now();
+        node.hits += 1;
+        if self.lazy_recency {
+            node.referenced = true;
+            return;
+        }
+        self.move_to_head(index);
     }
-}
 
-// --- Méthodes Internes (Private) ---
-impl<K, V> LruCache<K, V>
-where
-    K: Hash + Eq + Clone + Debug,
-{
     /// Déplace un nœud existant vers la position `head`.
     /// Met à jour les liens `prev` et `next` des voisins.
-    fn move_to_head(&mut self, index: usize) {
+    pub(crate) fn move_to_head(&mut self, index: usize) {
+        self.arena[index].referenced = false;
         if Some(index) == self.head {
             return;
         }
-        let prev_idx = self.arena[index].prev;
-        let next_idx = self.arena[index].next;
-        
-        // Détachement du nœud
-        if let Some(prev) = prev_idx {
-            self.arena[prev].next = next_idx;
-        }
-        if let Some(next) = next_idx {
-            self.arena[next].prev = prev_idx;
-        }
+        self.unlink_level(index);
+        self.link_level_front(index);
 
-        // Mise à jour du Tail si nécessaire
-        if Some(index) == self.tail {
-            self.tail = prev_idx;
-        }
+        // Détachement du nœud
+        self.detach(index);
 
         // Insertion en Tête
         if let Some(old_head) = self.head {
@@ -180,65 +795,112 @@ where
         }
 
         self.arena[index].next = self.head;
-        self.arena[index].prev = None;
         self.head = Some(index);
     }
 
-    /// Supprime l'élément le moins récemment utilisé (Tail).
+    /// Supprime physiquement le nœud à `index` et retourne son contenu.
     ///
-    /// # Stratégie d'éviction
+    /// # Stratégie
     /// Utilise `swap_remove` pour supprimer l'élément du vecteur en O(1).
     /// Cela déplace le dernier élément du vecteur à l'index supprimé.
     /// Il faut donc "patcher" les liens de cet élément déplacé.
-    /// 
-    /// 
-    fn remove_lru(&mut self) {
-        if let Some(tail_idx) = self.tail {
-            // 1. Suppression logique de la Map
-            let key_to_remove = self.arena[tail_idx].key.clone();
-            self.map.remove(&key_to_remove);
-
-            // 2. Mise à jour du pointeur Tail
-            self.tail = self.arena[tail_idx].prev;
-            
-            if let Some(new_tail) = self.tail {
-                self.arena[new_tail].next = None;
-            } else {
-                self.head = None;
-            }
+    pub(crate) fn remove_at(&mut self, index: usize) -> (K, V) {
+        // Les index mémorisés par les lectures partagées vont être invalidés.
+        self.apply_buffered_reads();
 
-            // 3. Suppression physique et Patching des indices
-            self.arena.swap_remove(tail_idx);
-
-            // Si l'élément supprimé n'était pas le dernier physique du tableau,
-            // un autre élément a pris sa place (celui qui était à la fin).
-            if tail_idx < self.arena.len() {
-                let moved_key = self.arena[tail_idx].key.clone();
-                self.map.insert(moved_key, tail_idx);
-                
-                let prev = self.arena[tail_idx].prev;
-                let next = self.arena[tail_idx].next;
-  
-                if let Some(p) = prev {
-                    self.arena[p].next = Some(tail_idx);
-                }
-                if let Some(n) = next {
-                    self.arena[n].prev = Some(tail_idx);
-                }
+        self.changes += 1;
 
-                if self.head == Some(self.arena.len()) {
-                    self.head = Some(tail_idx);
-                }
-                if self.tail == Some(self.arena.len()) {
-                    self.tail = Some(tail_idx);
-                }
+        // 1. Suppression logique de l'index et de la liste
+        self.index.remove(index);
+        self.detach(index);
+        self.unlink_level(index);
+
+        // 2. Suppression physique et Patching des indices
+        let node = self.arena.swap_remove(index);
+        self.total_weight -= u64::from(node.weight);
+        self.priority_counts[node.priority as usize] -= 1;
+        let moved_from = self.arena.len();
+        self.index.relocate(moved_from, index);
+
+        // Si l'élément supprimé n'était pas le dernier physique du tableau,
+        // un autre élément a pris sa place (celui qui était à la fin).
+        if index < moved_from {
+            let prev = self.arena[index].prev;
+            let next = self.arena[index].next;
+
+            match prev {
+                Some(p) => self.arena[p].next = Some(index),
+                None => self.head = Some(index),
             }
+            match next {
+                Some(n) => self.arena[n].prev = Some(index),
+                None => self.tail = Some(index),
+            }
+            self.relink_level(index);
+        }
+
+        (node.key, node.value)
+    }
+
+    /// Supprime la victime d'éviction : l'entrée non épinglée la moins
+    /// récemment utilisée parmi celles de plus basse priorité.
+    ///
+    /// Retourne `false` si aucune entrée ne peut être évincée.
+    pub(crate) fn remove_lru(&mut self) -> bool {
+        let Some(victim) = self.next_victim() else {
+            return false;
+        };
+        self.evict_victim(victim);
+        true
+    }
+
+    /// Prochaine victime d'éviction, après avoir rangé les entrées lues en
+    /// mode [`lazy_recency`](crate::LruCacheBuilder::lazy_recency).
+    pub(crate) fn next_victim(&mut self) -> Option<usize> {
+        if self.lazy_recency {
+            self.settle_recency();
+        }
+        self.eviction_victim()
+    }
+
+    /// Évince `victim`, comptée comme expirée si elle l'est déjà.
+    pub(crate) fn evict_victim(&mut self, victim: usize) {
+        let cause = if self.arena[victim].is_expired(self.clock.now(), self.tti) {
+            RemovalCause::Expired
+        } else {
+            RemovalCause::Evicted
+        };
+        self.evict_at(victim, cause);
+    }
+
+    /// Supprime une entrée retirée automatiquement (éviction, expiration).
+    ///
+    /// Une entrée modifiée pas encore écrite dans le stockage est mise de
+    /// côté pour le prochain `flush` au lieu d'être perdue.
+    pub(crate) fn evict_at(&mut self, index: usize, cause: RemovalCause) {
+        let dirty = self.arena[index].dirty;
+        let entry = self.remove_at(index);
+        self.record(match cause {
+            RemovalCause::Expired => Stat::Expiration,
+            _ => Stat::Eviction,
+        });
+        if cause == RemovalCause::Evicted
+            && let Some(ghosts) = self.ghosts.as_mut()
+        {
+            ghosts.on_evict(self.index.hash(&entry.0));
+        }
+        #[cfg(feature = "tracing")]
+        self.trace(Operation::Evict(cause), Some(&entry.0), None);
+        self.notify_removal(&entry.0, &entry.1, cause);
+        if dirty {
+            self.evicted_dirty.push(entry);
         }
     }
 }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::MockClock;
     #[test]
     fn test_simple_put_get() {
         let mut cache = LruCache::new(2);
@@ -259,4 +921,305 @@ mod tests {
         assert_eq!(cache.get(&"A"), Some(&1));
         assert_eq!(cache.get(&"C"), Some(&3));
     }
+
+    #[test]
+    fn test_value_without_debug() {
+        struct Secret(u32);
+        let mut cache = LruCache::new(1);
+        cache.put("token", Secret(42));
+        assert!(matches!(cache.get(&"token"), Some(Secret(42))));
+    }
+
+    #[test]
+    fn test_key_without_clone() {
+        #[derive(PartialEq, Eq, Hash)]
+        struct Handle(u32);
+        let mut cache = LruCache::new(2);
+        cache.put(Handle(1), "un");
+        cache.put(Handle(2), "deux");
+        cache.put(Handle(3), "trois");
+        assert_eq!(cache.get(&Handle(1)), None);
+        assert_eq!(cache.get(&Handle(3)), Some(&"trois"));
+    }
+
+    #[test]
+    fn test_matches_reference_model() {
+        // Modèle naïf : Vec ordonné du plus récent au plus ancien.
+        let mut model: Vec<(u32, u32)> = Vec::new();
+        let mut cache = LruCache::new(8);
+        let mut seed = 12345u32;
+        for step in 0..5000 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let key = (seed >> 16) % 20;
+            if seed.is_multiple_of(3) {
+                let expected = model.iter().position(|(k, _)| *k == key).map(|pos| {
+                    let entry = model.remove(pos);
+                    model.insert(0, entry);
+                    entry.1
+                });
+                assert_eq!(cache.get(&key).copied(), expected);
+            } else {
+                if let Some(pos) = model.iter().position(|(k, _)| *k == key) {
+                    model.remove(pos);
+                } else if model.len() == 8 {
+                    model.pop();
+                }
+                model.insert(0, (key, step));
+                cache.put(key, step);
+            }
+            assert_eq!(cache.len(), model.len());
+        }
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        let mut cache = LruCache::new(3);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.put("C", 3);
+        assert_eq!(cache.remove(&"B"), Some(2));
+        assert_eq!(cache.remove(&"B"), None);
+        cache.put("D", 4);
+        cache.put("E", 5);
+        assert_eq!(cache.get(&"A"), None);
+        assert_eq!(cache.len(), 3);
+        cache.clear();
+        assert!(cache.is_empty());
+        cache.put("F", 6);
+        assert_eq!(cache.get(&"F"), Some(&6));
+    }
+
+    #[test]
+    fn test_trait_object() {
+        let mut cache: Box<dyn Cache<&str, i32>> = Box::new(LruCache::new(2));
+        cache.put("A", 1);
+        assert_eq!(cache.get(&"A"), Some(&1));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_peek_and_iteration_order() {
+        let mut cache = LruCache::new(3);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.put("C", 3);
+        // peek ne promeut pas : "A" reste le LRU.
+        assert_eq!(cache.peek(&"A"), Some(&1));
+        assert!(cache.contains(&"B"));
+        assert!(cache.is_full());
+        assert_eq!(cache.capacity(), 3);
+        let keys: Vec<_> = cache.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, ["C", "B", "A"]);
+        let oldest_first: Vec<_> = cache.iter().rev().map(|(k, _)| *k).collect();
+        assert_eq!(oldest_first, ["A", "B", "C"]);
+        cache.put("D", 4);
+        assert!(!cache.contains(&"A"));
+    }
+
+    #[test]
+    fn test_ttl_expires_entries() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2)
+            .ttl(Duration::from_secs(30))
+            .clock(clock.clone())
+            .build();
+        cache.put("A", 1);
+        assert_eq!(cache.get(&"A"), Some(&1));
+        clock.advance(Duration::from_secs(40));
+        assert_eq!(cache.peek(&"A"), None);
+        assert_eq!(cache.iter().count(), 0);
+        assert_eq!(cache.get(&"A"), None);
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_put_refreshes_ttl() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2)
+            .ttl(Duration::from_secs(60))
+            .clock(clock.clone())
+            .build();
+        cache.put("A", 1);
+        clock.advance(Duration::from_secs(40));
+        cache.put("A", 2);
+        clock.advance(Duration::from_secs(40));
+        assert_eq!(cache.get(&"A"), Some(&2));
+    }
+
+    #[test]
+    fn test_time_to_idle_refreshed_by_reads() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2)
+            .time_to_idle(Duration::from_secs(50))
+            .clock(clock.clone())
+            .build();
+        cache.put("A", 1);
+        cache.put("B", 2);
+        for _ in 0..3 {
+            clock.advance(Duration::from_secs(25));
+            assert_eq!(cache.get(&"A"), Some(&1));
+        }
+        assert_eq!(cache.get(&"B"), None);
+    }
+
+    #[test]
+    fn test_equality_compares_contents_and_order() {
+        let mut a = LruCache::new(3);
+        let mut b = LruCache::builder(10).ttl(Duration::from_secs(60)).build();
+        for (key, value) in [("x", 1), ("y", 2)] {
+            a.put(key, value);
+            b.put(key, value);
+        }
+        assert!(a == b);
+        a.get(&"x");
+        assert!(a != b);
+        b.get(&"x");
+        assert!(a == b);
+        b.put("y", 3);
+        a.put("y", 2);
+        assert!(a != b);
+    }
+
+    #[test]
+    fn test_insert_returns_replaced_value() {
+        let mut cache = LruCache::new(2);
+        assert_eq!(cache.insert("A", 1), None);
+        assert_eq!(cache.insert("A", 2), Some(1));
+        cache.put("B", 3);
+        assert_eq!(cache.insert("C", 4), None);
+        assert_eq!(cache.get(&"A"), None);
+        assert_eq!(cache.get(&"C"), Some(&4));
+    }
+
+    #[test]
+    fn test_pop_entry_returns_owned_key() {
+        let mut cache = LruCache::new(2);
+        cache.put(std::path::PathBuf::from("/tmp/a.bin"), 1);
+        cache.put(std::path::PathBuf::from("/tmp/b.bin"), 2);
+        let (path, value) = cache.pop_entry(std::path::Path::new("/tmp/a.bin")).unwrap();
+        assert_eq!((path.to_str(), value), (Some("/tmp/a.bin"), 1));
+        assert_eq!(cache.pop_entry(std::path::Path::new("/tmp/a.bin")), None);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_get_or_insert_with_ttl() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2).clock(clock.clone()).build();
+        let mut calls = 0;
+        for _ in 0..2 {
+            let value = cache.get_or_insert_with_ttl("A", Duration::from_secs(10), || {
+                calls += 1;
+                calls
+            });
+            assert_eq!(*value, 1);
+        }
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(*cache.get_or_insert_with_ttl("A", Duration::from_secs(10), || 2), 2);
+        assert_eq!(calls, 1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_get_or_insert_with_ttl_returns_unkept_value() {
+        let ttl = Duration::from_secs(10);
+        let mut cache = LruCache::builder(10)
+            .max_weight(10, |_: &&str, v: &String| v.len() as u32)
+            .build();
+        cache.put_with_priority("a", "xxxx".to_string(), Priority::High);
+        cache.put_with_priority("b", "xxxx".to_string(), Priority::High);
+        // 8 + 5 > 10 : la nouvelle entrée Normal est la victime, pas "a" ni "b".
+        assert_eq!(cache.get_or_insert_with_ttl("c", ttl, || "yyyyy".to_string()), "yyyyy");
+        assert!(!cache.contains(&"c"));
+        assert_eq!(cache.len(), 2);
+        // Une entrée Low part avant elle.
+        cache.remove(&"b");
+        cache.put_with_priority("low", "zz".to_string(), Priority::Low);
+        assert_eq!(cache.get_or_insert_with_ttl("c", ttl, || "yyyyy".to_string()), "yyyyy");
+        assert!(cache.contains(&"c") && !cache.contains(&"low"));
+
+        // Des entrées épinglées occupent tout le budget.
+        let mut cache = LruCache::builder(10)
+            .max_weight(10, |_: &&str, v: &String| v.len() as u32)
+            .build();
+        cache.put("p", "x".repeat(8));
+        cache.pin(&"p");
+        assert_eq!(cache.get_or_insert_with_ttl("c", ttl, || "yyyyy".to_string()), "yyyyy");
+        assert_eq!(cache.get_or_insert_with_ttl("d", ttl, || "y".repeat(11)), &"y".repeat(11));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.check_consistency(), Ok(()));
+    }
+
+    #[test]
+    fn test_max_lifetime_and_idle_combined() {
+        let clock = MockClock::new();
+        let mut sessions = LruCache::builder(2)
+            .max_lifetime(Duration::from_secs(100))
+            .time_to_idle(Duration::from_secs(30))
+            .clock(clock.clone())
+            .build();
+        sessions.put("active", 1);
+        sessions.put("idle", 2);
+        for _ in 0..3 {
+            clock.advance(Duration::from_secs(25));
+            assert_eq!(sessions.get(&"active"), Some(&1));
+        }
+        assert_eq!(sessions.get(&"idle"), None);
+        // Les écritures ne prolongent pas la vie de la session.
+        sessions.put("active", 3);
+        clock.advance(Duration::from_secs(25));
+        assert_eq!(sessions.get(&"active"), None);
+        assert_eq!(sessions.max_lifetime(), Some(Duration::from_secs(100)));
+    }
+
+    #[test]
+    fn test_put_over_expired_lifetime_starts_fresh() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2)
+            .max_lifetime(Duration::from_secs(100))
+            .clock(clock.clone())
+            .build();
+        cache.put("A", 1);
+        clock.advance(Duration::from_secs(150));
+        // Expirée mais pas encore purgée : la réécriture repart de zéro.
+        cache.put("A", 2);
+        assert_eq!(cache.get(&"A"), Some(&2));
+        clock.advance(Duration::from_secs(99));
+        assert_eq!(cache.get(&"A"), Some(&2));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(cache.get(&"A"), None);
+    }
+
+    #[test]
+    fn test_ttl_and_idle_combined() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2)
+            .ttl(Duration::from_secs(60))
+            .time_to_idle(Duration::from_secs(200))
+            .clock(clock.clone())
+            .build();
+        cache.put("A", 1);
+        clock.advance(Duration::from_secs(35));
+        assert_eq!(cache.get(&"A"), Some(&1));
+        clock.advance(Duration::from_secs(35));
+        // Lu récemment, mais écrit il y a plus de 60 s.
+        assert_eq!(cache.get(&"A"), None);
+    }
+
+    #[test]
+    fn test_put_with_ttl_overrides_default() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(3)
+            .ttl(Duration::from_secs(3600))
+            .clock(clock.clone())
+            .build();
+        cache.put_with_ttl("court", 1, Duration::from_secs(20));
+        cache.put("long", 2);
+        let mut no_default = LruCache::builder(1).clock(clock.clone()).build();
+        no_default.put_with_ttl("court", 3, Duration::from_secs(20));
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(cache.get(&"court"), None);
+        assert_eq!(cache.get(&"long"), Some(&2));
+        assert_eq!(no_default.get(&"court"), None);
+    }
 }
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Source de temps consultée par le cache pour toutes ses fonctionnalités
+/// temporelles (TTL, inactivité...).
+///
+/// Injecter une horloge permet de tester l'expiration sans `sleep`.
+pub trait Clock: Send + Sync {
+    /// Instant courant.
+    fn now(&self) -> Instant;
+}
+
+/// Horloge réelle, basée sur [`Instant::now`]. C'est l'horloge par défaut.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Horloge manuelle pour les tests : le temps n'avance que via [`advance`](Self::advance).
+///
+/// Les clones partagent le même temps : on garde un clone dans le test et
+/// on confie l'autre au cache.
+///
+/// # Exemple
+/// ```
+/// use std::time::Duration;
+/// use cache_lru_project::{Cache, LruCache, MockClock};
+///
+/// let clock = MockClock::new();
+/// let mut cache = LruCache::builder(10)
+///     .ttl(Duration::from_secs(5))
+///     .clock(clock.clone())
+///     .build();
+/// cache.put("k", 1);
+/// clock.advance(Duration::from_secs(6));
+/// assert_eq!(cache.get(&"k"), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    origin: Instant,
+    elapsed_nanos: Arc<AtomicU64>,
+}
+
+impl MockClock {
+    /// Crée une horloge figée à l'instant présent.
+    pub fn new() -> Self {
+        MockClock {
+            origin: Instant::now(),
+            elapsed_nanos: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Fait avancer le temps de `delta`.
+    pub fn advance(&self, delta: Duration) {
+        let nanos = u64::try_from(delta.as_nanos()).unwrap_or(u64::MAX);
+        self.elapsed_nanos.fetch_add(nanos, Ordering::SeqCst);
+    }
+
+    /// Temps écoulé depuis la création de l'horloge.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::SeqCst))
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// Représentation textuelle des clés pour la persistance.
+///
+/// Permet de sauvegarder des clés qui n'implémentent pas `Display` et
+/// `FromStr` (structures, octets...). Le texte produit peut contenir
+/// n'importe quel caractère : il est échappé par le format de fichier.
+pub trait KeyCodec<K> {
+    /// Encode `key` en texte.
+    fn encode_key(&self, key: &K) -> String;
+
+    /// Décode une clé, ou décrit pourquoi le texte est invalide.
+    fn decode_key(&self, text: &str) -> Result<K, String>;
+}
+
+/// Représentation textuelle des valeurs pour la persistance (voir [`KeyCodec`]).
+pub trait ValueCodec<V> {
+    /// Encode `value` en texte.
+    fn encode_value(&self, value: &V) -> String;
+
+    /// Décode une valeur, ou décrit pourquoi le texte est invalide.
+    fn decode_value(&self, text: &str) -> Result<V, String>;
+}
+
+/// Codec par défaut : `Display` pour écrire, `FromStr` pour relire.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DisplayCodec;
+
+impl<K> KeyCodec<K> for DisplayCodec
+where
+    K: Display + FromStr,
+    <K as FromStr>::Err: Debug,
+{
+    fn encode_key(&self, key: &K) -> String {
+        key.to_string()
+    }
+
+    fn decode_key(&self, text: &str) -> Result<K, String> {
+        K::from_str(text).map_err(|err| format!("{err:?}"))
+    }
+}
+
+impl<V> ValueCodec<V> for DisplayCodec
+where
+    V: Display + FromStr,
+    <V as FromStr>::Err: Debug,
+{
+    fn encode_value(&self, value: &V) -> String {
+        value.to_string()
+    }
+
+    fn decode_value(&self, text: &str) -> Result<V, String> {
+        V::from_str(text).map_err(|err| format!("{err:?}"))
+    }
+}
+
+/// Codec des `Vec<u8>` en hexadécimal.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HexCodec;
+
+impl HexCodec {
+    fn encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn decode(text: &str) -> Result<Vec<u8>, String> {
+        if !text.len().is_multiple_of(2) {
+            return Err("longueur hexadécimale impaire".to_owned());
+        }
+        (0..text.len())
+            .step_by(2)
+            .map(|i| {
+                text.get(i..i + 2)
+                    .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                    .ok_or_else(|| format!("octet hexadécimal invalide à la position {i}"))
+            })
+            .collect()
+    }
+}
+
+impl KeyCodec<Vec<u8>> for HexCodec {
+    fn encode_key(&self, key: &Vec<u8>) -> String {
+        Self::encode(key)
+    }
+
+    fn decode_key(&self, text: &str) -> Result<Vec<u8>, String> {
+        Self::decode(text)
+    }
+}
+
+impl ValueCodec<Vec<u8>> for HexCodec {
+    fn encode_value(&self, value: &Vec<u8>) -> String {
+        Self::encode(value)
+    }
+
+    fn decode_value(&self, text: &str) -> Result<Vec<u8>, String> {
+        Self::decode(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_codec() {
+        let bytes = vec![0u8, 0x7f, 0xff];
+        assert_eq!(HexCodec.encode_value(&bytes), "007fff");
+        assert_eq!(HexCodec.decode_value("007fff"), Ok(bytes));
+        assert!(HexCodec.decode_key("0g").is_err());
+        assert!(HexCodec.decode_key("abc").is_err());
+    }
+}
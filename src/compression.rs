@@ -0,0 +1,233 @@
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::hash::Hash;
+use std::io::{self, ErrorKind, Write};
+use std::str::FromStr;
+
+use crate::cache::LruCache;
+use crate::persistence::write_atomically;
+
+/// Algorithme de compression utilisé par [`LruCache::save_compressed`].
+///
+/// [`Lz77`] est fourni ; un codec gzip ou zstd s'ajoute en implémentant
+/// ce trait au-dessus de la bibliothèque de son choix.
+pub trait Compressor {
+    /// Compresse `input`.
+    fn compress(&self, input: &[u8]) -> Vec<u8>;
+
+    /// Inverse de [`compress`](Self::compress). Une entrée altérée est une
+    /// erreur `ErrorKind::InvalidData`.
+    fn decompress(&self, input: &[u8]) -> io::Result<Vec<u8>>;
+}
+
+/// Compression LZ77 rapide et sans dépendance, à la manière de LZ4 :
+/// efficace sur du texte répétitif, moins sur des données aléatoires.
+///
+/// Format : longueur décompressée (`u64` little-endian), puis des séquences
+/// `jeton, littéraux, distance (u16), longueurs étendues`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Lz77;
+
+const MIN_MATCH: usize = 4;
+const WINDOW: usize = u16::MAX as usize;
+const HASH_BITS: u32 = 14;
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.to_owned())
+}
+
+/// Écrit la partie d'une longueur qui dépasse le quartet du jeton.
+fn write_extra_length(len: usize, out: &mut Vec<u8>) {
+    if len >= 15 {
+        let mut rest = len - 15;
+        while rest >= 255 {
+            out.push(255);
+            rest -= 255;
+        }
+        out.push(rest as u8);
+    }
+}
+
+fn read_byte(input: &[u8], pos: &mut usize) -> io::Result<u8> {
+    let byte = *input.get(*pos).ok_or_else(|| invalid("données compressées tronquées"))?;
+    *pos += 1;
+    Ok(byte)
+}
+
+fn read_length(nibble: u8, input: &[u8], pos: &mut usize) -> io::Result<usize> {
+    let mut len = usize::from(nibble);
+    if nibble == 15 {
+        loop {
+            let byte = read_byte(input, pos)?;
+            len += usize::from(byte);
+            if byte != 255 {
+                break;
+            }
+        }
+    }
+    Ok(len)
+}
+
+/// Émet une séquence : des littéraux, suivis d'une correspondance sauf en fin de flux.
+fn emit(literals: &[u8], matched: Option<(usize, usize)>, out: &mut Vec<u8>) {
+    let match_len = matched.map_or(0, |(_, len)| len - MIN_MATCH);
+    out.push(((literals.len().min(15) as u8) << 4) | match_len.min(15) as u8);
+    write_extra_length(literals.len(), out);
+    out.extend_from_slice(literals);
+    if let Some((distance, _)) = matched {
+        out.extend_from_slice(&(distance as u16).to_le_bytes());
+        write_extra_length(match_len, out);
+    }
+}
+
+impl Compressor for Lz77 {
+    fn compress(&self, input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(input.len() / 2 + 16);
+        out.extend_from_slice(&(input.len() as u64).to_le_bytes());
+        let mut table = vec![usize::MAX; 1 << HASH_BITS];
+        let (mut anchor, mut i) = (0, 0);
+        while i + MIN_MATCH <= input.len() {
+            let window = &input[i..i + MIN_MATCH];
+            let seq = u32::from_le_bytes(window.try_into().expect("4 octets"));
+            let hash = (seq.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize;
+            let candidate = std::mem::replace(&mut table[hash], i);
+            if candidate == usize::MAX
+                || i - candidate > WINDOW
+                || &input[candidate..candidate + MIN_MATCH] != window
+            {
+                i += 1;
+                continue;
+            }
+            let mut len = MIN_MATCH;
+            while i + len < input.len() && input[candidate + len] == input[i + len] {
+                len += 1;
+            }
+            emit(&input[anchor..i], Some((i - candidate, len)), &mut out);
+            i += len;
+            anchor = i;
+        }
+        emit(&input[anchor..], None, &mut out);
+        out
+    }
+
+    fn decompress(&self, input: &[u8]) -> io::Result<Vec<u8>> {
+        let header = input.get(..8).ok_or_else(|| invalid("données compressées tronquées"))?;
+        let expected = u64::from_le_bytes(header.try_into().expect("8 octets"));
+        let expected = usize::try_from(expected).map_err(|_| invalid("longueur hors limites"))?;
+        // Un octet compressé produit au plus quelques centaines d'octets : borne l'allocation.
+        let mut out = Vec::with_capacity(expected.min(input.len().saturating_mul(256)));
+        let mut pos = 8;
+        while pos < input.len() {
+            let token = read_byte(input, &mut pos)?;
+            let literals = read_length(token >> 4, input, &mut pos)?;
+            let end = pos
+                .checked_add(literals)
+                .filter(|&end| end <= input.len())
+                .ok_or_else(|| invalid("données compressées tronquées"))?;
+            out.extend_from_slice(&input[pos..end]);
+            pos = end;
+            if pos == input.len() {
+                break;
+            }
+            let distance = usize::from(u16::from_le_bytes([
+                read_byte(input, &mut pos)?,
+                read_byte(input, &mut pos)?,
+            ]));
+            if distance == 0 || distance > out.len() {
+                return Err(invalid("distance de correspondance invalide"));
+            }
+            let len = read_length(token & 15, input, &mut pos)? + MIN_MATCH;
+            if out.len() + len > expected {
+                return Err(invalid("données décompressées trop longues"));
+            }
+            // Copie octet par octet : la correspondance peut chevaucher sa source.
+            let start = out.len() - distance;
+            for k in 0..len {
+                out.push(out[start + k]);
+            }
+        }
+        if out.len() != expected {
+            return Err(invalid("longueur décompressée incorrecte"));
+        }
+        Ok(out)
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Display + FromStr,
+    V: Display + FromStr,
+    <K as FromStr>::Err: Debug,
+    <V as FromStr>::Err: Debug,
+{
+    /// Sauvegarde le cache au format texte de
+    /// [`save_to_writer`](Self::save_to_writer), compressé par `codec`.
+    /// L'écriture est atomique.
+    pub fn save_compressed(&self, filepath: &str, codec: &impl Compressor) -> io::Result<()> {
+        let mut text = Vec::new();
+        self.save_to_writer(&mut text)?;
+        let compressed = codec.compress(&text);
+        write_atomically(filepath, |file| file.write_all(&compressed))
+    }
+
+    /// Recharge un cache sauvegardé par [`save_compressed`](Self::save_compressed)
+    /// avec le même codec.
+    ///
+    /// Si le fichier n'existe pas, retourne un cache vide de capacité
+    /// `capacity`. Les données sont ensuite lues comme par
+    /// [`load_from_reader`](Self::load_from_reader).
+    pub fn load_compressed(capacity: usize, filepath: &str, codec: &impl Compressor) -> io::Result<Self> {
+        let compressed = match fs::read(filepath) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(LruCache::new(capacity)),
+            Err(err) => return Err(err),
+        };
+        let text = codec.decompress(&compressed)?;
+        Self::load_from_reader(capacity, text.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_lz77_round_trip() {
+        let repetitive = "clé=valeur répétée\n".repeat(1000);
+        let compressed = Lz77.compress(repetitive.as_bytes());
+        assert!(compressed.len() < repetitive.len() / 20);
+        assert_eq!(Lz77.decompress(&compressed).unwrap(), repetitive.as_bytes());
+
+        let mut state = 0x2545_f491_u32;
+        let noisy: Vec<u8> = (0..5000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state % 7) as u8
+            })
+            .collect();
+        for input in [&b""[..], b"abc", &noisy] {
+            assert_eq!(Lz77.decompress(&Lz77.compress(input)).unwrap(), input);
+        }
+        assert!(Lz77.decompress(&compressed[..compressed.len() - 3]).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_compressed() {
+        let path = std::env::temp_dir().join(format!("lru_compressed_{}.lz", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut cache = LruCache::new(100);
+        for i in 0..100u32 {
+            cache.put(i, format!("valeur très répétitive numéro {}", i % 3));
+        }
+        cache.save_compressed(path, &Lz77).unwrap();
+        assert!(fs::metadata(path).unwrap().len() < 2000);
+
+        let loaded: LruCache<u32, String> = LruCache::load_compressed(10, path, &Lz77).unwrap();
+        assert_eq!(loaded.capacity(), 100);
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+use std::hash::Hash;
+
+use crate::cache::{Cache, LruCache};
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Index de `key` si l'entrée est présente et non expirée (sans effet de bord).
+    fn find_unexpired(&self, key: &K) -> Option<usize> {
+        let index = self.find(key)?;
+        (!self.arena[index].is_expired(self.clock.now(), self.tti)).then_some(index)
+    }
+
+    /// Insère `value` seulement si la clé est absente (ou expirée).
+    ///
+    /// Retourne la valeur déjà présente, sans la remplacer ni modifier sa
+    /// récence ; `None` signifie que `value` a été insérée.
+    pub fn put_if_absent(&mut self, key: K, value: V) -> Option<&V> {
+        match self.find_unexpired(&key) {
+            Some(index) => Some(&self.arena[index].value),
+            None => {
+                self.put(key, value);
+                None
+            }
+        }
+    }
+
+    /// Remplace la valeur de `key` par `new` seulement si elle vaut `expected`.
+    ///
+    /// En cas de succès, l'écriture se comporte comme un `put` (promotion,
+    /// TTL recalculé). Sinon (valeur différente, clé absente ou expirée),
+    /// `new` est rendue à l'appelant.
+    pub fn compare_and_swap(&mut self, key: K, expected: &V, new: V) -> Result<(), V>
+    where
+        V: PartialEq,
+    {
+        match self.find_unexpired(&key) {
+            Some(index) if self.arena[index].value == *expected => {
+                self.put(key, new);
+                Ok(())
+            }
+            _ => Err(new),
+        }
+    }
+
+    /// Modifie la valeur de `key` en place puis promeut l'entrée en Tête.
+    ///
+    /// Le poids de l'entrée est recalculé (une valeur qui grossit peut donc
+    /// provoquer des évictions ; devenue plus lourde que le maximum à elle
+    /// seule, elle est évincée sans toucher aux autres). Retourne `false` si
+    /// la clé est absente ou expirée, sans appeler `f`.
+    pub fn modify(&mut self, key: &K, f: impl FnOnce(&mut V)) -> bool {
+        let Some(index) = self.find_live(key) else {
+            return false;
+        };
+        f(&mut self.arena[index].value);
+        self.changes += 1;
+        self.promote(index);
+        let node = &self.arena[index];
+        let weight = self.weigh(&node.key, &node.value);
+        let old_weight = std::mem::replace(&mut self.arena[index].weight, weight);
+        self.total_weight = self.total_weight - u64::from(old_weight) + u64::from(weight);
+        if self.max_weight.is_some_and(|max_weight| u64::from(weight) > max_weight) {
+            self.evict_victim(index);
+        } else {
+            self.evict_overweight();
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_put_if_absent() {
+        let mut cache = LruCache::new(2);
+        assert_eq!(cache.put_if_absent("a", 1), None);
+        assert_eq!(cache.put_if_absent("a", 2), Some(&1));
+        assert_eq!(cache.peek(&"a"), Some(&1));
+    }
+
+    #[test]
+    fn test_compare_and_swap() {
+        let mut cache = LruCache::new(2);
+        cache.put("counter", 1);
+        assert_eq!(cache.compare_and_swap("counter", &1, 2), Ok(()));
+        assert_eq!(cache.compare_and_swap("counter", &1, 3), Err(3));
+        assert_eq!(cache.compare_and_swap("absent", &1, 4), Err(4));
+        assert_eq!(cache.peek(&"counter"), Some(&2));
+        assert!(!cache.contains(&"absent"));
+    }
+
+    #[test]
+    fn test_modify_in_place_promotes() {
+        let mut cache = LruCache::new(2);
+        cache.put("hits", vec![1]);
+        cache.put("other", vec![]);
+        assert!(cache.modify(&"hits", |v| v.push(2)));
+        assert!(!cache.modify(&"absent", |v| v.push(3)));
+        cache.put("new", vec![]);
+        assert_eq!(cache.peek(&"hits"), Some(&vec![1, 2]));
+        assert!(!cache.contains(&"other"));
+    }
+}
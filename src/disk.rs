@@ -0,0 +1,374 @@
+use std::cell::{OnceCell, RefCell};
+use std::fs::{self, File};
+use std::hash::Hash;
+use std::io::{self, BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::cache::{Cache, LruCache};
+use crate::codec::{DisplayCodec, KeyCodec, ValueCodec};
+use crate::persistence::{read_text, write_atomically, write_text};
+use crate::tiered::remove_file;
+
+/// Extension des fichiers de valeurs.
+const ENTRY_EXTENSION: &str = "entry";
+/// Nom du fichier d'index (ordre LRU, fichier et taille de chaque entrée).
+const INDEX_FILE: &str = "index";
+
+/// Métadonnées d'une entrée sur disque.
+struct Slot<V> {
+    file: u64,
+    size: u64,
+    /// Valeur décodée, chargée à la demande puis libérée à la
+    /// prochaine opération modifiant le cache.
+    value: OnceCell<V>,
+}
+
+/// Codec de l'index : `fichier:taille`.
+struct SlotCodec;
+
+impl<V> ValueCodec<Slot<V>> for SlotCodec {
+    fn encode_value(&self, slot: &Slot<V>) -> String {
+        format!("{}:{}", slot.file, slot.size)
+    }
+
+    fn decode_value(&self, text: &str) -> Result<Slot<V>, String> {
+        let (file, size) = text.split_once(':').ok_or("':' attendu")?;
+        Ok(Slot {
+            file: file.parse().map_err(|err| format!("{err:?}"))?,
+            size: size.parse().map_err(|err| format!("{err:?}"))?,
+            value: OnceCell::new(),
+        })
+    }
+}
+
+/// Cache LRU sur disque, borné en nombre d'entrées et en octets.
+///
+/// Chaque valeur est un fichier de `dir` ; l'ordre LRU et la taille des
+/// fichiers sont tenus dans un fichier d'index, réécrit par
+/// [`sync_index`](Self::sync_index) et à la destruction du cache. À
+/// l'ouverture, les entrées dont le fichier a disparu sont oubliées et les
+/// fichiers absents de l'index supprimés.
+///
+/// Les valeurs lues (`get`, `peek`, `iter` du trait [`Cache`]) sont gardées
+/// décodées en mémoire jusqu'à la prochaine opération modifiant le cache.
+/// Les erreurs d'entrée/sortie sont remontées par les méthodes `try_*` ; via
+/// le trait, une écriture qui échoue est ignorée et une lecture illisible
+/// est traitée comme absente.
+pub struct DiskCache<K, V, KC = DisplayCodec, VC = DisplayCodec> {
+    index: LruCache<K, Slot<V>>,
+    dir: PathBuf,
+    max_bytes: u64,
+    total_bytes: u64,
+    next_file: u64,
+    key_codec: KC,
+    value_codec: VC,
+    /// Clés dont la valeur est actuellement décodée en mémoire.
+    loaded: RefCell<Vec<K>>,
+    /// Réécriture de l'index à la destruction (capturée à l'ouverture,
+    /// `Drop` ne pouvant pas porter les bornes des codecs).
+    sync_on_drop: fn(&Self) -> io::Result<()>,
+}
+
+impl<K, V> DiskCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    DisplayCodec: KeyCodec<K> + ValueCodec<V>,
+{
+    /// Ouvre (ou crée) un cache disque dans `dir`, limité à `max_entries`
+    /// entrées et `max_bytes` octets de valeurs.
+    ///
+    /// # Panics
+    /// Panique si `max_entries` est 0.
+    pub fn open(dir: impl AsRef<Path>, max_entries: usize, max_bytes: u64) -> io::Result<Self> {
+        Self::open_with_codecs(dir, max_entries, max_bytes, DisplayCodec, DisplayCodec)
+    }
+}
+
+impl<K, V, KC, VC> DiskCache<K, V, KC, VC>
+where
+    K: Hash + Eq + Clone,
+    KC: KeyCodec<K>,
+    VC: ValueCodec<V>,
+{
+    /// Comme [`open`](DiskCache::open), avec des codecs pour les clés (index) et les valeurs (fichiers).
+    pub fn open_with_codecs(
+        dir: impl AsRef<Path>,
+        max_entries: usize,
+        max_bytes: u64,
+        key_codec: KC,
+        value_codec: VC,
+    ) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        let index = match File::open(dir.join(INDEX_FILE)) {
+            Ok(file) => read_text(
+                |_| LruCache::new(max_entries),
+                |index: &mut LruCache<K, Slot<V>>, key, slot| index.put(key, slot),
+                BufReader::new(file),
+                (&key_codec, &SlotCodec),
+                |_| Ok(()),
+            )
+            .map_err(io::Error::from)?,
+            Err(err) if err.kind() == ErrorKind::NotFound => LruCache::new(max_entries),
+            Err(err) => return Err(err),
+        };
+        let mut cache = DiskCache {
+            index,
+            dir,
+            max_bytes,
+            total_bytes: 0,
+            next_file: 0,
+            key_codec,
+            value_codec,
+            loaded: RefCell::new(Vec::new()),
+            sync_on_drop: Self::sync_index,
+        };
+        cache.reconcile()?;
+        Ok(cache)
+    }
+
+    /// Aligne l'index sur le contenu du répertoire (après un arrêt brutal).
+    fn reconcile(&mut self) -> io::Result<()> {
+        let mut on_disk = std::collections::HashMap::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != ENTRY_EXTENSION) {
+                continue;
+            }
+            match path.file_stem().and_then(|stem| stem.to_str()?.parse::<u64>().ok()) {
+                Some(file) => {
+                    on_disk.insert(file, fs::metadata(&path)?.len());
+                }
+                None => remove_file(&path)?,
+            }
+        }
+        self.index.remove_where(|node| on_disk.get(&node.value.file) != Some(&node.value.size));
+        for (_, slot) in self.index.iter() {
+            on_disk.remove(&slot.file);
+        }
+        for file in on_disk.into_keys() {
+            remove_file(&self.file_path(file))?;
+        }
+        self.total_bytes = self.index.iter().map(|(_, slot)| slot.size).sum();
+        self.next_file = self.index.iter().map(|(_, slot)| slot.file + 1).max().unwrap_or(0);
+        self.evict_over_budget()
+    }
+
+    fn file_path(&self, file: u64) -> PathBuf {
+        self.dir.join(format!("{file}.{ENTRY_EXTENSION}"))
+    }
+
+    /// Libère les valeurs décodées par les lectures précédentes.
+    fn release_loaded(&mut self) {
+        for key in self.loaded.get_mut().drain(..) {
+            if let Some(index) = self.index.find(&key) {
+                self.index.arena[index].value.value.take();
+            }
+        }
+    }
+
+    /// Décode au besoin la valeur de `slot` et la retourne.
+    fn load<'a>(&self, key: &K, slot: &'a Slot<V>) -> Option<&'a V> {
+        if slot.value.get().is_none() {
+            let text = fs::read_to_string(self.file_path(slot.file)).ok()?;
+            let value = self.value_codec.decode_value(&text).ok()?;
+            let _ = slot.value.set(value);
+            self.loaded.borrow_mut().push(key.clone());
+        }
+        slot.value.get()
+    }
+
+    /// Supprime l'entrée la plus ancienne et son fichier.
+    fn evict_oldest(&mut self) -> io::Result<bool> {
+        let Some((_, slot)) = self.index.drain_lru(1).pop() else {
+            return Ok(false);
+        };
+        self.total_bytes -= slot.size;
+        remove_file(&self.file_path(slot.file))?;
+        Ok(true)
+    }
+
+    fn evict_over_budget(&mut self) -> io::Result<()> {
+        while self.total_bytes > self.max_bytes && self.evict_oldest()? {}
+        Ok(())
+    }
+
+    /// Écrit `value` dans un nouveau fichier puis évince les entrées les
+    /// plus anciennes au-delà des limites. Une valeur plus grande que
+    /// `max_bytes` n'est pas conservée.
+    pub fn try_put(&mut self, key: K, value: V) -> io::Result<()> {
+        self.release_loaded();
+        let encoded = self.value_codec.encode_value(&value);
+        let size = encoded.len() as u64;
+        self.try_remove_file_of(&key)?;
+        if size > self.max_bytes {
+            return Ok(());
+        }
+        let file = self.next_file;
+        self.next_file += 1;
+        fs::write(self.file_path(file), encoded)?;
+        if self.index.is_full() {
+            self.evict_oldest()?;
+        }
+        self.total_bytes += size;
+        self.index.put(key, Slot { file, size, value: OnceCell::new() });
+        self.evict_over_budget()
+    }
+
+    /// Retire `key` de l'index et supprime son fichier, sans le lire.
+    fn try_remove_file_of(&mut self, key: &K) -> io::Result<Option<Slot<V>>> {
+        let Some(slot) = self.index.remove(key) else {
+            return Ok(None);
+        };
+        self.total_bytes -= slot.size;
+        remove_file(&self.file_path(slot.file))?;
+        Ok(Some(slot))
+    }
+
+    /// Supprime `key` et retourne sa valeur, lue depuis le disque.
+    pub fn try_remove(&mut self, key: &K) -> io::Result<Option<V>> {
+        self.release_loaded();
+        let Some(slot) = self.index.peek(key) else {
+            return Ok(None);
+        };
+        let text = fs::read_to_string(self.file_path(slot.file))?;
+        let value = self
+            .value_codec
+            .decode_value(&text)
+            .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+        self.try_remove_file_of(key)?;
+        Ok(Some(value))
+    }
+
+    /// Supprime toutes les entrées et leurs fichiers.
+    pub fn try_clear(&mut self) -> io::Result<()> {
+        self.release_loaded();
+        while self.evict_oldest()? {}
+        self.sync_index()
+    }
+
+    /// Réécrit le fichier d'index (atomiquement).
+    pub fn sync_index(&self) -> io::Result<()> {
+        let count = self.index.len();
+        let entries = self.index.iter().rev();
+        let codecs = (&self.key_codec, &SlotCodec);
+        let path = self.dir.join(INDEX_FILE);
+        let path = path
+            .to_str()
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "chemin non UTF-8"))?;
+        write_atomically(
+            path,
+            |file| write_text(file, None, count, entries, codecs),
+        )
+    }
+
+    /// Taille totale des valeurs stockées, en octets.
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+}
+
+impl<K, V, KC, VC> Cache<K, V> for DiskCache<K, V, KC, VC>
+where
+    K: Hash + Eq + Clone,
+    KC: KeyCodec<K>,
+    VC: ValueCodec<V>,
+{
+    fn put(&mut self, key: K, value: V) {
+        let _ = self.try_put(key, value);
+    }
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        self.release_loaded();
+        self.index.get(key)?;
+        let slot = self.index.peek(key)?;
+        self.load(key, slot)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        let slot = self.index.peek(key)?;
+        self.load(key, slot)
+    }
+
+    fn contains(&self, key: &K) -> bool {
+        self.index.contains(key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.try_remove(key).ok().flatten()
+    }
+
+    fn clear(&mut self) {
+        let _ = self.try_clear();
+    }
+
+    fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.index.capacity()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        Box::new(self.index.iter().filter_map(|(key, slot)| Some((key, self.load(key, slot)?))))
+    }
+}
+
+impl<K, V, KC, VC> Drop for DiskCache<K, V, KC, VC> {
+    fn drop(&mut self) {
+        let _ = (self.sync_on_drop)(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("lru_disk_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_size_budget_evicts_oldest() {
+        let dir = temp_dir("budget");
+        let mut cache: DiskCache<u32, String> = DiskCache::open(&dir, 100, 25).unwrap();
+        for i in 0..5 {
+            cache.put(i, format!("valeur_{i}"));
+        }
+        // Trois valeurs de 8 octets tiennent dans 25 octets.
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.total_bytes(), 24);
+        assert!(!cache.contains(&1));
+        assert_eq!(cache.get(&2).map(String::as_str), Some("valeur_2"));
+        cache.put(5, "valeur_5".to_string());
+        assert!(cache.contains(&2));
+        assert!(!cache.contains(&3));
+        cache.put(6, "x".repeat(100));
+        assert!(!cache.contains(&6));
+        assert_eq!(cache.remove(&2).as_deref(), Some("valeur_2"));
+        assert_eq!(cache.len(), 2);
+        drop(cache);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reopen_restores_index() {
+        let dir = temp_dir("reopen");
+        {
+            let mut cache: DiskCache<String, u32> = DiskCache::open(&dir, 10, 1000).unwrap();
+            cache.put("a=b".to_string(), 1);
+            cache.put("c".to_string(), 2);
+            cache.get(&"a=b".to_string());
+        }
+        // Un fichier orphelin (arrêt brutal) est supprimé à l'ouverture.
+        fs::write(dir.join("99.entry"), "3").unwrap();
+
+        let cache: DiskCache<String, u32> = DiskCache::open(&dir, 10, 1000).unwrap();
+        let entries: Vec<_> = Cache::iter(&cache).map(|(k, v)| (k.clone(), *v)).collect();
+        assert_eq!(entries, vec![("a=b".to_string(), 1), ("c".to_string(), 2)]);
+        assert!(!dir.join("99.entry").exists());
+        drop(cache);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
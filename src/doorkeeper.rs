@@ -0,0 +1,113 @@
+use std::hash::Hash;
+
+use crate::cache::LruCache;
+
+/// Nombre de bits du filtre par clé de la fenêtre (~3 % de faux positifs avec 3 sondes).
+const BITS_PER_KEY: usize = 8;
+const PROBES: u64 = 3;
+
+/// Filtre de Bloom d'admission ("doorkeeper").
+///
+/// # Architecture
+/// * **Bits** : `window × 8` bits (puissance de deux), 3 sondes dérivées du
+///   hash par double hachage.
+/// * **Fenêtre** : Après `window` nouvelles clés enregistrées, le filtre est
+///   vidé ; une clé doit donc revenir dans la même fenêtre pour être admise.
+/// * **Faux positifs** : Une clé jamais vue peut être admise d'emblée,
+///   jamais l'inverse.
+pub(crate) struct Doorkeeper {
+    bits: Vec<u64>,
+    recorded: usize,
+    window: usize,
+}
+
+impl Doorkeeper {
+    pub(crate) fn new(window: usize) -> Self {
+        let bits = (window.max(1) * BITS_PER_KEY).max(64).next_power_of_two();
+        Doorkeeper {
+            bits: vec![0; bits / 64],
+            recorded: 0,
+            window: window.max(1),
+        }
+    }
+
+    /// Indique si le hash a déjà été vu dans la fenêtre ; sinon l'enregistre.
+    pub(crate) fn admit(&mut self, hash: u64) -> bool {
+        let mask = self.bits.len() as u64 * 64 - 1;
+        let step = (hash >> 32) | 1;
+        let positions = (0..PROBES).map(|i| hash.wrapping_add(i.wrapping_mul(step)) & mask);
+        let mut seen = true;
+        for bit in positions {
+            let word = &mut self.bits[(bit / 64) as usize];
+            seen &= *word & (1 << (bit % 64)) != 0;
+            *word |= 1 << (bit % 64);
+        }
+        if !seen {
+            self.recorded += 1;
+            if self.recorded >= self.window {
+                self.bits.fill(0);
+                self.recorded = 0;
+            }
+        }
+        seen
+    }
+}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Décide si une écriture de `key` entre dans le cache : toujours pour
+    /// une clé présente, à sa deuxième demande dans la fenêtre sinon.
+    pub(crate) fn admit(&mut self, key: &K) -> bool {
+        if self.find(key).is_some() {
+            return true;
+        }
+        let hash = self.index.hash(key);
+        self.doorkeeper.as_mut().is_none_or(|doorkeeper| doorkeeper.admit(hash))
+    }
+
+    /// Exécute `write` sans filtre d'admission : pour les écritures dont
+    /// l'appelant attend qu'elles restent en cache (chargement, pré-remplissage).
+    pub(crate) fn without_admission<R>(&mut self, write: impl FnOnce(&mut Self) -> R) -> R {
+        let doorkeeper = self.doorkeeper.take();
+        let result = write(self);
+        self.doorkeeper = doorkeeper;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::Cache;
+
+    #[test]
+    fn test_scan_does_not_flush_cache() {
+        let mut cache = LruCache::builder(10).doorkeeper(1000).build();
+        for round in 0..2 {
+            for key in 0..10 {
+                cache.put(key, round);
+            }
+        }
+        assert_eq!(cache.len(), 10);
+        // Un balayage de clés vues une seule fois ne chasse rien.
+        for key in 100..200 {
+            cache.put(key, 0);
+        }
+        assert_eq!(cache.len(), 10);
+        assert!((0..10).all(|key| cache.get(&key) == Some(&1)));
+        // Une clé présente se met à jour dès la première écriture.
+        cache.put(3, 7);
+        assert_eq!(cache.peek(&3), Some(&7));
+    }
+
+    #[test]
+    fn test_window_forgets_first_requests() {
+        let mut doorkeeper = Doorkeeper::new(4);
+        assert!(!doorkeeper.admit(1));
+        assert!(doorkeeper.admit(1));
+        for hash in [0x9E37_79B9_7F4A_7C15, 0xC2B2_AE3D_27D4_EB4F, 0x1656_67B1_9E37_79F9] {
+            doorkeeper.admit(hash);
+        }
+        // La fenêtre de 4 clés est écoulée : le filtre est vidé.
+        assert!(!doorkeeper.admit(1));
+    }
+}
@@ -0,0 +1,114 @@
+use std::hash::Hash;
+use std::time::Instant;
+
+use crate::cache::{Cache, LruCache};
+use crate::listener::RemovalCause;
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Retire et retourne les `n` entrées les moins récemment utilisées,
+    /// de la plus ancienne à la plus récente.
+    ///
+    /// Les entrées épinglées sont conservées ; les entrées retirées sont
+    /// notifiées avec [`RemovalCause::Explicit`].
+    ///
+    /// # Complexité
+    /// O(n) (plus le nombre d'entrées épinglées traversées).
+    pub fn drain_lru(&mut self, n: usize) -> Vec<(K, V)> {
+        self.apply_buffered_reads();
+        let mut drained = Vec::with_capacity(n.min(self.arena.len()));
+        let mut current = self.tail;
+        while let Some(index) = current {
+            if drained.len() == n {
+                break;
+            }
+            if self.arena[index].pinned {
+                current = self.arena[index].prev;
+                continue;
+            }
+            let prev = self.arena[index].prev;
+            // `swap_remove` déplace le dernier nœud de l'arena à `index`.
+            let moved_from = self.arena.len() - 1;
+            let entry = self.remove_at(index);
+            self.notify_removal(&entry.0, &entry.1, RemovalCause::Explicit);
+            drained.push(entry);
+            current = prev.map(|prev| if prev == moved_from { index } else { prev });
+        }
+        #[cfg(feature = "metrics")]
+        self.emit_size();
+        drained
+    }
+
+    /// Retire les entrées les moins récemment utilisées jusqu'à n'en garder
+    /// que `len`, et retourne le nombre d'entrées retirées.
+    ///
+    /// Comme [`drain_lru`](Self::drain_lru) : les entrées épinglées sont
+    /// conservées, les autres notifiées avec [`RemovalCause::Explicit`].
+    /// Suivi de [`shrink_to_fit`](Self::shrink_to_fit), rend la mémoire
+    /// libérée au reste du programme.
+    pub fn truncate(&mut self, len: usize) -> usize {
+        let excess = self.len().saturating_sub(len);
+        self.drain_lru(excess).len()
+    }
+
+    /// Retire toutes les entrées ni lues ni écrites depuis `cutoff`, et
+    /// retourne leur nombre. Les entrées épinglées sont conservées.
+    ///
+    /// # Complexité
+    /// O(n) : parcours complet de l'arena.
+    pub fn evict_older_than(&mut self, cutoff: Instant) -> usize {
+        self.apply_buffered_reads();
+        self.remove_where(|node| !node.pinned && node.last_access < cutoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Clock, MockClock};
+    use std::time::Duration;
+
+    #[test]
+    fn test_drain_oldest_entries() {
+        let mut cache = LruCache::new(5);
+        for i in 0..5 {
+            cache.put(i, i * 10);
+        }
+        cache.get(&0);
+        assert_eq!(cache.drain_lru(2), vec![(1, 10), (2, 20)]);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.drain_lru(10), vec![(3, 30), (4, 40), (0, 0)]);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_drain_skips_pinned() {
+        let mut cache = LruCache::new(3);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        cache.pin("a");
+        assert_eq!(cache.drain_lru(2), vec![("b", 2), ("c", 3)]);
+        assert!(cache.contains(&"a"));
+    }
+
+    #[test]
+    fn test_truncate_and_evict_older_than() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(10).clock(clock.clone()).build();
+        for i in 0..6 {
+            cache.put(i, i);
+            clock.advance(Duration::from_secs(1));
+        }
+        cache.get(&0);
+        assert_eq!(cache.evict_older_than(clock.now() - Duration::from_secs(3)), 2);
+        let keys: Vec<_> = cache.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, [0, 5, 4, 3]);
+
+        assert_eq!(cache.truncate(1), 3);
+        assert_eq!(cache.peek(&0), Some(&0));
+        assert_eq!(cache.truncate(5), 0);
+    }
+}
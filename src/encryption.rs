@@ -0,0 +1,65 @@
+use std::io;
+
+/// Chiffrement authentifié des fichiers de sauvegarde.
+///
+/// Configuré via [`LruCacheBuilder::cipher`](crate::LruCacheBuilder::cipher),
+/// il s'applique à [`save_to_file`](crate::LruCache::save_to_file) et à
+/// [`build_persistent`](crate::LruCacheBuilder::build_persistent).
+/// L'implémentation (AES-GCM, ChaCha20-Poly1305...) et la gestion de la clé
+/// ou de sa dérivation depuis une phrase secrète sont laissées à l'appelant,
+/// qui choisit une bibliothèque éprouvée.
+pub trait Cipher: Send + Sync {
+    /// Chiffre le contenu complet d'un fichier (nonce et tag compris dans le résultat).
+    fn encrypt(&self, plaintext: &[u8]) -> io::Result<Vec<u8>>;
+
+    /// Déchiffre et authentifie `ciphertext`. Un contenu altéré ou une
+    /// mauvaise clé doivent produire une erreur, jamais des données.
+    fn decrypt(&self, ciphertext: &[u8]) -> io::Result<Vec<u8>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cache, LruCache};
+
+    /// Chiffrement jouet (XOR + somme) : uniquement pour les tests.
+    struct XorCipher(u8);
+
+    impl Cipher for XorCipher {
+        fn encrypt(&self, plaintext: &[u8]) -> io::Result<Vec<u8>> {
+            let mut out: Vec<u8> = plaintext.iter().map(|b| b ^ self.0).collect();
+            out.push(plaintext.iter().fold(self.0, |acc, b| acc.wrapping_add(*b)));
+            Ok(out)
+        }
+
+        fn decrypt(&self, ciphertext: &[u8]) -> io::Result<Vec<u8>> {
+            let (tag, data) = ciphertext.split_last().ok_or(io::ErrorKind::InvalidData)?;
+            let plain: Vec<u8> = data.iter().map(|b| b ^ self.0).collect();
+            if plain.iter().fold(self.0, |acc, b| acc.wrapping_add(*b)) != *tag {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "authentification échouée"));
+            }
+            Ok(plain)
+        }
+    }
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let path = std::env::temp_dir().join(format!("lru_encrypted_{}.bin", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut cache = LruCache::builder(3).cipher(XorCipher(0x5a)).build();
+        cache.put("secret".to_string(), "valeur".to_string());
+        cache.save_to_file(path).unwrap();
+        let raw = std::fs::read(path).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains("secret"));
+
+        let loaded: LruCache<String, String> =
+            LruCache::builder(3).cipher(XorCipher(0x5a)).build_persistent(path).unwrap();
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+
+        let wrong_key = LruCache::<String, String>::builder(3)
+            .cipher(XorCipher(0x11))
+            .build_persistent(path);
+        assert!(wrong_key.is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Erreur de chargement d'un cache persisté.
+#[derive(Debug)]
+pub enum CacheError {
+    /// Erreur d'entrée/sortie (fichier illisible, flux interrompu...).
+    Io(io::Error),
+    /// Ligne du fichier impossible à interpréter.
+    Parse {
+        /// Numéro de la ligne fautive (à partir de 1).
+        line: usize,
+        /// Description du problème, avec le texte concerné.
+        message: String,
+    },
+    /// Fichier écrit par une version plus récente du format.
+    UnsupportedVersion(u32),
+    /// Le fichier ne contient pas le nombre d'entrées annoncé par son en-tête.
+    Truncated {
+        /// Nombre d'entrées annoncé.
+        expected: usize,
+        /// Nombre d'entrées lues.
+        found: usize,
+    },
+    /// Données altérées : somme de contrôle absente ou différente.
+    Corrupted(String),
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheError::Io(err) => write!(f, "erreur d'entrée/sortie : {err}"),
+            CacheError::Parse { line, message } => write!(f, "ligne {line} : {message}"),
+            CacheError::UnsupportedVersion(version) => {
+                write!(f, "version de format {version} non supportée")
+            }
+            CacheError::Truncated { expected, found } => {
+                write!(f, "{found} entrées lues sur {expected} annoncées")
+            }
+            CacheError::Corrupted(message) => write!(f, "données corrompues : {message}"),
+        }
+    }
+}
+
+impl Error for CacheError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CacheError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CacheError {
+    fn from(err: io::Error) -> Self {
+        CacheError::Io(err)
+    }
+}
+
+impl From<CacheError> for io::Error {
+    fn from(err: CacheError) -> Self {
+        match err {
+            CacheError::Io(err) => err,
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::hash::Hash;
+
+use crate::cache::LruCache;
+use crate::listener::RemovalCause;
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Supprime immédiatement toutes les entrées expirées et retourne leur nombre.
+    ///
+    /// Sans cet appel, une entrée expirée jamais relue continue d'occuper
+    /// de la capacité jusqu'à ce qu'elle atteigne la queue de la liste.
+    ///
+    /// # Complexité
+    /// O(n) : parcours complet de l'arena.
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.now();
+        let mut purged = 0;
+        // Parcours à rebours : le `swap_remove` de `evict_at` ramène à
+        // l'index courant un nœud déjà examiné.
+        for index in (0..self.arena.len()).rev() {
+            if self.arena[index].is_expired(now, self.tti) {
+                self.evict_at(index, RemovalCause::Expired);
+                purged += 1;
+            }
+        }
+        purged
+    }
+}
+
+#[cfg(feature = "timer-wheel")]
+mod sweeper {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    use crate::cache::Node;
+    use crate::wheel::TimerWheel;
+
+    /// Nombre maximum d'échéances traitées par écriture, pour lisser la latence.
+    const MAX_EXPIRATIONS_PER_WRITE: usize = 32;
+
+    impl<K, V> Node<K, V> {
+        /// Prochaine échéance effective de l'entrée : la plus proche entre sa
+        /// date d'expiration et la fin de son délai d'inactivité.
+        fn deadline(&self, tti: Option<Duration>) -> Option<Instant> {
+            let idle = tti.map(|tti| self.last_access + tti);
+            match (self.expires_at, idle) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            }
+        }
+    }
+
+    /// Expiration incrémentale : une roue temporelle indexe les échéances par
+    /// empreinte de clé, et chaque écriture purge une partie des entrées échues.
+    pub(crate) struct Sweeper {
+        wheel: TimerWheel,
+        due: Vec<u64>,
+    }
+
+    impl Sweeper {
+        pub(crate) fn new(origin: Instant, resolution: Duration) -> Self {
+            Sweeper {
+                wheel: TimerWheel::new(origin, resolution),
+                due: Vec::new(),
+            }
+        }
+
+        pub(crate) fn clear(&mut self) {
+            self.wheel.clear();
+            self.due.clear();
+        }
+    }
+
+    impl<K, V> LruCache<K, V>
+    where
+        K: Hash + Eq,
+    {
+        /// Programme l'échéance de l'entrée écrite, puis purge les entrées échues
+        /// (au plus `MAX_EXPIRATIONS_PER_WRITE`).
+        pub(crate) fn sweep_after_write(&mut self, index: usize) {
+            self.schedule_expiry(index);
+            self.sweep_due();
+        }
+
+        /// Programme l'échéance de l'entrée à `index` dans la roue.
+        pub(crate) fn schedule_expiry(&mut self, index: usize) {
+            let deadline = self.arena[index].deadline(self.tti);
+            let hash = self.index.hash_of(index);
+            if let Some(sweeper) = self.sweeper.as_mut()
+                && let Some(deadline) = deadline
+            {
+                sweeper.wheel.schedule(deadline, hash);
+            }
+        }
+
+        /// Purge les entrées échues (au plus `MAX_EXPIRATIONS_PER_WRITE`).
+        pub(crate) fn sweep_due(&mut self) {
+            let now = self.clock.now();
+            let Some(sweeper) = self.sweeper.as_mut() else {
+                return;
+            };
+            sweeper.wheel.advance(now, &mut sweeper.due);
+
+            for _ in 0..MAX_EXPIRATIONS_PER_WRITE {
+                let Some(hash) = self.sweeper.as_mut().and_then(|s| s.due.pop()) else {
+                    break;
+                };
+                for slot in self.index.slots_with_hash(hash) {
+                    let node = &self.arena[slot];
+                    if node.is_expired(now, self.tti) {
+                        self.evict_at(slot, RemovalCause::Expired);
+                    } else if let Some(next) = node.deadline(self.tti)
+                        && let Some(sweeper) = self.sweeper.as_mut()
+                    {
+                        // Échéance repoussée (lecture récente ou réécriture).
+                        sweeper.wheel.schedule(next, hash);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "timer-wheel")]
+pub(crate) use sweeper::Sweeper;
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, LruCache, MockClock};
+    use std::time::Duration;
+
+    #[test]
+    fn test_purge_expired_frees_capacity() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(4).clock(clock.clone()).build();
+        cache.put_with_ttl("a", 1, Duration::from_secs(10));
+        cache.put("b", 2);
+        cache.put_with_ttl("c", 3, Duration::from_secs(10));
+        cache.put_with_ttl("d", 4, Duration::from_secs(100));
+        clock.advance(Duration::from_secs(20));
+        assert_eq!(cache.purge_expired(), 2);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"b"), Some(&2));
+        assert_eq!(cache.get(&"d"), Some(&4));
+    }
+
+    #[cfg(feature = "timer-wheel")]
+    #[test]
+    fn test_writes_sweep_expired_entries() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(100)
+            .ttl(Duration::from_secs(5))
+            .incremental_expiration(Duration::from_secs(1))
+            .clock(clock.clone())
+            .build();
+        for key in 0..10 {
+            cache.put(key, key);
+        }
+        clock.advance(Duration::from_secs(10));
+        cache.put(100, 100);
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -0,0 +1,281 @@
+//! Interface C : un cache LRU de tableaux d'octets derrière un pointeur opaque.
+//!
+//! Les déclarations correspondantes sont dans `include/lru_cache.h`. La
+//! bibliothèque se construit avec
+//! `cargo rustc --lib --release --features ffi --crate-type cdylib` (ou `staticlib`).
+//!
+//! Aucune panique ne traverse la frontière C : elle est rattrapée et
+//! signalée par la valeur d'erreur de la fonction (NULL ou -1).
+
+use std::ffi::{CStr, c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::backend::FileBackend;
+use crate::cache::{Cache, LruCache};
+use crate::codec::HexCodec;
+
+/// Cache manipulé côté C (type opaque `LruCache` du header).
+pub type ByteCache = LruCache<Vec<u8>, Vec<u8>>;
+
+/// Vue sur `len` octets à partir de `data` (`data` peut être nul si `len` vaut 0).
+///
+/// # Safety
+/// `data` doit désigner `len` octets lisibles pendant la durée `'a`.
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        return &[];
+    }
+    // SAFETY : garanti par l'appelant.
+    unsafe { std::slice::from_raw_parts(data, len) }
+}
+
+/// Exécute `body`, ou retourne `on_panic` s'il panique.
+fn guarded<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(on_panic)
+}
+
+/// Crée un cache de `capacity` entrées. Retourne NULL si `capacity` vaut 0.
+#[unsafe(no_mangle)]
+pub extern "C" fn cache_new(capacity: usize) -> *mut ByteCache {
+    guarded(ptr::null_mut(), || {
+        if capacity == 0 {
+            return ptr::null_mut();
+        }
+        Box::into_raw(Box::new(LruCache::new(capacity)))
+    })
+}
+
+/// Recharge un cache sauvegardé par [`cache_save`]. Un fichier absent donne
+/// un cache vide ; NULL si le fichier est illisible ou `capacity` vaut 0.
+///
+/// # Safety
+/// `path` doit être une chaîne C valide.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cache_load(capacity: usize, path: *const c_char) -> *mut ByteCache {
+    guarded(ptr::null_mut(), || {
+        // SAFETY : garanti par l'appelant.
+        let Ok(path) = unsafe { CStr::from_ptr(path) }.to_str() else {
+            return ptr::null_mut();
+        };
+        if capacity == 0 {
+            return ptr::null_mut();
+        }
+        let mut backend = FileBackend::with_codecs(path, HexCodec, HexCodec);
+        match LruCache::from_backend(capacity, &mut backend) {
+            Ok(cache) => Box::into_raw(Box::new(cache)),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+/// Insère (ou remplace) une entrée ; les octets sont copiés. Retourne 0 en
+/// cas de succès, -1 en cas d'erreur.
+///
+/// # Safety
+/// `cache` doit venir de [`cache_new`] ou [`cache_load`] ; `key` et `value`
+/// doivent désigner respectivement `key_len` et `value_len` octets.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cache_put(
+    cache: *mut ByteCache,
+    key: *const u8,
+    key_len: usize,
+    value: *const u8,
+    value_len: usize,
+) -> c_int {
+    guarded(-1, || {
+        // SAFETY : garanti par l'appelant.
+        let (cache, key, value) = unsafe { (&mut *cache, bytes(key, key_len), bytes(value, value_len)) };
+        cache.put(key.to_vec(), value.to_vec());
+        0
+    })
+}
+
+/// Cherche `key` et la marque comme récente. Retourne 1 et renseigne
+/// `*value` / `*value_len` si elle est présente, 0 sinon, -1 en cas d'erreur.
+///
+/// Les octets pointés appartiennent au cache et restent valides jusqu'au
+/// prochain appel modifiant le cache.
+///
+/// # Safety
+/// Comme [`cache_put`] ; `value` et `value_len` doivent être inscriptibles.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cache_get(
+    cache: *mut ByteCache,
+    key: *const u8,
+    key_len: usize,
+    value: *mut *const u8,
+    value_len: *mut usize,
+) -> c_int {
+    guarded(-1, || {
+        // SAFETY : garanti par l'appelant.
+        let (cache, key) = unsafe { (&mut *cache, bytes(key, key_len)) };
+        // Recherche par `&[u8]` (`Vec<u8>: Borrow<[u8]>`), sans copier la clé.
+        let Some(index) = cache.find_live(key) else {
+            return 0;
+        };
+        cache.promote(index);
+        let found = &cache.arena[index].value;
+        // SAFETY : garanti par l'appelant.
+        unsafe {
+            *value = found.as_ptr();
+            *value_len = found.len();
+        }
+        1
+    })
+}
+
+/// Supprime `key`. Retourne 1 si elle était présente, 0 sinon, -1 en cas d'erreur.
+///
+/// # Safety
+/// Comme [`cache_put`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cache_remove(cache: *mut ByteCache, key: *const u8, key_len: usize) -> c_int {
+    guarded(-1, || {
+        // SAFETY : garanti par l'appelant.
+        let (cache, key) = unsafe { (&mut *cache, bytes(key, key_len)) };
+        c_int::from(cache.pop_entry(key).is_some())
+    })
+}
+
+/// Sauvegarde le cache dans `path` (octets encodés en hexadécimal).
+/// Retourne 0 en cas de succès, -1 en cas d'erreur.
+///
+/// # Safety
+/// `cache` doit venir de [`cache_new`] ou [`cache_load`], et `path` être
+/// une chaîne C valide.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cache_save(cache: *const ByteCache, path: *const c_char) -> c_int {
+    guarded(-1, || {
+        // SAFETY : garanti par l'appelant.
+        let (cache, path) = unsafe { (&*cache, CStr::from_ptr(path)) };
+        let Ok(path) = path.to_str() else {
+            return -1;
+        };
+        let mut backend = FileBackend::with_codecs(path, HexCodec, HexCodec);
+        match cache.save_to_backend(&mut backend) {
+            Ok(()) => 0,
+            Err(_) => -1,
+        }
+    })
+}
+
+/// Libère le cache. NULL est accepté ; une panique d'un destructeur est ignorée.
+///
+/// # Safety
+/// `cache` doit venir de [`cache_new`] ou [`cache_load`], et ne plus être
+/// utilisé ensuite.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cache_free(cache: *mut ByteCache) {
+    guarded((), || {
+        if !cache.is_null() {
+            // SAFETY : garanti par l'appelant.
+            drop(unsafe { Box::from_raw(cache) });
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_api_round_trip() {
+        let path = std::env::temp_dir().join(format!("lru_ffi_{}.txt", std::process::id()));
+        let c_path = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        assert!(cache_new(0).is_null());
+        unsafe {
+            let cache = cache_new(2);
+            assert_eq!(cache_put(cache, b"a\0b".as_ptr(), 3, b"\xff\n".as_ptr(), 2), 0);
+            assert_eq!(cache_put(cache, b"c".as_ptr(), 1, ptr::null(), 0), 0);
+            let (mut value, mut len) = (ptr::null(), 0);
+            assert_eq!(cache_get(cache, b"a\0b".as_ptr(), 3, &mut value, &mut len), 1);
+            assert_eq!(std::slice::from_raw_parts(value, len), b"\xff\n");
+            assert_eq!(cache_save(cache, c_path.as_ptr()), 0);
+            assert_eq!(cache_remove(cache, b"c".as_ptr(), 1), 1);
+            assert_eq!(cache_remove(cache, b"c".as_ptr(), 1), 0);
+            cache_free(cache);
+
+            let loaded = cache_load(2, c_path.as_ptr());
+            assert_eq!(cache_get(loaded, b"c".as_ptr(), 1, &mut value, &mut len), 1);
+            assert_eq!(len, 0);
+            cache_free(loaded);
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_panic_becomes_error_code() {
+        assert_eq!(guarded(-1, || panic!("panique côté Rust")), -1);
+        assert!(guarded(ptr::null_mut::<ByteCache>(), || panic!("panique côté Rust")).is_null());
+        assert_eq!(guarded(-1, || 0), 0);
+    }
+
+    /// Type C correspondant à un type Rust des signatures exportées.
+    fn c_type(rust: &str) -> &'static str {
+        match rust {
+            "usize" => "size_t",
+            "c_int" => "int",
+            "*const u8" => "const uint8_t *",
+            "*mut *const u8" => "const uint8_t **",
+            "*mut usize" => "size_t *",
+            "*const c_char" => "const char *",
+            "*mut ByteCache" => "LruCache *",
+            "*const ByteCache" => "const LruCache *",
+            other => panic!("type sans équivalent C : {other}"),
+        }
+    }
+
+    /// Signatures `retour nom(types)` sans espaces, triées par nom.
+    fn normalized(mut signatures: Vec<(String, String)>) -> Vec<(String, String)> {
+        signatures.sort();
+        for (_, signature) in &mut signatures {
+            signature.retain(|c| !c.is_whitespace());
+        }
+        signatures
+    }
+
+    #[test]
+    fn test_header_matches_exports() {
+        let source = include_str!("ffi.rs");
+        let exports = &source[..source.find("#[cfg(test)]").unwrap()];
+        let marker = concat!("extern \"C\"", " fn ");
+        let rust = exports.split(marker).skip(1).map(|item| {
+            let (name, rest) = item.split_once('(').unwrap();
+            let (params, rest) = rest.split_once(')').unwrap();
+            let params: Vec<_> = params
+                .split(',')
+                .filter_map(|param| param.split_once(':'))
+                .map(|(_, ty)| c_type(ty.trim()))
+                .collect();
+            let ret = rest.split_once('{').unwrap().0.trim();
+            let ret = ret.strip_prefix("->").map_or("void", |ty| c_type(ty.trim()));
+            (name.to_owned(), format!("{ret} {name}({})", params.join(",")))
+        });
+
+        let mut header = include_str!("../include/lru_cache.h").to_owned();
+        while let Some(start) = header.find("/*") {
+            let end = start + header[start..].find("*/").unwrap() + 2;
+            header.replace_range(start..end, "");
+        }
+        let header: String = header.lines().filter(|line| !line.starts_with('#')).collect();
+        let trailing_ident = |text: &str| {
+            let text = text.trim();
+            let start = text.rfind(|c: char| !(c.is_alphanumeric() || c == '_')).map_or(0, |i| i + 1);
+            (text[..start].to_owned(), text[start..].to_owned())
+        };
+        let c = header.split(';').filter(|decl| decl.contains('(')).map(|decl| {
+            let (head, params) = decl.split_once('(').unwrap();
+            let (ret, name) = trailing_ident(head);
+            let params: Vec<_> = params
+                .trim_end()
+                .trim_end_matches(')')
+                .split(',')
+                .map(|param| trailing_ident(param).0)
+                .collect();
+            (name.clone(), format!("{ret} {name}({})", params.join(",")))
+        });
+
+        assert_eq!(normalized(c.collect()), normalized(rust.collect()));
+    }
+}
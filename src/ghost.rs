@@ -0,0 +1,135 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::cache::{Cache, LruCache};
+
+/// Part des hits fantômes que la capacité suggérée doit couvrir.
+const COVERED_PERCENT: u64 = 90;
+
+/// Mémoire bornée des clés récemment évincées, pour estimer ce qu'un
+/// cache plus grand aurait servi.
+///
+/// # Architecture
+/// * **Membres** : `hash -> numéro d'éviction` ; seules les empreintes sont
+///   gardées, pas les clés ni les valeurs.
+/// * **File** : `(hash, numéro)` dans l'ordre d'éviction, pour oublier les
+///   plus anciennes au-delà de `capacity` ; les entrées retrouvées y restent
+///   jusqu'à leur passage en tête (suppression paresseuse).
+/// * **Profondeurs** : `depths[d - 1]` compte les misses qui auraient été des
+///   hits avec `d` places de plus (nombre d'évictions depuis celle de la clé).
+pub(crate) struct GhostTracker {
+    members: HashMap<u64, u64>,
+    queue: VecDeque<(u64, u64)>,
+    capacity: usize,
+    evictions: u64,
+    depths: Vec<u64>,
+}
+
+impl GhostTracker {
+    pub(crate) fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        GhostTracker {
+            members: HashMap::with_capacity(capacity),
+            queue: VecDeque::with_capacity(capacity),
+            capacity,
+            evictions: 0,
+            depths: vec![0; capacity],
+        }
+    }
+
+    /// Mémorise une clé évincée faute de place.
+    pub(crate) fn on_evict(&mut self, hash: u64) {
+        self.evictions += 1;
+        self.members.insert(hash, self.evictions);
+        self.queue.push_back((hash, self.evictions));
+        while self.members.len() > self.capacity {
+            let Some((oldest, number)) = self.queue.pop_front() else {
+                break;
+            };
+            if self.members.get(&oldest) == Some(&number) {
+                self.members.remove(&oldest);
+            }
+        }
+        if self.queue.len() > 2 * self.capacity {
+            let members = &self.members;
+            self.queue.retain(|(hash, number)| members.get(hash) == Some(number));
+        }
+    }
+
+    /// Indique si un miss porte sur une clé encore mémorisée (et l'oublie).
+    pub(crate) fn on_miss(&mut self, hash: u64) -> bool {
+        let Some(number) = self.members.remove(&hash) else {
+            return false;
+        };
+        let depth = (self.evictions - number) as usize + 1;
+        self.depths[depth.min(self.capacity) - 1] += 1;
+        true
+    }
+
+    /// Places supplémentaires qui auraient couvert 90 % des hits fantômes.
+    pub(crate) fn extra_capacity(&self) -> usize {
+        let total: u64 = self.depths.iter().sum();
+        let mut covered = 0;
+        for (i, count) in self.depths.iter().enumerate() {
+            covered += count;
+            if covered * 100 >= total * COVERED_PERCENT && total > 0 {
+                return i + 1;
+            }
+        }
+        0
+    }
+
+    pub(crate) fn reset_hits(&mut self) {
+        self.depths.fill(0);
+    }
+}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Capacité estimée qui aurait transformé en hits 90 % des misses sur
+    /// des clés récemment évincées (voir [`CacheStats::ghost_hits`](crate::CacheStats::ghost_hits)).
+    ///
+    /// Retourne la capacité actuelle sans suivi configuré
+    /// ([`ghost_capacity`](crate::LruCacheBuilder::ghost_capacity)) ou sans
+    /// hit fantôme : ajouter de la mémoire n'aiderait pas. L'estimation ne
+    /// voit pas au-delà du nombre de clés mémorisées.
+    pub fn suggested_capacity(&self) -> usize {
+        let extra = self.ghosts.as_ref().map_or(0, |ghosts| ghosts.extra_capacity());
+        self.capacity() + extra
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ghost_hits_counted_on_recent_evictions() {
+        let mut cache = LruCache::builder(2).record_stats().ghost_capacity(2).build();
+        for key in 0..6u32 {
+            cache.put(key, key);
+        }
+        // 0..4 évincées ; seules les 2 dernières sont mémorisées.
+        assert_eq!(cache.get(&0), None);
+        assert_eq!(cache.get(&3), None);
+        assert_eq!(cache.get(&99), None);
+        let stats = cache.stats();
+        assert_eq!((stats.misses, stats.ghost_hits), (3, 1));
+        // Déjà comptée : un second miss n'est plus un hit fantôme.
+        assert_eq!(cache.get(&3), None);
+        assert_eq!(cache.stats().ghost_hits, 1);
+    }
+
+    #[test]
+    fn test_suggested_capacity_covers_working_set() {
+        let mut cache = LruCache::builder(4).ghost_capacity(16).build();
+        assert_eq!(cache.suggested_capacity(), 4);
+        // Un cycle de 6 clés ne tient pas dans 4 places : il en faut 2 de plus.
+        for key in (0..6u32).cycle().take(60) {
+            if cache.get(&key).is_none() {
+                cache.put(key, ());
+            }
+        }
+        assert_eq!(cache.suggested_capacity(), 6);
+        assert_eq!(LruCache::<u32, ()>::new(4).suggested_capacity(), 4);
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::cache::{Cache, LruCache};
+use crate::listener::RemovalCause;
+use crate::stats::CacheStats;
+
+/// Statistiques par espace de noms, partagées avec le listener d'éviction.
+type SharedStats = Arc<Mutex<Vec<CacheStats>>>;
+
+struct GroupInner<K, V> {
+    /// Les clés sont préfixées par l'identifiant de leur espace de noms.
+    cache: LruCache<(u32, K), V>,
+    names: HashMap<String, u32>,
+}
+
+/// Plusieurs espaces de noms partageant un seul [`LruCache`] et sa capacité.
+///
+/// # Architecture
+/// * **Cache partagé** : Les entrées de tous les espaces sont en concurrence
+///   dans la même liste LRU ; un espace très actif peut donc évincer les
+///   entrées d'un autre.
+/// * **Portée des clés** : Chaque [`Namespace`] préfixe ses clés par un
+///   identifiant numérique, deux espaces peuvent utiliser la même clé.
+/// * **Statistiques** : Tenues par espace ; les évictions et expirations
+///   sont attribuées à l'espace de l'entrée retirée.
+///
+/// # Exemple
+/// ```
+/// use cache_lru_project::CacheGroup;
+///
+/// let group = CacheGroup::new(100);
+/// let sessions = group.namespace("sessions");
+/// let users = group.namespace("users");
+/// sessions.put("42", "token");
+/// users.put("42", "alice");
+/// assert_eq!(sessions.get(&"42"), Some("token"));
+/// assert_eq!(group.len(), 2);
+/// ```
+pub struct CacheGroup<K, V> {
+    inner: Arc<Mutex<GroupInner<K, V>>>,
+    stats: SharedStats,
+}
+
+/// Vue d'un espace de noms d'un [`CacheGroup`]. Clonable et partageable entre threads.
+pub struct Namespace<K, V> {
+    id: u32,
+    name: Arc<str>,
+    inner: Arc<Mutex<GroupInner<K, V>>>,
+    stats: SharedStats,
+}
+
+impl<K, V> Clone for Namespace<K, V> {
+    fn clone(&self) -> Self {
+        Namespace {
+            id: self.id,
+            name: Arc::clone(&self.name),
+            inner: Arc::clone(&self.inner),
+            stats: Arc::clone(&self.stats),
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<K, V> CacheGroup<K, V>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Clone + 'static,
+{
+    /// Crée un groupe vide dont tous les espaces se partagent `capacity` entrées.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn new(capacity: usize) -> Self {
+        let stats = SharedStats::default();
+        let counters = Arc::clone(&stats);
+        let cache = LruCache::builder(capacity)
+            .on_evict(move |key: &(u32, K), _: &V, cause| {
+                let mut stats = lock(&counters);
+                let namespace = &mut stats[key.0 as usize];
+                match cause {
+                    RemovalCause::Evicted => namespace.evictions += 1,
+                    RemovalCause::Expired => namespace.expirations += 1,
+                    RemovalCause::Replaced | RemovalCause::Explicit => {}
+                }
+            })
+            .build();
+        CacheGroup {
+            inner: Arc::new(Mutex::new(GroupInner {
+                cache,
+                names: HashMap::new(),
+            })),
+            stats,
+        }
+    }
+
+    /// Retourne la vue de l'espace `name`, créé au premier appel.
+    pub fn namespace(&self, name: &str) -> Namespace<K, V> {
+        let mut inner = lock(&self.inner);
+        let next = inner.names.len() as u32;
+        let id = *inner.names.entry(name.to_owned()).or_insert(next);
+        if id == next {
+            lock(&self.stats).push(CacheStats::default());
+        }
+        Namespace {
+            id,
+            name: name.into(),
+            inner: Arc::clone(&self.inner),
+            stats: Arc::clone(&self.stats),
+        }
+    }
+
+    /// Noms des espaces créés, dans l'ordre de création.
+    pub fn namespaces(&self) -> Vec<String> {
+        let inner = lock(&self.inner);
+        let mut names: Vec<_> = inner.names.iter().collect();
+        names.sort_by_key(|(_, id)| **id);
+        names.into_iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// Nombre total d'entrées, tous espaces confondus.
+    pub fn len(&self) -> usize {
+        lock(&self.inner).cache.len()
+    }
+
+    /// Indique si aucun espace ne contient d'entrée.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Capacité partagée par tous les espaces.
+    pub fn capacity(&self) -> usize {
+        lock(&self.inner).cache.capacity()
+    }
+
+    /// Vide tous les espaces (les statistiques sont conservées).
+    pub fn clear(&self) {
+        lock(&self.inner).cache.clear();
+    }
+}
+
+impl<K, V> Namespace<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Nom de l'espace.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn update_stats(&self, update: impl FnOnce(&mut CacheStats)) {
+        update(&mut lock(&self.stats)[self.id as usize]);
+    }
+
+    /// Retourne un clone de la valeur et la marque comme récente.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let value = lock(&self.inner)
+            .cache
+            .get(&(self.id, key.clone()))
+            .cloned();
+        self.update_stats(|stats| match value {
+            Some(_) => stats.hits += 1,
+            None => stats.misses += 1,
+        });
+        value
+    }
+
+    /// Retourne un clone de la valeur sans modifier la récence.
+    pub fn peek(&self, key: &K) -> Option<V> {
+        lock(&self.inner)
+            .cache
+            .peek(&(self.id, key.clone()))
+            .cloned()
+    }
+
+    /// Indique si la clé est présente dans cet espace.
+    pub fn contains(&self, key: &K) -> bool {
+        lock(&self.inner).cache.contains(&(self.id, key.clone()))
+    }
+
+    /// Insère ou met à jour une valeur, en concurrence avec les autres espaces.
+    pub fn put(&self, key: K, value: V) {
+        let key = (self.id, key);
+        let mut inner = lock(&self.inner);
+        let present = inner.cache.contains(&key);
+        inner.cache.put(key, value);
+        drop(inner);
+        self.update_stats(|stats| {
+            if present {
+                stats.updates += 1;
+            } else {
+                stats.insertions += 1;
+            }
+        });
+    }
+
+    /// Supprime une clé de cet espace et retourne sa valeur.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        lock(&self.inner).cache.remove(&(self.id, key.clone()))
+    }
+
+    /// Supprime toutes les entrées de cet espace et retourne leur nombre.
+    ///
+    /// # Complexité
+    /// O(n) sur l'ensemble du groupe.
+    pub fn clear(&self) -> usize {
+        lock(&self.inner)
+            .cache
+            .remove_where(|node| node.key.0 == self.id)
+    }
+
+    /// Nombre d'entrées de cet espace.
+    ///
+    /// # Complexité
+    /// O(n) sur l'ensemble du groupe.
+    pub fn len(&self) -> usize {
+        let inner = lock(&self.inner);
+        inner
+            .cache
+            .iter()
+            .filter(|((id, _), _)| *id == self.id)
+            .count()
+    }
+
+    /// Indique si cet espace ne contient aucune entrée.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Statistiques de cet espace depuis sa création.
+    pub fn stats(&self) -> CacheStats {
+        lock(&self.stats)[self.id as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespaces_share_capacity() {
+        let group = CacheGroup::new(3);
+        let sessions = group.namespace("sessions");
+        let users = group.namespace("users");
+        sessions.put(1, "s1");
+        sessions.put(2, "s2");
+        users.put(1, "u1");
+        assert_eq!(sessions.get(&1), Some("s1"));
+        assert_eq!(users.get(&1), Some("u1"));
+
+        // "s2" est le LRU du groupe entier.
+        users.put(2, "u2");
+        assert_eq!(sessions.peek(&2), None);
+        assert_eq!(group.len(), 3);
+        assert_eq!(sessions.stats().evictions, 1);
+        assert_eq!(users.stats().evictions, 0);
+        assert_eq!(group.namespaces(), ["sessions", "users"]);
+    }
+
+    #[test]
+    fn test_clear_is_scoped() {
+        let group = CacheGroup::new(10);
+        let a = group.namespace("a");
+        let b = group.namespace("b");
+        a.put("k", 1);
+        a.put("l", 2);
+        b.put("k", 3);
+        assert_eq!(a.clear(), 2);
+        assert!(a.is_empty());
+        assert_eq!(b.len(), 1);
+        assert_eq!(group.namespace("b").get(&"k"), Some(3));
+        assert_eq!(b.stats().hits, 1);
+    }
+}
@@ -0,0 +1,120 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::cache::LruCache;
+use crate::listener::RemovalCause;
+use crate::stats::Stat;
+
+/// Référence opaque vers une entrée d'un [`LruCache`], obtenue par
+/// [`handle_of`](LruCache::handle_of).
+///
+/// Elle désigne l'emplacement de l'entrée dans l'arena et son numéro
+/// d'insertion : y accéder ne demande aucun hachage de la clé. Un handle
+/// devient périmé quand son entrée quitte le cache (suppression, éviction,
+/// expiration), mais aussi quand elle est déplacée dans l'arena, ce qui
+/// arrive lorsqu'une autre entrée est retirée. Un handle périmé ne donne
+/// jamais accès à une autre entrée : il suffit alors d'en redemander un.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EntryHandle {
+    slot: usize,
+    generation: u64,
+}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Handle vers l'entrée de `key`, sans effet sur la récence ni les
+    /// statistiques. `None` si la clé est absente ou expirée.
+    pub fn handle_of<Q>(&self, key: &Q) -> Option<EntryHandle>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.find(key)?;
+        let node = &self.arena[slot];
+        (!node.is_expired(self.clock.now(), self.tti)).then_some(EntryHandle {
+            slot,
+            generation: node.generation,
+        })
+    }
+
+    /// Emplacement désigné par `handle`, s'il n'est pas périmé.
+    fn resolve(&self, handle: EntryHandle) -> Option<usize> {
+        self.arena
+            .get(handle.slot)
+            .filter(|node| node.generation == handle.generation)
+            .map(|_| handle.slot)
+    }
+
+    /// Comme [`get`](crate::Cache::get), en O(1) sans hacher la clé.
+    /// Un handle périmé compte comme un échec.
+    pub fn get_by_handle(&mut self, handle: EntryHandle) -> Option<&V> {
+        let Some(index) = self.resolve(handle) else {
+            self.record(Stat::Miss);
+            return None;
+        };
+        let node = &self.arena[index];
+        let may_expire = node.expires_at.is_some() || self.tti.is_some();
+        if may_expire && node.is_expired(self.clock.now(), self.tti) {
+            self.evict_at(index, RemovalCause::Expired);
+            self.record(Stat::Miss);
+            return None;
+        }
+        self.record(Stat::Hit);
+        self.promote(index);
+        Some(&self.arena[index].value)
+    }
+
+    /// Comme [`peek`](crate::Cache::peek), en O(1) sans hacher la clé.
+    pub fn peek_by_handle(&self, handle: EntryHandle) -> Option<&V> {
+        let node = &self.arena[self.resolve(handle)?];
+        (!node.is_expired(self.clock.now(), self.tti)).then_some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::cache::Cache;
+    use crate::clock::MockClock;
+
+    #[test]
+    fn test_handle_access_and_promotion() {
+        let mut cache = LruCache::new(2);
+        cache.put("a".to_string(), 1);
+        cache.put("b".to_string(), 2);
+        let a = cache.handle_of("a").unwrap();
+        assert_eq!(cache.get_by_handle(a), Some(&1));
+        // `a` a été promu : `b` est évincé.
+        cache.put("c".to_string(), 3);
+        assert!(!cache.contains(&"b".to_string()));
+        assert_eq!(cache.peek_by_handle(a), Some(&1));
+        assert_eq!(cache.handle_of("b"), None);
+    }
+
+    #[test]
+    fn test_stale_handles_never_alias() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2).clock(clock.clone()).build();
+        cache.put(1, "un");
+        let one = cache.handle_of(&1).unwrap();
+        cache.remove(&1);
+        // La nouvelle entrée réutilise l'emplacement, pas le numéro.
+        cache.put(2, "deux");
+        assert_eq!(cache.get_by_handle(one), None);
+
+        // Déplacement par `swap_remove` : le handle est périmé, pas erroné.
+        cache.put(3, "trois");
+        let three = cache.handle_of(&3).unwrap();
+        cache.remove(&2);
+        assert_eq!(cache.peek_by_handle(three), None);
+        let three = cache.handle_of(&3).unwrap();
+        assert_eq!(cache.peek_by_handle(three), Some(&"trois"));
+
+        cache.put_with_ttl(4, "quatre", Duration::from_secs(1));
+        let four = cache.handle_of(&4).unwrap();
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(cache.get_by_handle(four), None);
+        assert_eq!(cache.len(), 1);
+    }
+}
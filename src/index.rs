@@ -0,0 +1,212 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+
+/// Hasher "identité" pour les clés `u64` déjà hachées.
+///
+/// Les hash stockés dans l'index proviennent déjà d'un `RandomState` :
+/// les re-hacher avec SipHash serait du travail inutile.
+#[derive(Default)]
+pub(crate) struct PassThroughHasher(u64);
+
+impl Hasher for PassThroughHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 << 8) | u64::from(b);
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = n;
+    }
+}
+
+/// Index `Clé -> Emplacement` qui ne stocke jamais la clé elle-même.
+///
+/// # Architecture
+/// * **Buckets** : Associe `hash(clé) -> premier emplacement` de la chaîne.
+/// * **Chaînes** : Les collisions de hash sont chaînées via `chains[slot]`.
+/// * **Clés** : Restent uniquement dans l'arena du cache ; la comparaison
+///   finale est déléguée à l'appelant via une closure.
+///
+/// Ainsi l'insertion n'exige ni `K: Clone` ni allocation pour la clé.
+pub(crate) struct KeyIndex {
+    hasher: RandomState,
+    buckets: HashMap<u64, usize, BuildHasherDefault<PassThroughHasher>>,
+    /// Hash de chaque emplacement (parallèle à l'arena).
+    hashes: Vec<u64>,
+    /// Emplacement suivant portant le même hash.
+    chains: Vec<Option<usize>>,
+}
+
+impl KeyIndex {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        KeyIndex {
+            hasher: RandomState::new(),
+            buckets: HashMap::with_capacity_and_hasher(capacity, Default::default()),
+            hashes: Vec::with_capacity(capacity),
+            chains: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Calcule le hash d'une clé (ou d'une forme empruntée de la clé).
+    pub(crate) fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
+    }
+
+    /// Recherche l'emplacement dont la clé est égale à `key`.
+    ///
+    /// `key_at` doit retourner la clé stockée à un emplacement donné.
+    pub(crate) fn find<'a, K, Q>(
+        &self,
+        key: &Q,
+        key_at: impl Fn(usize) -> &'a K,
+    ) -> Option<usize>
+    where
+        K: Borrow<Q> + 'a,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hash(key);
+        let mut current = self.buckets.get(&hash).copied();
+        while let Some(slot) = current {
+            if key_at(slot).borrow() == key {
+                return Some(slot);
+            }
+            current = self.chains[slot];
+        }
+        None
+    }
+
+    /// Hash enregistré pour `slot`.
+    #[cfg(feature = "timer-wheel")]
+    pub(crate) fn hash_of(&self, slot: usize) -> u64 {
+        self.hashes[slot]
+    }
+
+    /// Emplacements enregistrés sous `hash`, par index décroissant
+    /// (l'ordre sûr pour des `swap_remove` successifs).
+    #[cfg(feature = "timer-wheel")]
+    pub(crate) fn slots_with_hash(&self, hash: u64) -> Vec<usize> {
+        let mut slots = Vec::new();
+        let mut current = self.buckets.get(&hash).copied();
+        while let Some(slot) = current {
+            slots.push(slot);
+            current = self.chains[slot];
+        }
+        slots.sort_unstable_by(|a, b| b.cmp(a));
+        slots
+    }
+
+    /// Enregistre `slot` sous `hash`, en tête de sa chaîne de collision.
+    pub(crate) fn insert(&mut self, slot: usize, hash: u64) {
+        if slot == self.hashes.len() {
+            self.hashes.push(hash);
+            self.chains.push(None);
+        } else {
+            self.hashes[slot] = hash;
+        }
+        self.chains[slot] = self.buckets.insert(hash, slot);
+    }
+
+    /// Retire `slot` de sa chaîne. L'emplacement reste réservé jusqu'à
+    /// une nouvelle insertion ou un `relocate`.
+    pub(crate) fn remove(&mut self, slot: usize) {
+        let hash = self.hashes[slot];
+        let next = self.chains[slot].take();
+        self.replace_link(hash, slot, next);
+    }
+
+    /// Déplace l'entrée indexée de `from` vers `to` (cas du `swap_remove`).
+    ///
+    /// `to` doit avoir été retiré au préalable. Si `from` était le dernier
+    /// emplacement, les tableaux internes sont raccourcis (`from == to`
+    /// se contente donc de libérer le dernier emplacement).
+    pub(crate) fn relocate(&mut self, from: usize, to: usize) {
+        if from != to {
+            let hash = self.hashes[from];
+            self.replace_link(hash, from, Some(to));
+            self.hashes[to] = hash;
+            self.chains[to] = self.chains[from].take();
+        }
+        if from + 1 == self.hashes.len() {
+            self.hashes.pop();
+            self.chains.pop();
+        }
+    }
+
+    /// Nombre d'emplacements accessibles depuis les buckets (chaînes comprises).
+    ///
+    /// Parcours complet : réservé aux vérifications d'invariants.
+    pub(crate) fn indexed_slots(&self) -> usize {
+        let mut count = 0;
+        for &first in self.buckets.values() {
+            let mut current = Some(first);
+            while let Some(slot) = current {
+                count += 1;
+                // Une chaîne cyclique ne peut pas compter plus que tous les emplacements.
+                if count > self.chains.len() {
+                    return count;
+                }
+                current = self.chains[slot];
+            }
+        }
+        count
+    }
+
+    /// Estimation des octets alloués par l'index.
+    pub(crate) fn heap_size(&self) -> usize {
+        // Chaque bucket d'une HashMap coûte sa paire plus un octet de contrôle.
+        self.buckets.capacity() * (size_of::<(u64, usize)>() + 1)
+            + self.hashes.capacity() * size_of::<u64>()
+            + self.chains.capacity() * size_of::<Option<usize>>()
+    }
+
+    /// Libère la mémoire réservée au-delà des emplacements utilisés.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.buckets.shrink_to_fit();
+        self.hashes.shrink_to_fit();
+        self.chains.shrink_to_fit();
+    }
+
+    /// Réserve la place de `additional` emplacements supplémentaires.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.buckets.reserve(additional);
+        self.hashes.reserve(additional);
+        self.chains.reserve(additional);
+    }
+
+    /// Vide complètement l'index.
+    pub(crate) fn clear(&mut self) {
+        self.buckets.clear();
+        self.hashes.clear();
+        self.chains.clear();
+    }
+
+    /// Remplace le lien qui pointe vers `slot` (bucket ou chaîne) par `target`.
+    fn replace_link(&mut self, hash: u64, slot: usize, target: Option<usize>) {
+        if self.buckets.get(&hash) == Some(&slot) {
+            match target {
+                Some(t) => {
+                    self.buckets.insert(hash, t);
+                }
+                None => {
+                    self.buckets.remove(&hash);
+                }
+            }
+            return;
+        }
+        let mut current = self.buckets.get(&hash).copied();
+        while let Some(idx) = current {
+            if self.chains[idx] == Some(slot) {
+                self.chains[idx] = target;
+                return;
+            }
+            current = self.chains[idx];
+        }
+    }
+}
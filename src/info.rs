@@ -0,0 +1,120 @@
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::cache::LruCache;
+
+/// Métadonnées d'une entrée, pour l'analyse des clés chaudes et le
+/// diagnostic des évictions (voir [`LruCache::entry_info`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EntryInfo {
+    /// Nombre de lectures réussies depuis l'insertion de la clé.
+    pub hits: u64,
+    /// Temps écoulé depuis l'insertion de la clé (les mises à jour ne le remettent pas à zéro).
+    pub age: Duration,
+    /// Temps écoulé depuis le dernier accès (lecture ou écriture).
+    pub idle: Duration,
+    /// Distance à la Tête : 0 pour l'entrée la plus récente, `len - 1` pour
+    /// la prochaine évincée.
+    pub position: usize,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Retourne les métadonnées d'une entrée, sans effet sur la récence.
+    /// Une entrée expirée est vue comme absente.
+    ///
+    /// # Complexité
+    /// O(n) : la position est calculée en parcourant la liste depuis la Tête.
+    pub fn entry_info<Q>(&self, key: &Q) -> Option<EntryInfo>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        let node = &self.arena[index];
+        let now = self.clock.now();
+        if node.is_expired(now, self.tti) {
+            return None;
+        }
+        let position = std::iter::successors(self.head, |&i| self.arena[i].next)
+            .position(|i| i == index)
+            .expect("entrée indexée absente de la liste");
+        Some(EntryInfo {
+            hits: node.hits,
+            age: now.saturating_duration_since(node.created_at),
+            idle: now.saturating_duration_since(node.last_access),
+            position,
+        })
+    }
+
+    /// Les `n` entrées vivantes les plus lues, avec leur nombre de lectures
+    /// réussies depuis l'insertion, de la plus lue à la moins lue (à égalité,
+    /// la plus récente d'abord). Sans effet sur la récence.
+    ///
+    /// # Complexité
+    /// O(n log n) sur le nombre d'entrées du cache.
+    pub fn hottest(&self, n: usize) -> Vec<(K, u64)>
+    where
+        K: Clone,
+    {
+        let now = self.clock.now();
+        let mut entries: Vec<(K, u64)> = std::iter::successors(self.head, |&i| self.arena[i].next)
+            .map(|i| &self.arena[i])
+            .filter(|node| !node.is_expired(now, self.tti))
+            .map(|node| (node.key.clone(), node.hits))
+            .collect();
+        entries.sort_by_key(|&(_, hits)| Reverse(hits));
+        entries.truncate(n);
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cache, MockClock};
+
+    #[test]
+    fn test_entry_info_reports_usage() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(3).clock(clock.clone()).build();
+        cache.put("a", 1);
+        clock.advance(Duration::from_secs(10));
+        cache.put("b", 2);
+        cache.get(&"a");
+        cache.get(&"a");
+        clock.advance(Duration::from_secs(5));
+        cache.put("a", 3);
+        cache.put("c", 4);
+
+        let info = cache.entry_info("a").unwrap();
+        assert_eq!(info.hits, 2);
+        assert_eq!(info.age, Duration::from_secs(15));
+        assert_eq!(info.idle, Duration::ZERO);
+        assert_eq!(info.position, 1);
+        assert_eq!(cache.entry_info("b").unwrap().position, 2);
+        assert_eq!(cache.entry_info("b").unwrap().idle, Duration::from_secs(5));
+        assert_eq!(cache.entry_info("z"), None);
+    }
+
+    #[test]
+    fn test_hottest_ranks_by_hits() {
+        let mut cache = LruCache::new(4);
+        for key in ["a", "b", "c", "d"] {
+            cache.put(key, ());
+        }
+        for key in ["b", "b", "b", "d", "a", "d"] {
+            cache.get(&key);
+        }
+        assert_eq!(cache.hottest(3), [("b", 3), ("d", 2), ("a", 1)]);
+        assert_eq!(cache.hottest(10).len(), 4);
+        assert!(cache.hottest(0).is_empty());
+        // Une réinsertion après éviction repart de zéro.
+        cache.put("e", ());
+        assert_eq!(cache.hottest(5).last(), Some(&("e", 0)));
+    }
+}
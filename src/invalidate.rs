@@ -0,0 +1,76 @@
+use std::hash::Hash;
+
+use crate::cache::{LruCache, Node};
+use crate::listener::RemovalCause;
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Supprime toutes les entrées dont la clé commence par `prefix`
+    /// (ex: `"user:42:"`) et retourne leur nombre.
+    ///
+    /// # Complexité
+    /// O(n) : toutes les clés sont examinées.
+    pub fn invalidate_prefix(&mut self, prefix: &str) -> usize
+    where
+        K: AsRef<str>,
+    {
+        self.invalidate_prefix_by(prefix, |key| key.as_ref())
+    }
+
+    /// Comme [`invalidate_prefix`](Self::invalidate_prefix), pour des clés
+    /// dont `project` extrait la partie textuelle.
+    pub fn invalidate_prefix_by<F>(&mut self, prefix: &str, project: F) -> usize
+    where
+        F: Fn(&K) -> &str,
+    {
+        self.remove_where(|node| project(&node.key).starts_with(prefix))
+    }
+
+    /// Supprime les entrées satisfaisant `matches`, notifiées avec
+    /// [`RemovalCause::Explicit`], et retourne leur nombre.
+    pub(crate) fn remove_where(&mut self, mut matches: impl FnMut(&Node<K, V>) -> bool) -> usize {
+        let mut removed = 0;
+        // Parcours à rebours : `swap_remove` ne déplace que des entrées déjà vues.
+        for index in (0..self.arena.len()).rev() {
+            if matches(&self.arena[index]) {
+                let (key, value) = self.remove_at(index);
+                self.notify_removal(&key, &value, RemovalCause::Explicit);
+                removed += 1;
+            }
+        }
+        #[cfg(feature = "metrics")]
+        self.emit_size();
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_invalidate_prefix() {
+        let mut cache = LruCache::new(10);
+        cache.put("user:42:profile".to_string(), 1);
+        cache.put("user:42:settings".to_string(), 2);
+        cache.put("user:420:profile".to_string(), 3);
+        cache.put("order:42".to_string(), 4);
+
+        assert_eq!(cache.invalidate_prefix("user:42:"), 2);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&"user:420:profile".to_string()));
+    }
+
+    #[test]
+    fn test_invalidate_prefix_by_projection() {
+        let mut cache = LruCache::new(10);
+        cache.put((1, "a/b"), ());
+        cache.put((2, "a/c"), ());
+        cache.put((3, "b/a"), ());
+        assert_eq!(cache.invalidate_prefix_by("a/", |key| key.1), 2);
+        assert!(cache.contains(&(3, "b/a")));
+    }
+}
@@ -0,0 +1,199 @@
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::cache::{Cache, LruCache};
+use crate::priority::Priority;
+
+/// Invariant interne d'un [`LruCache`] violé, détecté par
+/// [`check_consistency`](LruCache::check_consistency).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// La clé de l'emplacement `slot` ne mène pas à cet emplacement via l'index.
+    IndexMismatch { slot: usize },
+    /// L'index ne référence pas autant d'emplacements que l'arena en contient.
+    IndexSize { indexed: usize, arena: usize },
+    /// Les liens `prev`/`next` autour de `slot` ne sont pas réciproques,
+    /// ou pointent hors de l'arena.
+    BrokenLink { slot: usize },
+    /// La liste de récence boucle sur elle-même.
+    Cycle,
+    /// La liste ne relie pas toutes les entrées de l'arena.
+    ListLength { linked: usize, arena: usize },
+    /// `head`/`tail` ne désignent pas les extrémités de la liste.
+    BadEnds,
+    /// Plus d'entrées que la capacité, alors que certaines sont évinçables.
+    OverCapacity { len: usize, capacity: usize },
+    /// Un compteur agrégé (poids total, entrées par priorité) ne correspond pas aux entrées.
+    Counter(&'static str),
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantViolation::IndexMismatch { slot } => {
+                write!(f, "l'index ne retrouve pas l'emplacement {slot}")
+            }
+            InvariantViolation::IndexSize { indexed, arena } => {
+                write!(f, "{indexed} emplacements indexés pour {arena} entrées")
+            }
+            InvariantViolation::BrokenLink { slot } => write!(f, "liens incohérents autour de l'emplacement {slot}"),
+            InvariantViolation::Cycle => write!(f, "cycle dans la liste de récence"),
+            InvariantViolation::ListLength { linked, arena } => {
+                write!(f, "{linked} entrées chaînées pour {arena} entrées")
+            }
+            InvariantViolation::BadEnds => write!(f, "head/tail incohérents"),
+            InvariantViolation::OverCapacity { len, capacity } => {
+                write!(f, "{len} entrées pour une capacité de {capacity}")
+            }
+            InvariantViolation::Counter(name) => write!(f, "compteur « {name} » incohérent"),
+        }
+    }
+}
+
+impl Error for InvariantViolation {}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Vérifie les invariants internes du cache : accord entre l'index et
+    /// l'arena, intégrité de la liste doublement chaînée dans les deux sens
+    /// (sans cycle), capacité et compteurs agrégés.
+    ///
+    /// Parcourt tout le cache (O(n)) : destiné aux tests et aux harnais de
+    /// fuzzing, pas au chemin critique.
+    pub fn check_consistency(&self) -> Result<(), InvariantViolation> {
+        let len = self.arena.len();
+        for (slot, node) in self.arena.iter().enumerate() {
+            if self.find(&node.key) != Some(slot) {
+                return Err(InvariantViolation::IndexMismatch { slot });
+            }
+        }
+        let indexed = self.index.indexed_slots();
+        if indexed != len {
+            return Err(InvariantViolation::IndexSize { indexed, arena: len });
+        }
+
+        // Du head vers le tail, puis en sens inverse.
+        let forward = self.walk(self.head, |slot| self.arena[slot].next, |slot| self.arena[slot].prev)?;
+        if forward.last != self.tail {
+            return Err(InvariantViolation::BadEnds);
+        }
+        let backward = self.walk(self.tail, |slot| self.arena[slot].prev, |slot| self.arena[slot].next)?;
+        if backward.last != self.head {
+            return Err(InvariantViolation::BadEnds);
+        }
+        if forward.count != len {
+            return Err(InvariantViolation::ListLength { linked: forward.count, arena: len });
+        }
+
+        if len > self.capacity() && self.arena.iter().any(|node| !node.pinned) {
+            return Err(InvariantViolation::OverCapacity { len, capacity: self.capacity() });
+        }
+        let total_weight: u64 = self.arena.iter().map(|node| u64::from(node.weight)).sum();
+        if total_weight != self.total_weight {
+            return Err(InvariantViolation::Counter("total_weight"));
+        }
+        let mut priority_counts = [0; Priority::LEVELS];
+        for node in &self.arena {
+            priority_counts[node.priority as usize] += 1;
+        }
+        if priority_counts != self.priority_counts {
+            return Err(InvariantViolation::Counter("priority_counts"));
+        }
+
+        // Listes de récence par niveau de priorité.
+        for (level, ends) in self.levels.iter().enumerate() {
+            let walk = self.walk(
+                ends.head,
+                |slot| self.arena[slot].level_next,
+                |slot| self.arena[slot].level_prev,
+            )?;
+            if walk.last != ends.tail {
+                return Err(InvariantViolation::BadEnds);
+            }
+            if walk.count != priority_counts[level] {
+                return Err(InvariantViolation::Counter("levels"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parcourt la liste depuis `start` en suivant `step`, en vérifiant
+    /// que `back` ramène au nœud précédent.
+    fn walk(
+        &self,
+        start: Option<usize>,
+        step: impl Fn(usize) -> Option<usize>,
+        back: impl Fn(usize) -> Option<usize>,
+    ) -> Result<Walk, InvariantViolation> {
+        let mut walk = Walk { count: 0, last: None };
+        let mut current = start;
+        while let Some(slot) = current {
+            if slot >= self.arena.len() || back(slot) != walk.last {
+                return Err(InvariantViolation::BrokenLink { slot });
+            }
+            walk.count += 1;
+            if walk.count > self.arena.len() {
+                return Err(InvariantViolation::Cycle);
+            }
+            walk.last = Some(slot);
+            current = step(slot);
+        }
+        Ok(walk)
+    }
+}
+
+/// Résultat d'un parcours de la liste de récence.
+struct Walk {
+    count: usize,
+    last: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consistent_after_random_operations() {
+        let mut cache = LruCache::new(8);
+        let mut seed = 11u32;
+        for step in 0..3000 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let key = (seed >> 16) % 20;
+            match seed % 4 {
+                0 => {
+                    cache.remove(&key);
+                }
+                1 => {
+                    cache.get(&key);
+                }
+                _ => cache.put(key, step),
+            }
+            assert_eq!(cache.check_consistency(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_detects_corruption() {
+        let mut cache = LruCache::new(4);
+        for i in 0..3 {
+            cache.put(i, i);
+        }
+        let head = cache.head.unwrap();
+        cache.arena[head].prev = Some(head);
+        assert_eq!(cache.check_consistency(), Err(InvariantViolation::BrokenLink { slot: head }));
+
+        let mut cache = LruCache::new(4);
+        for i in 0..3 {
+            cache.put(i, i);
+        }
+        let tail = cache.tail.unwrap();
+        let head = cache.head;
+        cache.arena[tail].next = head;
+        assert!(cache.check_consistency().is_err());
+
+        let mut cache = LruCache::new(4);
+        cache.put(1, 1);
+        cache.arena[0].key = 2;
+        assert_eq!(cache.check_consistency(), Err(InvariantViolation::IndexMismatch { slot: 0 }));
+    }
+}
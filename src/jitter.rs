@@ -0,0 +1,81 @@
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::cache::LruCache;
+use crate::rng::XorShift64;
+
+/// Étalement aléatoire des durées de vie, pour que des entrées écrites
+/// ensemble n'expirent pas au même instant.
+pub(crate) struct TtlJitter {
+    fraction: f64,
+    rng: XorShift64,
+}
+
+impl TtlJitter {
+    pub(crate) fn new(fraction: f64) -> Self {
+        TtlJitter {
+            fraction,
+            rng: XorShift64::from_entropy(),
+        }
+    }
+
+    /// Tire une durée uniformément dans `ttl × [1 - fraction, 1 + fraction]`.
+    pub(crate) fn apply(&mut self, ttl: Duration) -> Duration {
+        let unit = (self.rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        ttl.mul_f64(1.0 + self.fraction * (2.0 * unit - 1.0))
+    }
+}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Date d'expiration d'une entrée écrite maintenant avec la durée de vie
+    /// `ttl`, étalée si [`ttl_jitter`](crate::LruCacheBuilder::ttl_jitter) est configuré.
+    pub(crate) fn deadline_after(&mut self, ttl: Duration) -> Instant {
+        let ttl = match self.jitter.as_mut() {
+            Some(jitter) => jitter.apply(ttl),
+            None => ttl,
+        };
+        self.clock.now() + ttl
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, LruCache, MockClock};
+    use std::time::Duration;
+
+    #[test]
+    fn test_jitter_spreads_expirations() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(1000)
+            .ttl(Duration::from_secs(100))
+            .ttl_jitter(0.2)
+            .clock(clock.clone())
+            .build();
+        for key in 0..1000u32 {
+            cache.put(key, ());
+        }
+        clock.advance(Duration::from_secs(79));
+        assert_eq!(cache.iter().count(), 1000);
+        clock.advance(Duration::from_secs(21));
+        let alive = cache.iter().count();
+        // Environ la moitié expire avant le TTL nominal.
+        assert!((300..700).contains(&alive), "{alive} entrées vivantes");
+        clock.advance(Duration::from_secs(21));
+        assert_eq!(cache.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_jitter_applies_to_explicit_ttl() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(100).ttl_jitter(0.5).clock(clock.clone()).build();
+        for key in 0..100u32 {
+            cache.put_with_ttl(key, (), Duration::from_secs(10));
+        }
+        clock.advance(Duration::from_secs(10));
+        let alive = cache.iter().count();
+        assert!(alive > 0 && alive < 100, "{alive} entrées vivantes");
+        cache.put(1000, ());
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(cache.iter().count(), 1);
+    }
+}
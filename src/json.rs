@@ -0,0 +1,348 @@
+use std::fmt::{Debug, Display, Write as _};
+use std::hash::Hash;
+use std::io::{self, ErrorKind, Write};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::cache::{Cache, LruCache};
+use crate::persistence::write_atomically;
+
+/// Valeur JSON générique, le temps de relire un fichier.
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    /// Nombre conservé sous sa forme textuelle.
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn field(&self, name: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(key, _)| key == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.into())
+}
+
+/// Écrit `text` sous forme de chaîne JSON échappée.
+fn write_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Analyseur JSON minimal (RFC 8259), suffisant pour relire les sauvegardes.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(input: &'a str) -> io::Result<Json> {
+        let mut parser = Parser { input, pos: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos != input.len() {
+            return Err(parser.error("données en trop"));
+        }
+        Ok(value)
+    }
+
+    fn error(&self, message: &str) -> io::Error {
+        invalid(format!("JSON invalide à l'octet {} : {message}", self.pos))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> io::Result<()> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.error(&format!("'{}' attendu", byte as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> io::Result<Json> {
+        if !self.input[self.pos..].starts_with(word) {
+            return Err(self.error("littéral inconnu"));
+        }
+        self.pos += word.len();
+        Ok(value)
+    }
+
+    fn value(&mut self) -> io::Result<Json> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(b'n') => self.literal("null", Json::Null),
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.pos;
+                while matches!(
+                    self.peek(),
+                    Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+                ) {
+                    self.pos += 1;
+                }
+                Ok(Json::Number(self.input[start..self.pos].to_owned()))
+            }
+            _ => Err(self.error("valeur attendue")),
+        }
+    }
+
+    fn object(&mut self) -> io::Result<Json> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(fields));
+                }
+                _ => return Err(self.error("',' ou '}' attendu")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> io::Result<Json> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(items));
+                }
+                _ => return Err(self.error("',' ou ']' attendu")),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> io::Result<u32> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| self.error("\\u tronqué"))?;
+        let code = u32::from_str_radix(digits, 16).map_err(|_| self.error("\\u invalide"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+        loop {
+            let rest = &self.input[self.pos..];
+            let end = rest
+                .find(['"', '\\'])
+                .ok_or_else(|| self.error("chaîne non terminée"))?;
+            out.push_str(&rest[..end]);
+            self.pos += end + 1;
+            if rest.as_bytes()[end] == b'"' {
+                return Ok(out);
+            }
+            let escape = self
+                .peek()
+                .ok_or_else(|| self.error("échappement tronqué"))?;
+            self.pos += 1;
+            match escape {
+                b'"' => out.push('"'),
+                b'\\' => out.push('\\'),
+                b'/' => out.push('/'),
+                b'b' => out.push('\u{8}'),
+                b'f' => out.push('\u{c}'),
+                b'n' => out.push('\n'),
+                b'r' => out.push('\r'),
+                b't' => out.push('\t'),
+                b'u' => {
+                    let mut code = self.hex4()?;
+                    // Paire de substitution UTF-16.
+                    if (0xd800..0xdc00).contains(&code) && self.input[self.pos..].starts_with("\\u")
+                    {
+                        self.pos += 2;
+                        let low = self.hex4()?;
+                        code =
+                            0x10000 + ((code - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff);
+                    }
+                    out.push(char::from_u32(code).ok_or_else(|| self.error("caractère invalide"))?);
+                }
+                _ => return Err(self.error("échappement inconnu")),
+            }
+        }
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Display + FromStr,
+    V: Display + FromStr,
+    <K as FromStr>::Err: Debug,
+    <V as FromStr>::Err: Debug,
+{
+    /// Sauvegarde le cache au format JSON, lisible par d'autres outils :
+    ///
+    /// ```json
+    /// {"capacity":3,"saved_at":1700000000,"entries":[{"key":"a","value":"1"}]}
+    /// ```
+    ///
+    /// `saved_at` est en secondes depuis l'époque Unix. Les entrées sont
+    /// ordonnées du Tail (Vieux) vers le Head (Récent), sous forme de
+    /// chaînes (`Display`) ; les entrées expirées sont ignorées. L'écriture est atomique.
+    pub fn save_json(&self, filepath: &str) -> io::Result<()> {
+        let saved_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let mut out = format!(
+            "{{\"capacity\":{},\"saved_at\":{saved_at},\"entries\":[",
+            self.capacity()
+        );
+        for (i, (key, value)) in self.iter().rev().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("\n{\"key\":");
+            write_string(&mut out, &key.to_string());
+            out.push_str(",\"value\":");
+            write_string(&mut out, &value.to_string());
+            out.push('}');
+        }
+        out.push_str("\n]}\n");
+        write_atomically(filepath, |file| file.write_all(out.as_bytes()))
+    }
+
+    /// Recharge un cache sauvegardé par [`save_json`](Self::save_json), avec
+    /// la capacité enregistrée.
+    ///
+    /// Si le fichier n'existe pas, retourne un cache vide de capacité
+    /// `capacity`. Un fichier illisible est une erreur `ErrorKind::InvalidData`.
+    pub fn new_persistent_json(capacity: usize, filepath: &str) -> io::Result<Self> {
+        let text = match std::fs::read_to_string(filepath) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(LruCache::new(capacity)),
+            Err(err) => return Err(err),
+        };
+        let document = Parser::parse(&text)?;
+        let saved_capacity = match document.field("capacity") {
+            Some(Json::Number(n)) => n.parse::<usize>().ok().filter(|&n| n > 0),
+            _ => None,
+        }
+        .ok_or_else(|| invalid("champ \"capacity\" manquant ou invalide"))?;
+        let Some(Json::Array(entries)) = document.field("entries") else {
+            return Err(invalid("champ \"entries\" manquant"));
+        };
+
+        let mut cache = LruCache::new(saved_capacity);
+        for (i, entry) in entries.iter().enumerate() {
+            let (Some(Json::String(key)), Some(Json::String(value))) =
+                (entry.field("key"), entry.field("value"))
+            else {
+                return Err(invalid(format!(
+                    "entrée {i} : \"key\" et \"value\" attendus"
+                )));
+            };
+            let key =
+                K::from_str(key).map_err(|err| invalid(format!("entrée {i} : clé {err:?}")))?;
+            let value = V::from_str(value)
+                .map_err(|err| invalid(format!("entrée {i} : valeur {err:?}")))?;
+            cache.put(key, value);
+        }
+        Ok(cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parser() {
+        let json = Parser::parse(r#" {"a": [1, -2.5e3, true, null], "b": "x\"é😀\n"} "#).unwrap();
+        assert_eq!(
+            json.field("a"),
+            Some(&Json::Array(vec![
+                Json::Number("1".into()),
+                Json::Number("-2.5e3".into()),
+                Json::Bool(true),
+                Json::Null,
+            ]))
+        );
+        assert_eq!(json.field("b"), Some(&Json::String("x\"é😀\n".into())));
+        assert!(Parser::parse("{\"a\":1,}").is_err());
+        assert!(Parser::parse("[1] 2").is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_json() {
+        let path = std::env::temp_dir().join(format!("lru_json_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut cache = LruCache::new(3);
+        cache.put("a=b".to_string(), "ligne 1\nligne \"2\"".to_string());
+        cache.put("c".to_string(), "\\".to_string());
+        cache.get(&"a=b".to_string());
+        cache.save_json(path).unwrap();
+
+        let text = std::fs::read_to_string(path).unwrap();
+        assert!(text.starts_with("{\"capacity\":3,\"saved_at\":"));
+        let loaded: LruCache<String, String> = LruCache::new_persistent_json(10, path).unwrap();
+        assert_eq!(loaded.capacity(), 3);
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+
+        std::fs::write(path, "{\"capacity\":2,\"entries\":[{\"key\":\"x\"}]}").unwrap();
+        let err = LruCache::<String, String>::new_persistent_json(2, path)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,4 +1,117 @@
+#[cfg(feature = "async")]
+pub mod async_cache;
+mod autosave;
+pub mod backend;
+#[cfg(feature = "binary")]
+pub mod binary;
+mod buffer;
+pub mod builder;
 pub mod cache;
+pub mod clock;
+pub mod codec;
+#[cfg(feature = "compression")]
+pub mod compression;
+mod conditional;
+mod crc;
+pub mod disk;
+mod doorkeeper;
+mod drain;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+pub mod error;
+mod expiry;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod ghost;
+pub mod group;
+pub mod handle;
+mod index;
+pub mod info;
+mod invalidate;
+pub mod invariant;
+mod jitter;
+#[cfg(feature = "json")]
+mod json;
+mod listener;
+pub mod loader;
+pub mod memsize;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(all(feature = "mmap", target_os = "linux", target_pointer_width = "64"))]
+pub mod mmap;
+mod negative;
 pub mod persistence;
-pub use cache::LruCache;
-pub use cache::Cache;
\ No newline at end of file
+mod pin;
+pub mod policy;
+#[cfg(feature = "string-persistence")]
+mod portable;
+pub mod priority;
+mod recency;
+mod refresh;
+mod rng;
+pub mod sharded;
+mod shared;
+pub mod simulate;
+pub mod singleflight;
+pub mod sketch;
+mod snapshot;
+pub mod stats;
+pub mod store;
+pub mod sync;
+mod tags;
+pub mod tiered;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod wal;
+mod watermark;
+pub mod weak;
+pub mod weight;
+#[cfg(feature = "timer-wheel")]
+mod wheel;
+#[cfg(feature = "async")]
+pub use async_cache::{AsyncLruCache, EventStream};
+pub use backend::{FileBackend, PersistenceBackend};
+#[cfg(feature = "binary")]
+pub use binary::BinaryCodec;
+pub use builder::LruCacheBuilder;
+pub use cache::{Iter, LruCache};
+pub use cache::Cache;
+pub use clock::{Clock, MockClock, SystemClock};
+pub use codec::{DisplayCodec, HexCodec, KeyCodec, ValueCodec};
+#[cfg(feature = "compression")]
+pub use compression::{Compressor, Lz77};
+pub use disk::DiskCache;
+#[cfg(feature = "encryption")]
+pub use encryption::Cipher;
+pub use error::CacheError;
+pub use group::{CacheGroup, Namespace};
+pub use handle::EntryHandle;
+pub use info::EntryInfo;
+pub use invariant::InvariantViolation;
+pub use listener::{CacheEvent, RemovalCause};
+pub use loader::{CacheLoader, LoadingCache};
+pub use memsize::MemSize;
+#[cfg(feature = "metrics")]
+pub use metrics::MetricsRecorder;
+#[cfg(all(feature = "mmap", target_os = "linux", target_pointer_width = "64"))]
+pub use mmap::{FixedSize, MmapCache};
+pub use negative::Cached;
+pub use persistence::SaveOnDrop;
+pub use policy::{
+    ArcCache, ClockCache, EvictionPolicy, FifoCache, GdsfCache, LfuCache, LrukCache, MruCache, PolicyCache,
+    RandomCache, SampledLruCache, SlruCache, TinyLfuCache, TwoQCache,
+};
+pub use priority::Priority;
+pub use sharded::{ShardStats, ShardedLruCache};
+pub use simulate::{ComparisonReport, PolicyKind, SimulationReport};
+pub use singleflight::SingleFlight;
+pub use sketch::FrequencySketch;
+pub use stats::CacheStats;
+pub use store::{Store, WriteBehind, WriteThrough};
+pub use sync::{SyncLruCache, ValueGuard};
+pub use tiered::TieredCache;
+#[cfg(feature = "tracing")]
+pub use trace::{TraceEvent, TraceSink};
+pub use wal::WalCache;
+pub use weak::WeakValueCache;
+pub use weight::Weigher;
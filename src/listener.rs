@@ -0,0 +1,213 @@
+use std::any::Any;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Instant;
+
+use crate::cache::LruCache;
+
+/// Raison pour laquelle une entrée a quitté le cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RemovalCause {
+    /// Évincée pour faire de la place (capacité atteinte).
+    Evicted,
+    /// Expirée (TTL ou inactivité).
+    Expired,
+    /// Valeur remplacée par un `put` sur la même clé.
+    Replaced,
+    /// Supprimée explicitement (`remove`, `clear`...).
+    Explicit,
+}
+
+impl RemovalCause {
+    /// Indique si le cache a retiré l'entrée de lui-même (éviction ou expiration).
+    pub fn was_evicted(self) -> bool {
+        matches!(self, RemovalCause::Evicted | RemovalCause::Expired)
+    }
+}
+
+/// Événement publié à chaque retrait d'une entrée (voir [`LruCache::subscribe`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheEvent<K> {
+    /// Clé de l'entrée retirée.
+    pub key: K,
+    /// Raison du retrait.
+    pub cause: RemovalCause,
+    /// Instant du retrait, selon l'horloge du cache.
+    pub timestamp: Instant,
+}
+
+/// Callback appelé pour chaque entrée qui quitte le cache.
+///
+/// Rangé sans ses types de clé et de valeur, comme le
+/// [`BoxedWeigher`](crate::weight::BoxedWeigher), pour que des clés
+/// empruntées n'aient pas à survivre au cache.
+pub(crate) struct RemovalListener<K, V> {
+    callback: Box<dyn Any + Send + Sync>,
+    call: fn(&mut (dyn Any + Send + Sync), &K, &V, RemovalCause),
+}
+
+impl<K, V> RemovalListener<K, V> {
+    pub(crate) fn new<F>(callback: F) -> Self
+    where
+        F: FnMut(&K, &V, RemovalCause) + Send + Sync + 'static,
+    {
+        RemovalListener {
+            callback: Box::new(callback),
+            call: |callback, key, value, cause| {
+                if let Some(callback) = callback.downcast_mut::<F>() {
+                    callback(key, value, cause);
+                }
+            },
+        }
+    }
+
+    pub(crate) fn call(&mut self, key: &K, value: &V, cause: RemovalCause) {
+        (self.call)(&mut *self.callback, key, value, cause);
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Prévient le listener (s'il y en a un) du retrait d'une entrée.
+    pub(crate) fn notify_removal(&mut self, key: &K, value: &V, cause: RemovalCause) {
+        if let Some(listener) = self.listener.as_mut() {
+            listener.call(key, value, cause);
+        }
+    }
+
+    /// Retourne un canal recevant un [`CacheEvent`] pour chaque entrée qui
+    /// quitte le cache, quelle qu'en soit la raison.
+    ///
+    /// Contrairement à [`on_evict`](crate::LruCacheBuilder::on_evict), aucun code
+    /// utilisateur ne s'exécute pendant les opérations du cache : un autre
+    /// thread peut consommer les événements à son rythme. Le canal n'est pas
+    /// borné ; un abonné dont le `Receiver` est détruit est oublié.
+    /// Plusieurs abonnements (et un listener) peuvent coexister.
+    pub fn subscribe(&mut self) -> Receiver<CacheEvent<K>>
+    where
+        K: Clone + Send + 'static,
+        V: 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        self.add_event_listener(move |event| sender.send(event).is_ok());
+        receiver
+    }
+
+    /// Ajoute, après le listener existant, un abonné recevant chaque retrait
+    /// sous forme de [`CacheEvent`] daté par l'horloge du cache.
+    ///
+    /// `publish` retourne `false` une fois l'abonné parti : il est alors
+    /// libéré, et les retraits suivants ne clonent plus la clé.
+    pub(crate) fn add_event_listener(
+        &mut self,
+        publish: impl FnMut(CacheEvent<K>) -> bool + Send + Sync + 'static,
+    ) where
+        K: Clone + 'static,
+        V: 'static,
+    {
+        let clock = Arc::clone(&self.clock);
+        let mut previous = self.listener.take();
+        let mut publish = Some(publish);
+        self.listener = Some(RemovalListener::new(move |key: &K, value: &V, cause| {
+            if let Some(previous) = previous.as_mut() {
+                previous.call(key, value, cause);
+            }
+            let Some(subscriber) = publish.as_mut() else {
+                return;
+            };
+            let event = CacheEvent {
+                key: key.clone(),
+                cause,
+                timestamp: clock.now(),
+            };
+            if !subscriber(event) {
+                publish = None;
+            }
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cache, Clock, MockClock};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[test]
+    fn test_listener_receives_every_cause() {
+        let clock = MockClock::new();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let mut cache = LruCache::builder(2)
+            .clock(clock.clone())
+            .on_evict(move |k: &&str, v: &u32, cause| sink.lock().unwrap().push((*k, *v, cause)))
+            .build();
+
+        cache.put("a", 1);
+        cache.put("a", 2);
+        cache.put("b", 3);
+        cache.put("c", 4);
+        cache.remove(&"b");
+        cache.put_with_ttl("d", 5, Duration::from_secs(1));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(cache.get(&"d"), None);
+        cache.clear();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                ("a", 1, RemovalCause::Replaced),
+                ("a", 2, RemovalCause::Evicted),
+                ("b", 3, RemovalCause::Explicit),
+                ("d", 5, RemovalCause::Expired),
+                ("c", 4, RemovalCause::Explicit),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_subscribers_receive_events_on_another_thread() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(1).clock(clock.clone()).build();
+        let events = cache.subscribe();
+        let consumer = std::thread::spawn(move || events.iter().collect::<Vec<_>>());
+
+        cache.put("a", 1);
+        clock.advance(Duration::from_secs(3));
+        cache.put("b", 2);
+        drop(cache);
+
+        let events = consumer.join().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].key, "a");
+        assert_eq!(events[0].cause, RemovalCause::Evicted);
+        assert_eq!(events[0].timestamp, clock.now());
+    }
+
+    #[test]
+    fn test_dropped_subscriber_stops_cloning_keys() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CLONES: AtomicUsize = AtomicUsize::new(0);
+        #[derive(PartialEq, Eq, Hash)]
+        struct Key(u32);
+        impl Clone for Key {
+            fn clone(&self) -> Self {
+                CLONES.fetch_add(1, Ordering::Relaxed);
+                Key(self.0)
+            }
+        }
+
+        let mut cache = LruCache::new(1);
+        drop(cache.subscribe());
+        for i in 0..4 {
+            cache.put(Key(i), i);
+        }
+        // Seul le premier retrait, dont l'envoi échoue, a cloné sa clé.
+        assert_eq!(CLONES.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,300 @@
+use std::hash::Hash;
+
+use crate::cache::{Cache, LruCache};
+
+/// Source de données consultée par un [`LoadingCache`] en cas d'échec.
+///
+/// Implémenté automatiquement pour toute closure `Fn(&K) -> Result<V, E>`.
+pub trait CacheLoader<K, V> {
+    /// Erreur remontée quand la source ne peut pas fournir la valeur.
+    type Error;
+
+    /// Charge la valeur associée à `key` depuis la source.
+    fn load(&self, key: &K) -> Result<V, Self::Error>;
+}
+
+impl<K, V, E, F> CacheLoader<K, V> for F
+where
+    F: Fn(&K) -> Result<V, E>,
+{
+    type Error = E;
+
+    fn load(&self, key: &K) -> Result<V, E> {
+        self(key)
+    }
+}
+
+/// Cache en lecture traversante (*read-through*) : `get` remplit
+/// automatiquement les échecs depuis un [`CacheLoader`].
+///
+/// Si le cache est configuré avec [`refresh_after`](crate::LruCacheBuilder::refresh_after),
+/// la première lecture d'une entrée vieillissante la recharge ; en cas
+/// d'erreur du loader, la valeur courante continue d'être servie.
+///
+/// Avec [`serve_stale`](Self::serve_stale), une entrée expirée mais encore
+/// présente est servie immédiatement et son rechargement mis en file.
+///
+/// # Exemple
+/// ```
+/// use std::convert::Infallible;
+/// use cache_lru_project::{LoadingCache, LruCache};
+///
+/// let mut cache = LoadingCache::new(LruCache::new(10), |key: &u32| Ok::<_, Infallible>(key * 2));
+/// let Ok(value) = cache.get(&21);
+/// assert_eq!(*value, 42);
+/// ```
+pub struct LoadingCache<K, V, L> {
+    cache: LruCache<K, V>,
+    loader: L,
+    /// Clés expirées servies, en attente de rechargement (`None` = pas de
+    /// *stale-while-revalidate*).
+    stale: Option<Vec<K>>,
+    /// Dernière valeur chargée que le cache ne pouvait pas garder (plus
+    /// lourde que le poids maximal, ou expirée dès l'écriture).
+    uncached: Option<V>,
+}
+
+impl<K, V, L> LoadingCache<K, V, L>
+where
+    K: Hash + Eq + Clone,
+    L: CacheLoader<K, V>,
+{
+    /// Associe un cache (éventuellement pré-rempli ou configuré) à sa source.
+    pub fn new(cache: LruCache<K, V>, loader: L) -> Self {
+        LoadingCache {
+            cache,
+            loader,
+            stale: None,
+            uncached: None,
+        }
+    }
+
+    /// Active le mode *stale-while-revalidate* : `get` sur une entrée
+    /// expirée mais pas encore retirée retourne aussitôt l'ancienne valeur
+    /// et met la clé en file, au lieu de bloquer sur le loader.
+    ///
+    /// La file est traitée par [`revalidate`](Self::revalidate), à appeler
+    /// hors du chemin critique (tâche de fond, fin de requête...). Une
+    /// entrée déjà retirée (éviction, `purge_expired`) est chargée normalement.
+    pub fn serve_stale(mut self) -> Self {
+        self.stale = Some(Vec::new());
+        self
+    }
+
+    /// Recharge les clés servies périmées depuis le dernier appel et
+    /// retourne le nombre de rechargements réussis.
+    ///
+    /// Une clé en échec garde sa valeur périmée ; elle sera remise en file
+    /// à sa prochaine lecture.
+    pub fn revalidate(&mut self) -> usize {
+        let pending = self.stale.as_mut().map(std::mem::take).unwrap_or_default();
+        let mut reloaded = 0;
+        for key in pending {
+            if let Ok(value) = self.loader.load(&key) {
+                let _ = self.cache.store_loaded(key, value);
+                reloaded += 1;
+            }
+        }
+        reloaded
+    }
+
+    /// Indice d'une entrée présente mais expirée, si le mode
+    /// *stale-while-revalidate* est actif ; la clé est alors mise en file.
+    fn stale_index(&mut self, key: &K) -> Option<usize> {
+        let pending = self.stale.as_mut()?;
+        let index = self.cache.find(key)?;
+        if !self.cache.arena[index].is_expired(self.cache.clock.now(), self.cache.tti) {
+            return None;
+        }
+        if !pending.contains(key) {
+            pending.push(key.clone());
+        }
+        Some(index)
+    }
+
+    /// Retourne la valeur de `key`, en la chargeant depuis la source si absente.
+    ///
+    /// Une erreur du loader est remontée telle quelle et rien n'est mis en cache.
+    /// Le [`doorkeeper`](crate::LruCacheBuilder::doorkeeper) ne filtre pas
+    /// les valeurs chargées ; une valeur que le cache ne peut pas garder
+    /// (trop lourde, TTL nul) est retournée sans être mise en cache.
+    pub fn get(&mut self, key: &K) -> Result<&V, L::Error> {
+        if let Some(index) = self.stale_index(key) {
+            return Ok(&self.cache.arena[index].value);
+        }
+        let mut refresh_due = false;
+        let hit = self.cache.get_with_refresh(key, |_| refresh_due = true).is_some();
+        let value = match (hit, refresh_due) {
+            (false, _) => self.loader.load(key)?,
+            (true, true) if let Ok(value) = self.loader.load(key) => value,
+            (true, _) => {
+                let index = self.cache.find(key).expect("entrée lue à l'instant");
+                return Ok(&self.cache.arena[index].value);
+            }
+        };
+        match self.cache.store_loaded(key.clone(), value) {
+            Ok(index) => Ok(&self.cache.arena[index].value),
+            Err(value) => Ok(self.uncached.insert(value)),
+        }
+    }
+
+    /// Recharge `key` depuis la source, que la clé soit présente ou non.
+    pub fn refresh(&mut self, key: &K) -> Result<(), L::Error> {
+        let value = self.loader.load(key)?;
+        let _ = self.cache.store_loaded(key.clone(), value);
+        Ok(())
+    }
+
+    /// Pré-charge `keys`, classées de la plus chaude à la plus froide,
+    /// depuis la source (voir [`LruCache::warm`]).
+    ///
+    /// Les clés en échec sont ignorées : elles seront chargées au premier
+    /// `get`. Retourne le nombre d'entrées chargées.
+    pub fn warm_from_loader(&mut self, keys: impl IntoIterator<Item = K>) -> usize {
+        let loaded: Vec<_> = keys
+            .into_iter()
+            .filter(|key| !self.cache.contains(key))
+            .filter_map(|key| self.loader.load(&key).ok().map(|value| (key, value)))
+            .take(self.cache.capacity())
+            .collect();
+        self.cache.warm(loaded)
+    }
+
+    /// Cache sous-jacent, pour les lectures sans chargement.
+    pub fn cache(&self) -> &LruCache<K, V> {
+        &self.cache
+    }
+
+    /// Cache sous-jacent, pour les écritures ou invalidations manuelles.
+    pub fn cache_mut(&mut self) -> &mut LruCache<K, V> {
+        &mut self.cache
+    }
+
+    /// Sépare le cache de sa source.
+    pub fn into_inner(self) -> LruCache<K, V> {
+        self.cache
+    }
+}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Écrit une valeur chargée avec le TTL du cache et retourne son
+    /// emplacement ; voir `store_computed` pour les valeurs rendues.
+    pub(crate) fn store_loaded(&mut self, key: K, value: V) -> Result<usize, V> {
+        let expires_at = self.default_deadline();
+        self.store_computed(key, value, expires_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockClock;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    #[test]
+    fn test_misses_are_loaded_once() {
+        let calls = Cell::new(0);
+        let loader = |key: &u32| {
+            calls.set(calls.get() + 1);
+            if *key == 0 { Err("clé invalide") } else { Ok(key * 10) }
+        };
+        let mut cache = LoadingCache::new(LruCache::new(4), loader);
+
+        assert_eq!(cache.get(&1), Ok(&10));
+        assert_eq!(cache.get(&1), Ok(&10));
+        assert_eq!(calls.get(), 1);
+
+        assert_eq!(cache.get(&0), Err("clé invalide"));
+        assert_eq!(cache.cache().len(), 1);
+    }
+
+    #[test]
+    fn test_refresh_ahead_reloads_aging_entries() {
+        let clock = MockClock::new();
+        let version = Cell::new(1);
+        let lru = LruCache::builder(4)
+            .ttl(Duration::from_secs(10))
+            .refresh_after(Duration::from_secs(8))
+            .clock(clock.clone())
+            .build();
+        let mut cache = LoadingCache::new(lru, |_: &&str| Ok::<_, ()>(version.get()));
+
+        assert_eq!(cache.get(&"k"), Ok(&1));
+        version.set(2);
+        clock.advance(Duration::from_secs(9));
+        assert_eq!(cache.get(&"k"), Ok(&2));
+    }
+
+    #[test]
+    fn test_serve_stale_then_revalidate() {
+        let clock = MockClock::new();
+        let version = Cell::new(1);
+        let lru = LruCache::builder(4).ttl(Duration::from_secs(10)).clock(clock.clone()).build();
+        let mut cache = LoadingCache::new(lru, |_: &&str| Ok::<_, ()>(version.get())).serve_stale();
+
+        assert_eq!(cache.get(&"k"), Ok(&1));
+        version.set(2);
+        clock.advance(Duration::from_secs(10));
+        // Expirée : l'ancienne valeur est servie sans appeler le loader.
+        assert_eq!(cache.get(&"k"), Ok(&1));
+        assert_eq!(cache.get(&"k"), Ok(&1));
+        assert_eq!(cache.revalidate(), 1);
+        assert_eq!(cache.get(&"k"), Ok(&2));
+        assert_eq!(cache.revalidate(), 0);
+    }
+
+    #[test]
+    fn test_loaded_values_bypass_admission() {
+        let lru = LruCache::builder(4).doorkeeper(100).build();
+        let mut cache = LoadingCache::new(lru, |key: &u32| Ok::<_, ()>(key * 10));
+        assert_eq!(cache.get(&1), Ok(&10));
+        assert!(cache.cache().contains(&1));
+        assert_eq!(cache.get(&2), Ok(&20));
+        assert_eq!(cache.cache().len(), 2);
+    }
+
+    #[test]
+    fn test_zero_ttl_with_doorkeeper_is_not_cached() {
+        let calls = Cell::new(0);
+        let lru = LruCache::builder(4).ttl(Duration::ZERO).doorkeeper(100).build();
+        let mut cache = LoadingCache::new(lru, |key: &u32| {
+            calls.set(calls.get() + 1);
+            Ok::<_, ()>(key * 10)
+        });
+        assert_eq!(cache.get(&1), Ok(&10));
+        assert_eq!(cache.get(&1), Ok(&10));
+        assert_eq!(calls.get(), 2);
+        assert!(cache.cache().is_empty());
+    }
+
+    #[test]
+    fn test_overweight_value_is_returned_uncached() {
+        let lru = LruCache::builder(4).max_weight(5, |_: &u32, v: &String| v.len() as u32).build();
+        let mut cache = LoadingCache::new(lru, |key: &u32| Ok::<_, ()>("x".repeat(*key as usize)));
+        assert_eq!(cache.get(&3).map(String::len), Ok(3));
+        assert_eq!(cache.get(&8).map(String::len), Ok(8));
+        assert_eq!(cache.cache().len(), 1);
+    }
+
+    #[test]
+    fn test_loaded_value_evicted_by_priority_is_returned() {
+        let mut lru = LruCache::builder(4).max_weight(10, |_: &u32, v: &String| v.len() as u32).build();
+        lru.put_with_priority(0, "x".repeat(8), crate::Priority::High);
+        let mut cache = LoadingCache::new(lru, |key: &u32| Ok::<_, ()>("x".repeat(*key as usize)));
+        // 8 + 5 > 10 : la valeur chargée (Normal) passe avant l'entrée High.
+        assert_eq!(cache.get(&5).map(String::len), Ok(5));
+        assert!(cache.cache().contains(&0) && !cache.cache().contains(&5));
+        assert_eq!(cache.get(&2).map(String::len), Ok(2));
+        assert_eq!(cache.cache().len(), 2);
+    }
+
+    #[test]
+    fn test_warm_from_loader() {
+        let loader = |key: &u32| if *key == 0 { Err(()) } else { Ok(key * 10) };
+        let mut cache = LoadingCache::new(LruCache::new(2), loader);
+        assert_eq!(cache.warm_from_loader([3, 0, 1, 2]), 2);
+        let keys: Vec<_> = cache.cache().iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, [3, 1]);
+    }
+}
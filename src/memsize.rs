@@ -0,0 +1,230 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::mem::{size_of, size_of_val};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::cache::{Cache, LruCache, Node};
+use crate::weight::Weigher;
+
+/// Estimation de la mémoire possédée par une valeur sur le tas.
+///
+/// `heap_size` ne compte que les allocations : la taille en ligne de la
+/// valeur (`size_of::<Self>()`) est comptée par le conteneur.
+pub trait MemSize {
+    /// Octets alloués sur le tas et possédés par `self`.
+    fn heap_size(&self) -> usize;
+}
+
+macro_rules! no_heap {
+    ($($t:ty),*) => {
+        $(impl MemSize for $t {
+            fn heap_size(&self) -> usize {
+                0
+            }
+        })*
+    };
+}
+
+no_heap!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64
+);
+
+impl<T: ?Sized> MemSize for &T {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl MemSize for str {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl MemSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: MemSize> MemSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(MemSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: MemSize> MemSize for VecDeque<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(MemSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: MemSize + ?Sized> MemSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        size_of_val(&**self) + (**self).heap_size()
+    }
+}
+
+impl<T: MemSize> MemSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, MemSize::heap_size)
+    }
+}
+
+/// Compte la valeur partagée en entier, même si d'autres `Arc` la référencent.
+impl<T: MemSize + ?Sized> MemSize for Arc<T> {
+    fn heap_size(&self) -> usize {
+        size_of_val(&**self) + (**self).heap_size()
+    }
+}
+
+/// Compte la valeur partagée en entier, même si d'autres `Rc` la référencent.
+impl<T: MemSize + ?Sized> MemSize for Rc<T> {
+    fn heap_size(&self) -> usize {
+        size_of_val(&**self) + (**self).heap_size()
+    }
+}
+
+impl<T: MemSize, const N: usize> MemSize for [T; N] {
+    fn heap_size(&self) -> usize {
+        self.iter().map(MemSize::heap_size).sum()
+    }
+}
+
+impl<A: MemSize, B: MemSize> MemSize for (A, B) {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size() + self.1.heap_size()
+    }
+}
+
+impl<A: MemSize, B: MemSize, C: MemSize> MemSize for (A, B, C) {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size() + self.1.heap_size() + self.2.heap_size()
+    }
+}
+
+/// Surcoût estimé de l'index par entrée (hash, chaînage, bucket).
+const INDEX_ENTRY_BYTES: usize =
+    size_of::<u64>() + size_of::<Option<usize>>() + size_of::<(u64, usize)>() + 1;
+
+/// [`Weigher`] estimant l'empreinte mémoire d'une entrée (nœud, index et tas).
+pub(crate) struct MemoryWeigher;
+
+impl<K: MemSize, V: MemSize> Weigher<K, V> for MemoryWeigher {
+    fn weight(&self, key: &K, value: &V) -> u32 {
+        let bytes =
+            size_of::<Node<K, V>>() + INDEX_ENTRY_BYTES + key.heap_size() + value.heap_size();
+        u32::try_from(bytes).unwrap_or(u32::MAX)
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + MemSize,
+    V: MemSize,
+{
+    /// Estimation de la mémoire occupée par le cache : structure, arena et
+    /// index (capacité allouée comprise), plus le tas des clés et valeurs.
+    ///
+    /// # Complexité
+    /// O(n) : le tas de chaque entrée est mesuré.
+    pub fn memory_usage(&self) -> usize {
+        let entries: usize = self
+            .arena
+            .iter()
+            .map(|node| node.key.heap_size() + node.value.heap_size())
+            .sum();
+        size_of::<Self>()
+            + self.arena.capacity() * size_of::<Node<K, V>>()
+            + self.index.heap_size()
+            + entries
+    }
+}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Rend à l'allocateur la mémoire de l'arena et de l'index qui dépasse
+    /// les entrées présentes (après un `clear` ou de nombreuses suppressions).
+    ///
+    /// Les insertions suivantes réallouent au fur et à mesure.
+    pub fn shrink_to_fit(&mut self) {
+        self.arena.shrink_to_fit();
+        self.index.shrink_to_fit();
+        self.evicted_dirty.shrink_to_fit();
+    }
+
+    /// Alloue d'avance la place de `additional` entrées supplémentaires,
+    /// pour éviter les réallocations pendant le remplissage.
+    ///
+    /// La réservation est bornée par la place restante sous la capacité.
+    pub fn reserve(&mut self, additional: usize) {
+        let additional = additional.min(self.capacity().saturating_sub(self.arena.len()));
+        self.arena.reserve(additional);
+        self.index.reserve(additional);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heap_sizes() {
+        assert_eq!(42u64.heap_size(), 0);
+        assert_eq!(String::with_capacity(10).heap_size(), 10);
+        let nested = vec![String::with_capacity(4), String::with_capacity(6)];
+        assert_eq!(nested.heap_size(), 2 * size_of::<String>() + 10);
+        assert_eq!(Some(Box::new(1u32)).heap_size(), 4);
+        assert_eq!(Arc::<str>::from("https://").heap_size(), 8);
+    }
+
+    #[test]
+    fn test_max_memory_bounds_cache() {
+        let mut cache = LruCache::builder(1000).max_memory_bytes(10_000).build();
+        for i in 0..100u32 {
+            cache.put(i, vec![0u8; 1000]);
+        }
+        assert!(cache.total_weight() <= 10_000);
+        assert!(cache.len() < 10);
+        assert!(cache.contains(&99));
+        assert!(cache.memory_usage() >= cache.len() * 1000);
+    }
+
+    #[test]
+    fn test_shrink_to_fit_and_reserve() {
+        let mut cache = LruCache::new(10_000);
+        for i in 0..5000u32 {
+            cache.put(i, i);
+        }
+        let full = cache.memory_usage();
+        cache.clear();
+        cache.shrink_to_fit();
+        assert!(cache.memory_usage() < full / 10);
+
+        cache.reserve(usize::MAX);
+        assert!(cache.arena.capacity() >= 10_000);
+        assert!(cache.arena.capacity() < 20_000);
+        for i in 0..3u32 {
+            cache.put(i, i);
+        }
+        cache.shrink_to_fit();
+        assert_eq!(cache.arena.capacity(), 3);
+        assert_eq!(cache.get(&2), Some(&2));
+    }
+}
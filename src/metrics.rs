@@ -0,0 +1,137 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::cache::{Cache, LruCache};
+use crate::stats::Stat;
+
+/// Destination des métriques du cache (Prometheus, StatsD...).
+///
+/// Une implémentation relaie typiquement vers la façade du crate `metrics` :
+/// ```ignore
+/// struct Facade;
+/// impl MetricsRecorder for Facade {
+///     fn increment_counter(&self, name: &str, value: u64) {
+///         metrics::counter!(name.to_owned()).increment(value);
+///     }
+///     fn set_gauge(&self, name: &str, value: f64) {
+///         metrics::gauge!(name.to_owned()).set(value);
+///     }
+/// }
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// Incrémente le compteur `name` de `value`.
+    fn increment_counter(&self, name: &str, value: u64);
+
+    /// Fixe la jauge `name` à `value`.
+    fn set_gauge(&self, name: &str, value: f64);
+}
+
+/// Recorder et noms de métriques pré-calculés d'un cache.
+pub(crate) struct Metrics {
+    recorder: Arc<dyn MetricsRecorder>,
+    hits: String,
+    misses: String,
+    insertions: String,
+    updates: String,
+    evictions: String,
+    expirations: String,
+    ghost_hits: String,
+    size: String,
+    capacity: String,
+}
+
+impl Metrics {
+    /// Les métriques sont nommées `{prefix}_hits`, `{prefix}_size`, etc.
+    pub(crate) fn new(recorder: Arc<dyn MetricsRecorder>, prefix: &str) -> Self {
+        let name = |suffix: &str| format!("{prefix}_{suffix}");
+        Metrics {
+            recorder,
+            hits: name("hits"),
+            misses: name("misses"),
+            insertions: name("insertions"),
+            updates: name("updates"),
+            evictions: name("evictions"),
+            expirations: name("expirations"),
+            ghost_hits: name("ghost_hits"),
+            size: name("size"),
+            capacity: name("capacity"),
+        }
+    }
+
+    fn counter(&self, stat: Stat) -> &str {
+        match stat {
+            Stat::Hit => &self.hits,
+            Stat::Miss => &self.misses,
+            Stat::Insertion => &self.insertions,
+            Stat::Update => &self.updates,
+            Stat::Eviction => &self.evictions,
+            Stat::Expiration => &self.expirations,
+            Stat::GhostHit => &self.ghost_hits,
+        }
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Publie un événement (et la taille courante) vers le recorder configuré.
+    pub(crate) fn emit(&self, stat: Stat) {
+        if let Some(metrics) = &self.metrics {
+            metrics.recorder.increment_counter(metrics.counter(stat), 1);
+            self.emit_size();
+        }
+    }
+
+    /// Publie les jauges de taille et de capacité.
+    pub(crate) fn emit_size(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.recorder.set_gauge(&metrics.size, self.len() as f64);
+            metrics.recorder.set_gauge(&metrics.capacity, self.capacity() as f64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        counters: Mutex<HashMap<String, u64>>,
+        gauges: Mutex<HashMap<String, f64>>,
+    }
+
+    impl MetricsRecorder for Recorder {
+        fn increment_counter(&self, name: &str, value: u64) {
+            *self.counters.lock().unwrap().entry(name.to_owned()).or_default() += value;
+        }
+
+        fn set_gauge(&self, name: &str, value: f64) {
+            self.gauges.lock().unwrap().insert(name.to_owned(), value);
+        }
+    }
+
+    #[test]
+    fn test_metrics_are_emitted_with_prefix() {
+        let recorder = Arc::new(Recorder::default());
+        let mut cache = LruCache::builder(2).metrics(recorder.clone(), "sessions").build();
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        cache.get(&"c");
+        cache.get(&"a");
+        cache.remove(&"c");
+
+        let counters = recorder.counters.lock().unwrap();
+        assert_eq!(counters["sessions_insertions"], 3);
+        assert_eq!(counters["sessions_evictions"], 1);
+        assert_eq!(counters["sessions_hits"], 1);
+        assert_eq!(counters["sessions_misses"], 1);
+        let gauges = recorder.gauges.lock().unwrap();
+        assert_eq!(gauges["sessions_size"], 1.0);
+        assert_eq!(gauges["sessions_capacity"], 2.0);
+    }
+}
@@ -0,0 +1,491 @@
+use std::ffi::c_void;
+use std::fs::OpenOptions;
+use std::io::{self, ErrorKind};
+use std::marker::PhantomData;
+use std::os::fd::AsRawFd;
+use std::path::Path;
+
+/// Signature des fichiers projetés, suivie de la version du format.
+const MAGIC: &[u8; 4] = b"LRUM";
+const VERSION: u32 = 1;
+
+/// Absence de nœud (liste, chaîne de hachage ou liste libre).
+const NIL: u32 = u32::MAX;
+
+// En-tête : champs `u32` little-endian à ces positions.
+const VERSION_AT: usize = 4;
+const CAPACITY_AT: usize = 8;
+const KEY_SIZE_AT: usize = 12;
+const VALUE_SIZE_AT: usize = 16;
+const LEN_AT: usize = 20;
+const HEAD_AT: usize = 24;
+const TAIL_AT: usize = 28;
+/// Premier emplacement de la liste des emplacements libérés.
+const FREE_AT: usize = 32;
+/// Premier emplacement jamais utilisé.
+const UNUSED_AT: usize = 36;
+const HEADER_SIZE: usize = 40;
+
+// Nœud : `prev`, `next`, `chain` (suivant dans l'alvéole), puis clé et valeur.
+const PREV: usize = 0;
+const NEXT: usize = 4;
+const CHAIN: usize = 8;
+const KEY: usize = 12;
+
+const PROT_READ: i32 = 1;
+const PROT_WRITE: i32 = 2;
+const MAP_SHARED: i32 = 1;
+const MS_SYNC: i32 = 4;
+
+unsafe extern "C" {
+    fn mmap(addr: *mut c_void, len: usize, prot: i32, flags: i32, fd: i32, offset: i64) -> *mut c_void;
+    fn munmap(addr: *mut c_void, len: usize) -> i32;
+    fn msync(addr: *mut c_void, len: usize, flags: i32) -> i32;
+}
+
+/// Type de taille fixe, stockable directement dans un [`MmapCache`].
+///
+/// L'encodage doit être déterministe : deux clés égales doivent produire
+/// les mêmes octets.
+pub trait FixedSize: Sized {
+    /// Nombre d'octets de l'encodage.
+    const SIZE: usize;
+
+    /// Écrit `self` dans `out` (exactement `SIZE` octets).
+    fn write_bytes(&self, out: &mut [u8]);
+
+    /// Relit une valeur écrite par [`write_bytes`](Self::write_bytes).
+    fn read_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! fixed_size {
+    ($($t:ty),*) => {
+        $(impl FixedSize for $t {
+            const SIZE: usize = size_of::<$t>();
+
+            fn write_bytes(&self, out: &mut [u8]) {
+                out.copy_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_bytes(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().expect("taille fixe"))
+            }
+        })*
+    };
+}
+
+fixed_size!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl<const N: usize> FixedSize for [u8; N] {
+    const SIZE: usize = N;
+
+    fn write_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(self);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Self {
+        bytes.try_into().expect("taille fixe")
+    }
+}
+
+/// Fichier projeté en mémoire, en lecture/écriture partagée.
+struct Mapping {
+    ptr: *mut u8,
+    len: usize,
+}
+
+// SAFETY : la projection appartient exclusivement à `Mapping` ; aucun alias
+// n'en sort hors des emprunts de `bytes` / `bytes_mut`.
+unsafe impl Send for Mapping {}
+
+impl Mapping {
+    fn new(file: &std::fs::File, len: usize) -> io::Result<Self> {
+        // SAFETY : appel système sans pointeur d'entrée ; le résultat est vérifié.
+        let ptr = unsafe {
+            mmap(
+                std::ptr::null_mut(),
+                len,
+                PROT_READ | PROT_WRITE,
+                MAP_SHARED,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr as usize == usize::MAX {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Mapping { ptr: ptr.cast(), len })
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY : `ptr` désigne `len` octets projetés, valides jusqu'au `munmap`.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY : idem, et `&mut self` garantit l'exclusivité.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        // SAFETY : la plage est exactement celle projetée.
+        if unsafe { msync(self.ptr.cast(), self.len, MS_SYNC) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // SAFETY : la plage est exactement celle projetée, et plus aucun
+        // emprunt n'est actif.
+        unsafe { munmap(self.ptr.cast(), self.len) };
+    }
+}
+
+/// Cache LRU dont l'arène vit dans un fichier projeté en mémoire.
+///
+/// Clés et valeurs sont de taille fixe ([`FixedSize`]) ; l'arène, la liste
+/// de récence et la table de hachage sont stockées telles quelles dans le
+/// fichier. Rouvrir le cache est donc en O(1) : rien n'est relu ni
+/// réinséré, les pages sont chargées à la demande par le système.
+///
+/// Les modifications atteignent le fichier au gré du système ;
+/// [`flush`](Self::flush) force leur écriture. Un arrêt brutal au milieu
+/// d'une opération peut laisser le fichier incohérent. Le fichier ne doit
+/// être ouvert que par un seul `MmapCache` à la fois.
+pub struct MmapCache<K, V> {
+    map: Mapping,
+    buckets: usize,
+    _entries: PhantomData<(K, V)>,
+}
+
+impl<K: FixedSize, V: FixedSize> MmapCache<K, V> {
+    /// Ouvre le cache projeté dans `path`, ou le crée vide avec `capacity`
+    /// entrées.
+    ///
+    /// Un fichier existant créé avec une autre capacité ou d'autres tailles
+    /// de clé ou de valeur est une erreur `ErrorKind::InvalidData`.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0 ou ne tient pas sur 32 bits.
+    pub fn open(path: impl AsRef<Path>, capacity: usize) -> io::Result<Self> {
+        assert!(capacity > 0, "La capacité doit être > 0");
+        assert!(capacity < NIL as usize, "Capacité trop grande pour un cache projeté");
+        let buckets = (capacity * 2).next_power_of_two();
+        let size = HEADER_SIZE + buckets * 4 + capacity * Self::node_size();
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let existing = file.metadata()?.len();
+        if existing == 0 {
+            file.set_len(size as u64)?;
+        } else if existing != size as u64 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!("fichier projeté de {existing} octets, {size} attendus"),
+            ));
+        }
+        let mut cache = MmapCache {
+            map: Mapping::new(&file, size)?,
+            buckets,
+            _entries: PhantomData,
+        };
+
+        if existing == 0 {
+            cache.map.bytes_mut()[..4].copy_from_slice(MAGIC);
+            cache.set(VERSION_AT, VERSION);
+            cache.set(CAPACITY_AT, capacity as u32);
+            cache.set(KEY_SIZE_AT, K::SIZE as u32);
+            cache.set(VALUE_SIZE_AT, V::SIZE as u32);
+            cache.reset();
+        } else if &cache.map.bytes()[..4] != MAGIC
+            || cache.at(VERSION_AT) != VERSION
+            || cache.at(CAPACITY_AT) != capacity as u32
+            || cache.at(KEY_SIZE_AT) != K::SIZE as u32
+            || cache.at(VALUE_SIZE_AT) != V::SIZE as u32
+        {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "en-tête de fichier projeté incompatible",
+            ));
+        }
+        Ok(cache)
+    }
+
+    fn node_size() -> usize {
+        KEY + K::SIZE + V::SIZE
+    }
+
+    fn at(&self, offset: usize) -> u32 {
+        u32::from_le_bytes(self.map.bytes()[offset..offset + 4].try_into().expect("4 octets"))
+    }
+
+    fn set(&mut self, offset: usize, value: u32) {
+        self.map.bytes_mut()[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn node(&self, slot: u32) -> usize {
+        HEADER_SIZE + self.buckets * 4 + slot as usize * Self::node_size()
+    }
+
+    fn key_bytes(&self, slot: u32) -> &[u8] {
+        let start = self.node(slot) + KEY;
+        &self.map.bytes()[start..start + K::SIZE]
+    }
+
+    fn value(&self, slot: u32) -> V {
+        let start = self.node(slot) + KEY + K::SIZE;
+        V::read_bytes(&self.map.bytes()[start..start + V::SIZE])
+    }
+
+    fn set_value(&mut self, slot: u32, value: &V) {
+        let start = self.node(slot) + KEY + K::SIZE;
+        value.write_bytes(&mut self.map.bytes_mut()[start..start + V::SIZE]);
+    }
+
+    /// Position de la tête de chaîne de l'alvéole de `key` (FNV-1a).
+    fn bucket(&self, key: &[u8]) -> usize {
+        let hash = key.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        HEADER_SIZE + (hash as usize & (self.buckets - 1)) * 4
+    }
+
+    fn encode(key: &K) -> Vec<u8> {
+        let mut bytes = vec![0; K::SIZE];
+        key.write_bytes(&mut bytes);
+        bytes
+    }
+
+    fn find(&self, key: &[u8]) -> Option<u32> {
+        let mut slot = self.at(self.bucket(key));
+        while slot != NIL {
+            if self.key_bytes(slot) == key {
+                return Some(slot);
+            }
+            slot = self.at(self.node(slot) + CHAIN);
+        }
+        None
+    }
+
+    fn unchain(&mut self, slot: u32) {
+        let mut link = self.bucket(self.key_bytes(slot));
+        while self.at(link) != slot {
+            link = self.node(self.at(link)) + CHAIN;
+        }
+        let next = self.at(self.node(slot) + CHAIN);
+        self.set(link, next);
+    }
+
+    fn detach(&mut self, slot: u32) {
+        let prev = self.at(self.node(slot) + PREV);
+        let next = self.at(self.node(slot) + NEXT);
+        match prev {
+            NIL => self.set(HEAD_AT, next),
+            prev => self.set(self.node(prev) + NEXT, next),
+        }
+        match next {
+            NIL => self.set(TAIL_AT, prev),
+            next => self.set(self.node(next) + PREV, prev),
+        }
+    }
+
+    fn push_front(&mut self, slot: u32) {
+        let head = self.at(HEAD_AT);
+        self.set(self.node(slot) + PREV, NIL);
+        self.set(self.node(slot) + NEXT, head);
+        match head {
+            NIL => self.set(TAIL_AT, slot),
+            head => self.set(self.node(head) + PREV, slot),
+        }
+        self.set(HEAD_AT, slot);
+    }
+
+    /// Réserve un emplacement : libéré, neuf, ou celui du plus ancien.
+    fn allocate(&mut self) -> u32 {
+        let free = self.at(FREE_AT);
+        let unused = self.at(UNUSED_AT);
+        if free != NIL {
+            let next = self.at(self.node(free) + NEXT);
+            self.set(FREE_AT, next);
+            free
+        } else if unused < self.at(CAPACITY_AT) {
+            self.set(UNUSED_AT, unused + 1);
+            unused
+        } else {
+            let tail = self.at(TAIL_AT);
+            self.detach(tail);
+            self.unchain(tail);
+            tail
+        }
+    }
+
+    /// Insère ou met à jour une entrée, et la rend la plus récente.
+    /// Si le cache est plein, l'entrée la moins récente est évincée.
+    pub fn put(&mut self, key: K, value: V) {
+        let bytes = Self::encode(&key);
+        if let Some(slot) = self.find(&bytes) {
+            self.set_value(slot, &value);
+            self.detach(slot);
+            self.push_front(slot);
+            return;
+        }
+        let full = self.len() == self.capacity();
+        let slot = self.allocate();
+        if !full {
+            let len = self.at(LEN_AT);
+            self.set(LEN_AT, len + 1);
+        }
+        let start = self.node(slot) + KEY;
+        self.map.bytes_mut()[start..start + K::SIZE].copy_from_slice(&bytes);
+        self.set_value(slot, &value);
+        let bucket = self.bucket(&bytes);
+        let chain = self.at(bucket);
+        self.set(self.node(slot) + CHAIN, chain);
+        self.set(bucket, slot);
+        self.push_front(slot);
+    }
+
+    /// Retourne la valeur de `key` et la rend la plus récente.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let slot = self.find(&Self::encode(key))?;
+        self.detach(slot);
+        self.push_front(slot);
+        Some(self.value(slot))
+    }
+
+    /// Retourne la valeur de `key` sans modifier la récence.
+    pub fn peek(&self, key: &K) -> Option<V> {
+        self.find(&Self::encode(key)).map(|slot| self.value(slot))
+    }
+
+    /// Indique si `key` est présente (sans effet sur la récence).
+    pub fn contains(&self, key: &K) -> bool {
+        self.find(&Self::encode(key)).is_some()
+    }
+
+    /// Supprime `key` et retourne sa valeur.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.find(&Self::encode(key))?;
+        let value = self.value(slot);
+        self.detach(slot);
+        self.unchain(slot);
+        let free = self.at(FREE_AT);
+        self.set(self.node(slot) + NEXT, free);
+        self.set(FREE_AT, slot);
+        let len = self.at(LEN_AT);
+        self.set(LEN_AT, len - 1);
+        Some(value)
+    }
+
+    /// Vide le cache (le fichier garde sa taille).
+    pub fn clear(&mut self) {
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        for offset in [LEN_AT, UNUSED_AT] {
+            self.set(offset, 0);
+        }
+        for offset in [HEAD_AT, TAIL_AT, FREE_AT] {
+            self.set(offset, NIL);
+        }
+        let buckets = HEADER_SIZE..HEADER_SIZE + self.buckets * 4;
+        self.map.bytes_mut()[buckets].fill(0xff);
+    }
+
+    /// Nombre d'entrées présentes.
+    pub fn len(&self) -> usize {
+        self.at(LEN_AT) as usize
+    }
+
+    /// Indique si le cache ne contient aucune entrée.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Nombre maximal d'entrées, fixé à la création du fichier.
+    pub fn capacity(&self) -> usize {
+        self.at(CAPACITY_AT) as usize
+    }
+
+    /// Parcourt les entrées du Head (Récent) vers le Tail (Vieux).
+    pub fn iter(&self) -> impl Iterator<Item = (K, V)> + '_ {
+        let mut slot = self.at(HEAD_AT);
+        std::iter::from_fn(move || {
+            if slot == NIL {
+                return None;
+            }
+            let entry = (K::read_bytes(self.key_bytes(slot)), self.value(slot));
+            slot = self.at(self.node(slot) + NEXT);
+            Some(entry)
+        })
+    }
+
+    /// Force l'écriture des pages modifiées dans le fichier.
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("lru_mmap_{name}_{}.map", std::process::id()))
+    }
+
+    #[test]
+    fn test_lru_order_and_eviction() {
+        let path = temp_path("lru");
+        let mut cache: MmapCache<u32, [u8; 4]> = MmapCache::open(&path, 3).unwrap();
+        cache.put(1, *b"un  ");
+        cache.put(2, *b"deux");
+        cache.put(3, *b"tros");
+        assert_eq!(cache.get(&1), Some(*b"un  "));
+        cache.put(4, *b"quat");
+        assert!(!cache.contains(&2));
+        assert_eq!(cache.remove(&3), Some(*b"tros"));
+        cache.put(5, *b"cinq");
+        cache.put(1, *b"UN  ");
+        let keys: Vec<u32> = cache.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec![1, 5, 4]);
+        assert_eq!(cache.len(), 3);
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&1), None);
+        drop(cache);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_reopen_keeps_entries() {
+        let path = temp_path("reopen");
+        {
+            let mut cache: MmapCache<u64, f64> = MmapCache::open(&path, 100).unwrap();
+            for i in 0..150 {
+                cache.put(i, i as f64 / 2.0);
+            }
+            cache.flush().unwrap();
+        }
+        let mut cache: MmapCache<u64, f64> = MmapCache::open(&path, 100).unwrap();
+        assert_eq!(cache.len(), 100);
+        assert_eq!(cache.iter().next(), Some((149, 74.5)));
+        assert_eq!(cache.get(&49), None);
+        assert_eq!(cache.get(&50), Some(25.0));
+        drop(cache);
+
+        let err = MmapCache::<u64, f64>::open(&path, 10).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let err = MmapCache::<u64, u32>::open(&path, 100).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::cache::{Cache, LruCache};
+
+/// Résultat d'une consultation tenant compte du cache négatif.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cached<V> {
+    /// La clé est présente avec cette valeur.
+    Hit(V),
+    /// La clé est connue comme absente (voir [`LruCache::put_negative`]).
+    Negative,
+    /// Le cache ne sait rien de la clé : il faut interroger la source.
+    Miss,
+}
+
+impl<V> Cached<V> {
+    /// Retourne la valeur en cas de `Hit`.
+    pub fn hit(self) -> Option<V> {
+        match self {
+            Cached::Hit(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Enregistre que `key` n'existe pas dans la source, pendant `ttl`.
+    ///
+    /// Évite de réinterroger une source lente pour des clés absentes. Les
+    /// absences sont gardées à part, dans la limite de la capacité du cache,
+    /// et ne comptent ni dans [`len`](Cache::len) ni dans l'itération.
+    /// Une éventuelle valeur pour `key` est supprimée ; un `put` ultérieur
+    /// efface l'absence.
+    pub fn put_negative(&mut self, key: K, ttl: Duration) {
+        self.remove(&key);
+        let capacity = self.capacity();
+        let clock = self.clock.clone();
+        let negatives = self.negatives.get_or_insert_with(|| {
+            let mut negatives = LruCache::new(capacity);
+            negatives.clock = clock;
+            Box::new(negatives)
+        });
+        negatives.put_with_ttl(key, (), ttl);
+    }
+
+    /// Consulte une clé en distinguant valeur présente, absence connue et inconnu.
+    ///
+    /// # Effets de bord
+    /// Comme [`get`](Cache::get) : une valeur trouvée est promue en Tête.
+    pub fn get_cached<Q>(&mut self, key: &Q) -> Cached<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(index) = self.find_live(key) {
+            self.promote(index);
+            return Cached::Hit(&self.arena[index].value);
+        }
+        match self.negatives.as_mut().and_then(|n| n.find_live(key)) {
+            Some(_) => Cached::Negative,
+            None => Cached::Miss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockClock;
+
+    #[test]
+    fn test_negative_entries_expire_separately() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(10)
+            .ttl(Duration::from_secs(60))
+            .clock(clock.clone())
+            .build();
+        cache.put("present", 1);
+        cache.put_negative("absent", Duration::from_secs(5));
+
+        assert_eq!(cache.get_cached("present"), Cached::Hit(&1));
+        assert_eq!(cache.get_cached("absent"), Cached::Negative);
+        assert_eq!(cache.get_cached("unknown"), Cached::Miss);
+        assert_eq!(cache.len(), 1);
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.get_cached("absent"), Cached::Miss);
+        assert_eq!(cache.get_cached("present"), Cached::Hit(&1));
+    }
+
+    #[test]
+    fn test_put_overrides_negative() {
+        let mut cache = LruCache::new(10);
+        cache.put("k", 1);
+        cache.put_negative("k", Duration::from_secs(5));
+        assert_eq!(cache.get_cached("k"), Cached::Negative);
+        assert_eq!(cache.peek(&"k"), None);
+
+        cache.put("k", 2);
+        assert_eq!(cache.get_cached("k"), Cached::Hit(&2));
+    }
+}
@@ -1,37 +1,486 @@
-use std::fs::File;
-use std::io::{self, BufRead, BufReader, Write};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 use std::str::FromStr;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use crate::autosave::AutoSave;
+use crate::builder::LruCacheBuilder;
 use crate::cache::{LruCache, Cache};
+use crate::codec::{DisplayCodec, KeyCodec, ValueCodec};
+use crate::crc::{ChecksumWriter, Crc32};
+use crate::error::CacheError;
+
+/// Échappe un champ du format texte : `\`, `=`, retour à la ligne et
+/// retour chariot deviennent `\\`, `\=`, `\n` et `\r`.
+pub(crate) fn escape(field: &str, out: &mut String) {
+    for c in field.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '=' => out.push_str("\\="),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Inverse de [`escape`]. Une séquence inconnue est conservée telle quelle,
+/// ce qui relit à l'identique la plupart des fichiers antérieurs à l'échappement.
+pub(crate) fn unescape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(c @ ('\\' | '=' | '#')) => out.push(c),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Découpe une ligne `clé=valeur` au premier `=` non échappé.
+pub(crate) fn split_record(line: &str) -> Option<(String, String)> {
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' => return Some((unescape(&line[..i]), unescape(&line[i + 1..]))),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Fichier associé à un cache par [`LruCache::new_persistent`].
+pub(crate) struct SaveTarget<K, V> {
+    pub(crate) path: String,
+    /// Valeur de `changes` lors de la dernière sauvegarde réussie.
+    saved_changes: Arc<AtomicU64>,
+    /// Contenu du fichier (`write_file_contents`), capturé là où ses
+    /// contraintes de type sont connues.
+    contents: fn(&LruCache<K, V>) -> io::Result<Vec<u8>>,
+    /// Sauvegarde périodique demandée via le builder.
+    pub(crate) auto_save: Option<AutoSave<K, V>>,
+}
+
+impl<K, V> SaveTarget<K, V> {
+    /// Capture le contenu à écrire si `cache` a changé depuis la dernière sauvegarde.
+    pub(crate) fn dirty_snapshot(&self, cache: &LruCache<K, V>) -> Option<Snapshot> {
+        (cache.changes != self.saved_changes.load(Ordering::Acquire)).then(|| Snapshot {
+            path: self.path.clone(),
+            changes: cache.changes,
+            saved_changes: Arc::clone(&self.saved_changes),
+            contents: (self.contents)(cache),
+        })
+    }
+}
+
+/// Contenu d'un cache prêt à être écrit, sans garder le cache verrouillé.
+pub(crate) struct Snapshot {
+    path: String,
+    changes: u64,
+    saved_changes: Arc<AtomicU64>,
+    contents: io::Result<Vec<u8>>,
+}
+
+impl Snapshot {
+    /// Écrit l'instantané de façon atomique et le marque comme sauvegardé.
+    pub(crate) fn write(self) -> io::Result<()> {
+        let contents = self.contents?;
+        write_atomically(&self.path, |file| file.write_all(&contents))?;
+        self.saved_changes.fetch_max(self.changes, Ordering::AcqRel);
+        Ok(())
+    }
+}
+
+/// Préfixe de la ligne d'en-tête du format texte.
+const HEADER_MAGIC: &str = "#lru-cache v";
+
+/// Préfixe de la ligne finale portant le CRC-32 du fichier (version 3).
+const CHECKSUM_PREFIX: &str = "#crc32 ";
+
+/// Première version du format dont les champs sont échappés (voir [`escape`]) ;
+/// les lignes des fichiers de version 1 sont relues telles quelles.
+const ESCAPED_SINCE: u32 = 2;
+
+/// Version courante du format texte. La version 1 (sans en-tête) est
+/// celle des fichiers antérieurs, la version 2 n'a pas de somme de
+/// contrôle ; toutes deux restent relisibles.
+pub const FORMAT_VERSION: u32 = 3;
+
+/// En-tête d'un fichier texte : `#lru-cache v3 capacity=3 count=2`.
+struct Header {
+    version: u32,
+    capacity: Option<usize>,
+    count: Option<usize>,
+}
+
+impl Header {
+    /// Interprète une ligne d'en-tête. Retourne `None` si la ligne n'en est
+    /// pas une (fichier de version 1, sans en-tête).
+    fn parse(line: &str) -> Option<Result<Header, CacheError>> {
+        let rest = line.strip_prefix(HEADER_MAGIC)?;
+        let invalid = || CacheError::Parse {
+            line: 1,
+            message: format!("en-tête invalide {line:?}"),
+        };
+        let mut fields = rest.split_whitespace();
+        let Some(version) = fields.next().and_then(|v| v.parse().ok()) else {
+            return Some(Err(invalid()));
+        };
+        let mut header = Header { version, capacity: None, count: None };
+        // Les champs inconnus sont ignorés (ajouts compatibles).
+        for field in fields {
+            let (name, value) = field.split_once('=').unwrap_or((field, ""));
+            let slot = match name {
+                "capacity" => &mut header.capacity,
+                "count" => &mut header.count,
+                _ => continue,
+            };
+            match value.parse() {
+                Ok(n) => *slot = Some(n),
+                Err(_) => return Some(Err(invalid())),
+            }
+        }
+        Some(Ok(header))
+    }
+}
+
+/// Décode une ligne `clé=valeur` (sans son retour à la ligne), échappée
+/// selon `escaped`.
+fn parse_record<K, V>(
+    line: usize,
+    bytes: &[u8],
+    escaped: bool,
+    key_codec: &impl KeyCodec<K>,
+    value_codec: &impl ValueCodec<V>,
+) -> Result<Option<(K, V)>, CacheError> {
+    let parse_error = |message: String| CacheError::Parse { line, message };
+    let text = std::str::from_utf8(bytes).map_err(|_| parse_error("UTF-8 invalide".to_owned()))?;
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let record = if escaped {
+        split_record(text)
+    } else {
+        text.split_once('=').map(|(key, value)| (key.to_owned(), value.to_owned()))
+    };
+    let (key, value) = record
+        .ok_or_else(|| parse_error(format!("séparateur '=' absent dans {text:?}")))?;
+    let key = key_codec
+        .decode_key(&key)
+        .map_err(|err| parse_error(format!("clé {key:?} invalide ({err})")))?;
+    let value = value_codec
+        .decode_value(&value)
+        .map_err(|err| parse_error(format!("valeur {value:?} invalide ({err})")))?;
+    Ok(Some((key, value)))
+}
+
+/// Lit la ligne suivante dans `bytes`, retour à la ligne compris.
+/// Retourne `false` en fin de flux.
+fn read_line(reader: &mut impl BufRead, bytes: &mut Vec<u8>) -> io::Result<bool> {
+    bytes.clear();
+    Ok(reader.read_until(b'\n', bytes)? > 0)
+}
+
+/// Retire le retour à la ligne (`\n` ou `\r\n`) final.
+fn trim_newline(bytes: &[u8]) -> &[u8] {
+    let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+    bytes.strip_suffix(b"\r").unwrap_or(bytes)
+}
+
+/// Lit l'en-tête éventuel puis les enregistrements d'un flux, passés à
+/// `put` sur la destination créée par `make` à partir de la capacité enregistrée.
+///
+/// Un fichier de version 1 (sans en-tête, donc sans capacité) est migré
+/// au format courant par la prochaine sauvegarde. Chaque ligne
+/// illisible, ou un nombre d'entrées différent de celui annoncé, est passé
+/// à `on_error`, qui l'ignore ou arrête la lecture en le retournant.
+pub(crate) fn read_text<K, V, S>(
+    make: impl FnOnce(Option<usize>) -> S,
+    mut put: impl FnMut(&mut S, K, V),
+    mut reader: impl BufRead,
+    (key_codec, value_codec): (&impl KeyCodec<K>, &impl ValueCodec<V>),
+    mut on_error: impl FnMut(CacheError) -> Result<(), CacheError>,
+) -> Result<S, CacheError> {
+    let mut bytes = Vec::new();
+    if !read_line(&mut reader, &mut bytes)? {
+        return Ok(make(None));
+    }
+    let header = match std::str::from_utf8(trim_newline(&bytes)).ok().and_then(Header::parse) {
+        Some(header) => Some(header?),
+        None => None,
+    };
+    let mut crc = Crc32::new();
+    // Sans en-tête, la première ligne est déjà un enregistrement.
+    let mut pending = header.is_none();
+    if !pending {
+        crc.update(&bytes);
+    }
+    let (version, saved_capacity, expected) = match header {
+        Some(Header { version, capacity, count }) => (version, capacity.filter(|&n| n > 0), count),
+        None => (1, None, None),
+    };
+    if version > FORMAT_VERSION {
+        return Err(CacheError::UnsupportedVersion(version));
+    }
+
+    let mut cache = make(saved_capacity);
+    let mut line = 1;
+    let mut found = 0;
+    let mut checksum = None;
+    loop {
+        if !std::mem::take(&mut pending) {
+            if !read_line(&mut reader, &mut bytes)? {
+                break;
+            }
+            line += 1;
+        }
+        let text = trim_newline(&bytes);
+        if checksum.is_some() {
+            return Err(CacheError::Corrupted(format!(
+                "ligne {line} après la somme de contrôle"
+            )));
+        }
+        if let Some(hex) = text.strip_prefix(CHECKSUM_PREFIX.as_bytes()).filter(|_| version >= 3) {
+            let parsed = std::str::from_utf8(hex)
+                .ok()
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+            checksum = Some(parsed.ok_or_else(|| {
+                CacheError::Corrupted(format!("ligne {line} : somme de contrôle illisible"))
+            })?);
+            continue;
+        }
+        crc.update(&bytes);
+        match parse_record(line, text, version >= ESCAPED_SINCE, key_codec, value_codec) {
+            Ok(None) => continue,
+            Ok(Some((key, value))) => put(&mut cache, key, value),
+            Err(err) => on_error(err)?,
+        }
+        found += 1;
+    }
+    if version >= 3 {
+        match checksum {
+            None => return Err(CacheError::Corrupted("somme de contrôle absente".to_owned())),
+            Some(expected) if expected != crc.finish() => {
+                return Err(CacheError::Corrupted(format!(
+                    "somme de contrôle {expected:08x} attendue, {:08x} calculée",
+                    crc.finish()
+                )));
+            }
+            Some(_) => {}
+        }
+    }
+    if let Some(expected) = expected.filter(|&n| n != found) {
+        on_error(CacheError::Truncated { expected, found })?;
+    }
+    Ok(cache)
+}
+
+/// Écrit l'en-tête, les `count` entrées (de la plus ancienne à la plus
+/// récente) et la somme de contrôle du format texte.
+pub(crate) fn write_text<'a, K: 'a, V: 'a>(
+    writer: impl Write,
+    capacity: Option<usize>,
+    count: usize,
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+    (key_codec, value_codec): (&impl KeyCodec<K>, &impl ValueCodec<V>),
+) -> io::Result<()> {
+    let mut writer = ChecksumWriter { inner: writer, crc: Crc32::new() };
+    write!(writer, "{HEADER_MAGIC}{FORMAT_VERSION}")?;
+    if let Some(capacity) = capacity {
+        write!(writer, " capacity={capacity}")?;
+    }
+    writeln!(writer, " count={count}")?;
+    let mut line = String::new();
+    for (key, value) in entries {
+        line.clear();
+        let key = key_codec.encode_key(key);
+        // Une ligne commençant par `#` serait prise pour un en-tête ou une somme de contrôle.
+        if key.starts_with('#') {
+            line.push('\\');
+        }
+        escape(&key, &mut line);
+        line.push('=');
+        escape(&value_codec.encode_value(value), &mut line);
+        writeln!(writer, "{line}")?;
+    }
+    let crc = writer.crc.finish();
+    writeln!(writer.inner, "{CHECKSUM_PREFIX}{crc:08x}")
+}
+
+/// Écrit `path` de façon atomique : le contenu produit par `write` va dans
+/// un fichier temporaire voisin, synchronisé sur disque (`fsync`) puis
+/// renommé par-dessus la cible.
+///
+/// En cas d'erreur, le fichier temporaire est supprimé et la cible n'est
+/// pas modifiée.
+pub(crate) fn write_atomically(
+    path: &str,
+    write: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>,
+) -> io::Result<()> {
+    // Numéro propre à chaque écriture : deux threads du même processus
+    // (sauvegarde périodique et manuelle) n'utilisent pas le même fichier temporaire.
+    static WRITES: AtomicUsize = AtomicUsize::new(0);
+    let target = Path::new(path);
+    let mut temp_name = target.file_name().unwrap_or_default().to_os_string();
+    let write_id = WRITES.fetch_add(1, Ordering::Relaxed);
+    temp_name.push(format!(".tmp{}.{write_id}", std::process::id()));
+    let temp = target.with_file_name(temp_name);
+
+    let result = (|| {
+        let mut file = BufWriter::new(File::create(&temp)?);
+        write(&mut file)?;
+        file.into_inner().map_err(io::IntoInnerError::into_error)?.sync_all()?;
+        fs::rename(&temp, target)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result?;
+    // Rend le renommage lui-même durable (best-effort, non supporté partout).
+    if let Some(dir) = target.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        let _ = File::open(dir).and_then(|dir| dir.sync_all());
+    }
+    Ok(())
+}
 
 impl<K, V> LruCache<K, V>
 where
-    K: Hash + Eq + Clone + Debug + Display + FromStr,
-    V: Debug + Display + FromStr,
+    K: Hash + Eq + Display + FromStr,
+    V: Display + FromStr,
     <K as FromStr>::Err: Debug,
     <V as FromStr>::Err: Debug,
 {
     /// Crée un cache et tente de charger son contenu depuis un fichier.
     ///
-    /// Le fichier doit suivre le format `clé=valeur` (une entrée par ligne).
-    /// Si le fichier n'existe pas ou est corrompu, un cache vide est retourné (best-effort).
+    /// Le fichier doit suivre le format `clé=valeur` (une entrée par ligne),
+    /// précédé d'un en-tête depuis la version 2 du format : la capacité
+    /// enregistrée y remplace alors `capacity`.
+    /// Si le fichier n'existe pas, un cache vide est retourné ; les lignes
+    /// corrompues sont ignorées (best-effort, voir [`load_strict`](Self::load_strict)).
+    ///
+    /// Le cache retient `filepath` : voir [`save`](Self::save) et
+    /// [`with_save_on_drop`](Self::with_save_on_drop).
     pub fn new_persistent(capacity: usize, filepath: &str) -> io::Result<Self> {
-        let mut cache = LruCache::new(capacity);
+        let mut cache = match File::open(filepath) {
+            Ok(file) => Self::load_from_reader(capacity, BufReader::new(file))?,
+            Err(_) => LruCache::new(capacity),
+        };
+        cache.remember_path(filepath, None);
+        Ok(cache)
+    }
+
+    /// Associe `filepath` au cache, pour [`save`](Self::save).
+    /// Le contenu actuel est considéré comme déjà sauvegardé.
+    fn remember_path(&mut self, filepath: &str, auto_save: Option<AutoSave<K, V>>) {
+        self.save_target = Some(Box::new(SaveTarget {
+            path: filepath.to_owned(),
+            saved_changes: Arc::new(AtomicU64::new(self.changes)),
+            contents: |cache| {
+                let mut contents = Vec::new();
+                cache.write_file_contents(&mut contents)?;
+                Ok(contents)
+            },
+            auto_save,
+        }));
+    }
 
-        if let Ok(file) = File::open(filepath) {
-            let reader = BufReader::new(file);
-            for line in reader.lines() {
-                if let Ok(content) = line {
-                    if let Some((k_str, v_str)) = content.split_once('=') {
-                        let k = K::from_str(k_str).expect("Erreur parsing clé");
-                        let v = V::from_str(v_str).expect("Erreur parsing valeur");
-                        cache.put(k, v);
-                    }
-                }
+    /// Sauvegarde le cache dans le fichier retenu par
+    /// [`new_persistent`](Self::new_persistent), comme [`save_to_file`](Self::save_to_file).
+    ///
+    /// Retourne une erreur `ErrorKind::InvalidInput` si aucun fichier n'est associé.
+    pub fn save(&self) -> io::Result<()> {
+        match &self.save_target {
+            Some(target) => {
+                self.save_to_file(&target.path)?;
+                target.saved_changes.fetch_max(self.changes, Ordering::AcqRel);
+                Ok(())
             }
+            None => Err(io::Error::new(io::ErrorKind::InvalidInput, "aucun fichier associé au cache")),
         }
-        Ok(cache)
+    }
+
+    /// Active la sauvegarde automatique dans le fichier retenu lorsque le
+    /// cache est détruit, y compris lors d'un retour anticipé ou d'une panique.
+    /// Un cache non modifié depuis son chargement ou sa dernière sauvegarde
+    /// n'est pas réécrit.
+    ///
+    /// La sauvegarde est best-effort : une erreur est ignorée silencieusement.
+    /// Sans effet si aucun fichier n'est associé ; [`save`](Self::save) reste
+    /// disponible pour sauvegarder explicitement et traiter les erreurs.
+    pub fn with_save_on_drop(self) -> SaveOnDrop<K, V> {
+        SaveOnDrop { cache: self }
+    }
+
+    /// Crée un cache à partir d'un flux au format `clé=valeur` (fichier,
+    /// tampon mémoire, socket, flux décompressé...).
+    ///
+    /// Les clés et valeurs sont échappées par [`save_to_writer`](Self::save_to_writer) :
+    /// `\`, `=` et les retours à la ligne y sont permis.
+    ///
+    /// Les lignes illisibles sont ignorées ; une erreur du flux, ou une somme
+    /// de contrôle absente ou différente ([`CacheError::Corrupted`]), est remontée
+    /// plutôt que de charger des données altérées.
+    pub fn load_from_reader(capacity: usize, reader: impl BufRead) -> io::Result<Self> {
+        Self::load_lenient(capacity, reader, |_| {})
+    }
+
+    /// Comme [`load_from_reader`](Self::load_from_reader), en signalant
+    /// chaque ligne ignorée à `on_error` (avec son numéro et son contenu).
+    pub fn load_lenient(
+        capacity: usize,
+        reader: impl BufRead,
+        mut on_error: impl FnMut(CacheError),
+    ) -> io::Result<Self> {
+        let cache = Self::read_from(|saved| LruCache::new(saved.unwrap_or(capacity)), reader, |err| {
+            on_error(err);
+            Ok(())
+        });
+        Ok(cache?)
+    }
+
+    /// Charge un fichier en refusant toute ligne corrompue.
+    ///
+    /// Si le fichier n'existe pas, un cache vide est retourné. Sinon, la
+    /// première erreur est remontée avec son numéro de ligne, sans panique.
+    pub fn load_strict(capacity: usize, filepath: &str) -> Result<Self, CacheError> {
+        match File::open(filepath) {
+            Ok(file) => Self::load_from_reader_strict(capacity, BufReader::new(file)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(LruCache::new(capacity)),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Version stricte de [`load_from_reader`](Self::load_from_reader).
+    pub fn load_from_reader_strict(capacity: usize, reader: impl BufRead) -> Result<Self, CacheError> {
+        Self::read_from(|saved| LruCache::new(saved.unwrap_or(capacity)), reader, Err)
+    }
+
+    /// Lit un flux au format texte dans le cache créé par `make` à partir
+    /// de la capacité enregistrée (voir [`read_text`]).
+    fn read_from(
+        make: impl FnOnce(Option<usize>) -> Self,
+        reader: impl BufRead,
+        on_error: impl FnMut(CacheError) -> Result<(), CacheError>,
+    ) -> Result<Self, CacheError> {
+        let put = |cache: &mut Self, key, value| cache.put(key, value);
+        read_text(make, put, reader, (&DisplayCodec, &DisplayCodec), on_error)
     }
 
     /// Sauvegarde l'état actuel du cache dans un fichier.
@@ -39,15 +488,324 @@ where
     /// L'ordre d'écriture se fait du **Tail (Vieux) vers Head (Récent)**.
     /// Cela garantit que lors du rechargement, les éléments seront réinsérés
     /// dans le bon ordre pour conserver leur statut de récence.
+    ///
+    /// L'écriture est atomique (fichier temporaire voisin, `fsync`, puis
+    /// renommage) : un arrêt brutal laisse l'ancien fichier intact.
     pub fn save_to_file(&self, filepath: &str) -> io::Result<()> {
-        let mut file = File::create(filepath)?;
-        
-        let mut current_idx = self.tail;
-        while let Some(idx) = current_idx {
-            let node = &self.arena[idx];
-            writeln!(file, "{}={}", node.key, node.value)?;
-            current_idx = node.prev; 
+        write_atomically(filepath, |file| self.write_file_contents(file))
+    }
+
+    /// Comme [`save_to_file`](Self::save_to_file), mais réécrit le fichier
+    /// sur place, sans fichier temporaire ni `fsync` : plus rapide, mais un
+    /// arrêt en cours d'écriture laisse un fichier tronqué.
+    pub fn save_to_file_in_place(&self, filepath: &str) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(filepath)?);
+        self.write_file_contents(&mut file)?;
+        file.flush()
+    }
+
+    /// Écrit le contenu d'un fichier de sauvegarde : le format texte,
+    /// chiffré si un `Cipher` est configuré.
+    fn write_file_contents(&self, mut writer: impl Write) -> io::Result<()> {
+        #[cfg(feature = "encryption")]
+        if let Some(cipher) = &self.cipher {
+            let mut plain = Vec::new();
+            self.save_to_writer(&mut plain)?;
+            return writer.write_all(&cipher.encrypt(&plain)?);
         }
+        self.save_to_writer(&mut writer)
+    }
+
+    /// Écrit le cache au format `clé=valeur` dans un flux quelconque, dans
+    /// le même ordre que [`save_to_file`](Self::save_to_file).
+    ///
+    /// Le flux n'est pas vidé (`flush`) : c'est à l'appelant de le faire.
+    pub fn save_to_writer(&self, writer: impl Write) -> io::Result<()> {
+        #[cfg(feature = "tracing")]
+        let started = self.trace_start();
+
+        // Les entrées expirées sont ignorées par l'itérateur.
+        let count = self.iter().count();
+        write_text(writer, Some(self.capacity()), count, self.iter().rev(), (&DisplayCodec, &DisplayCodec))?;
+        #[cfg(feature = "tracing")]
+        self.trace(crate::trace::Operation::Save { entries: count }, None, started);
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+impl<K, V> LruCacheBuilder<K, V>
+where
+    K: Hash + Eq + Display + FromStr,
+    V: Display + FromStr,
+    <K as FromStr>::Err: Debug,
+    <V as FromStr>::Err: Debug,
+{
+    /// Construit le cache puis y charge `filepath`, comme
+    /// [`LruCache::new_persistent`] (fichier absent = cache vide, lignes
+    /// illisibles ignorées).
+    ///
+    /// La capacité configurée prime sur celle enregistrée dans le fichier,
+    /// qui est retenu comme par `new_persistent`.
+    /// Si un `Cipher` est configuré, le fichier est déchiffré avant lecture.
+    pub fn build_persistent(mut self, filepath: &str) -> io::Result<LruCache<K, V>> {
+        let auto_save = self.auto_save.take();
+        let mut cache = self.build();
+        // Les entrées sauvegardées ont déjà été admises.
+        let doorkeeper = cache.doorkeeper.take();
+        let bytes = match fs::read(filepath) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                cache.doorkeeper = doorkeeper;
+                cache.remember_path(filepath, auto_save);
+                return Ok(cache);
+            }
+            Err(err) => return Err(err),
+        };
+        #[cfg(feature = "encryption")]
+        let bytes = match &cache.cipher {
+            Some(cipher) => cipher.decrypt(&bytes)?,
+            None => bytes,
+        };
+        let mut cache = LruCache::read_from(|_| cache, bytes.as_slice(), |_| Ok(()))?;
+        cache.doorkeeper = doorkeeper;
+        cache.remember_path(filepath, auto_save);
+        Ok(cache)
+    }
+}
+
+impl<K, V> LruCache<K, V> {
+    /// Fichier retenu par [`new_persistent`](Self::new_persistent), s'il y en a un.
+    pub fn persistent_path(&self) -> Option<&str> {
+        self.save_target.as_ref().map(|target| target.path.as_str())
+    }
+}
+
+/// Cache sauvegardé dans son fichier à sa destruction (voir
+/// [`LruCache::with_save_on_drop`]) ; s'utilise comme le cache lui-même.
+///
+/// `LruCache` n'a pas de `Drop` : il peut ainsi contenir des références
+/// qui ne lui survivent pas. Seul ce guard en impose un.
+pub struct SaveOnDrop<K, V> {
+    cache: LruCache<K, V>,
+}
+
+impl<K, V> Deref for SaveOnDrop<K, V> {
+    type Target = LruCache<K, V>;
+
+    fn deref(&self) -> &LruCache<K, V> {
+        &self.cache
+    }
+}
+
+impl<K, V> DerefMut for SaveOnDrop<K, V> {
+    fn deref_mut(&mut self) -> &mut LruCache<K, V> {
+        &mut self.cache
+    }
+}
+
+impl<K, V> Drop for SaveOnDrop<K, V> {
+    fn drop(&mut self) {
+        let Some(target) = self.cache.save_target.as_ref() else {
+            return;
+        };
+        // Jamais de panique ici : elle interromprait le processus si le cache
+        // est détruit pendant une autre panique.
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            if let Some(snapshot) = target.dirty_snapshot(&self.cache) {
+                let _ = snapshot.write();
+            }
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writer_reader_round_trip() {
+        let mut cache = LruCache::new(3);
+        cache.put(1, "un".to_string());
+        cache.put(2, "deux".to_string());
+        cache.get(&1);
+        let mut buffer = Vec::new();
+        cache.save_to_writer(&mut buffer).unwrap();
+        assert!(buffer.starts_with(b"#lru-cache v3 capacity=3 count=2\n2=deux\n1=un\n#crc32 "));
+
+        let loaded: LruCache<u32, String> = LruCache::load_from_reader(3, buffer.as_slice()).unwrap();
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+    }
+
+    #[test]
+    fn test_escaping_round_trip() {
+        let mut cache = LruCache::new(3);
+        cache.put("a=b".to_string(), "x=y\nz\\".to_string());
+        cache.put("\\n".to_string(), "\r\n".to_string());
+        let mut buffer = Vec::new();
+        cache.save_to_writer(&mut buffer).unwrap();
+        assert_eq!(buffer.iter().filter(|&&b| b == b'\n').count(), 4);
+
+        let loaded: LruCache<String, String> = LruCache::load_from_reader(3, buffer.as_slice()).unwrap();
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+    }
+
+    #[test]
+    fn test_reads_unescaped_legacy_lines() {
+        let legacy = "url=http://x/?a=1\npath=C:\\dir\\file\n";
+        let loaded: LruCache<String, String> = LruCache::load_from_reader(3, legacy.as_bytes()).unwrap();
+        assert_eq!(loaded.peek(&"url".to_string()).unwrap(), "http://x/?a=1");
+        assert_eq!(loaded.peek(&"path".to_string()).unwrap(), "C:\\dir\\file");
+
+        // Sans en-tête, `\n` et `\=` ne sont pas des séquences d'échappement.
+        let legacy = "path=C:\\new\nkey\\=a=b\n";
+        let loaded: LruCache<String, String> = LruCache::load_from_reader(3, legacy.as_bytes()).unwrap();
+        assert_eq!(loaded.peek(&"path".to_string()).unwrap(), "C:\\new");
+        assert_eq!(loaded.peek(&"key\\".to_string()).unwrap(), "a=b");
+    }
+
+    #[test]
+    fn test_strict_and_lenient_loading() {
+        let data = "1=10\nsans_separateur\n\n2=abc\n3=30\n";
+        let err = LruCache::<u32, u32>::load_from_reader_strict(5, data.as_bytes()).err().unwrap();
+        assert!(matches!(err, CacheError::Parse { line: 2, .. }));
+        assert!(err.to_string().contains("sans_separateur"));
+
+        let mut skipped = Vec::new();
+        let cache: LruCache<u32, u32> = LruCache::load_lenient(5, data.as_bytes(), |err| {
+            if let CacheError::Parse { line, .. } = err {
+                skipped.push(line);
+            }
+        })
+        .unwrap();
+        assert_eq!(skipped, [2, 4]);
+        assert_eq!(cache.to_vec(), vec![(1, 10), (3, 30)]);
+
+        let invalid_utf8: &[u8] = b"1=1\n\xff=2\n";
+        assert_eq!(LruCache::<u32, u32>::load_from_reader(5, invalid_utf8).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_header_versions() {
+        let mut cache = LruCache::new(4);
+        cache.put(1, 10);
+        cache.put(2, 20);
+        let mut buffer = Vec::new();
+        cache.save_to_writer(&mut buffer).unwrap();
+        let loaded: LruCache<u32, u32> = LruCache::load_from_reader_strict(10, buffer.as_slice()).unwrap();
+        assert_eq!(loaded.capacity(), 4);
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+
+        // Fichier de version 2, sans somme de contrôle, tronqué.
+        let truncated = "#lru-cache v2 capacity=4 count=2\n1=10\n";
+        let err = LruCache::<u32, u32>::load_from_reader_strict(4, truncated.as_bytes()).err().unwrap();
+        assert!(matches!(err, CacheError::Truncated { expected: 2, found: 1 }));
+
+        let future = "#lru-cache v99 capacity=4\n1=10\n";
+        let err = LruCache::<u32, u32>::load_from_reader(4, future.as_bytes()).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_checksum_detects_corruption() {
+        let mut cache = LruCache::new(4);
+        cache.put("#clé".to_string(), 10);
+        cache.put("b".to_string(), 20);
+        let mut buffer = Vec::new();
+        cache.save_to_writer(&mut buffer).unwrap();
+        let loaded: LruCache<String, u32> = LruCache::load_from_reader_strict(4, buffer.as_slice()).unwrap();
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+
+        // Un octet modifié, puis une écriture partielle sans somme finale.
+        let pos = buffer.iter().position(|&b| b == b'2').unwrap();
+        buffer[pos] = b'3';
+        let err = LruCache::<String, u32>::load_from_reader(4, buffer.as_slice()).err().unwrap();
+        assert!(err.to_string().contains("somme de contrôle"));
+        let partial = &buffer[..buffer.len() - 16];
+        let err = LruCache::<String, u32>::load_from_reader_strict(4, partial).err().unwrap();
+        assert!(matches!(err, CacheError::Corrupted(_)));
+    }
+
+    #[test]
+    fn test_build_persistent_keeps_configuration() {
+        let path = std::env::temp_dir().join(format!("lru_builder_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut cache = LruCache::new(5);
+        cache.put(1, 10);
+        cache.put(2, 20);
+        cache.save_to_file(path).unwrap();
+
+        let loaded: LruCache<u32, u32> = LruCache::builder(2)
+            .ttl(std::time::Duration::from_secs(60))
+            .build_persistent(path)
+            .unwrap();
+        assert_eq!(loaded.capacity(), 2);
+        assert_eq!(loaded.to_vec(), vec![(1, 10), (2, 20)]);
+        assert_eq!(loaded.ttl, Some(std::time::Duration::from_secs(60)));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_save_on_drop() {
+        let path = std::env::temp_dir().join(format!("lru_drop_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        {
+            let mut cache: LruCache<u32, u32> = LruCache::new_persistent(3, path).unwrap();
+            cache.put(1, 10);
+            assert_eq!(cache.persistent_path(), Some(path));
+        }
+        assert!(!Path::new(path).exists());
+
+        let result = std::panic::catch_unwind(|| {
+            let mut cache = LruCache::<u32, u32>::new_persistent(3, path).unwrap().with_save_on_drop();
+            cache.put(2, 20);
+            panic!("arrêt brutal");
+        });
+        assert!(result.is_err());
+        let mut reloaded: LruCache<u32, u32> = LruCache::new_persistent(3, path).unwrap();
+        assert_eq!(reloaded.to_vec(), vec![(2, 20)]);
+        reloaded.put(3, 30);
+        reloaded.save().unwrap();
+        assert_eq!(LruCache::<u32, u32>::new(3).save().unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_borrowed_keys_may_die_before_cache() {
+        // Sans `Drop` sur `LruCache`, la clé empruntée peut mourir avant lui.
+        let mut cache = LruCache::new(2);
+        let key = String::from("x");
+        cache.put(key.as_str(), 1);
+        assert_eq!(cache.len(), 1);
+
+        // Idem avec un `Weigher` et un listener, rangés sans `K` ni `V`.
+        let mut cache = LruCache::builder(2)
+            .max_weight(8, |key: &&str, _: &i32| key.len() as u32)
+            .on_evict(|_, _, _| {})
+            .build();
+        let key = String::from("y");
+        cache.put(key.as_str(), 2);
+        assert_eq!(cache.total_weight(), 1);
+    }
+
+    #[test]
+    fn test_failed_save_keeps_previous_file() {
+        let path = std::env::temp_dir().join(format!("lru_atomic_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut cache = LruCache::new(2);
+        cache.put(1, 1);
+        cache.save_to_file(path).unwrap();
+
+        let failed = write_atomically(path, |file| {
+            writeln!(file, "2=2")?;
+            Err(io::Error::other("crash simulé"))
+        });
+        assert!(failed.is_err());
+        assert!(fs::read_to_string(path).unwrap().contains("count=1\n1=1\n#crc32 "));
+        let leftovers = fs::read_dir(std::env::temp_dir())
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().file_name().to_string_lossy().contains("lru_atomic_"))
+            .count();
+        assert_eq!(leftovers, 1);
+        fs::remove_file(path).unwrap();
+    }
+}
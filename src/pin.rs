@@ -0,0 +1,98 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::cache::{Cache, LruCache};
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Épingle une entrée : elle n'est plus jamais choisie comme victime
+    /// d'éviction (capacité ou poids), mais reste soumise à l'expiration
+    /// et à `remove`.
+    ///
+    /// Si toutes les entrées sont épinglées, une insertion fait dépasser
+    /// temporairement la capacité ; le cache revient sous sa limite au fil
+    /// des [`unpin`](Self::unpin).
+    ///
+    /// Retourne `false` si la clé est absente.
+    pub fn pin<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.set_pinned(key, true)
+    }
+
+    /// Désépingle une entrée, puis évince si le cache dépasse sa capacité.
+    ///
+    /// Retourne `false` si la clé est absente.
+    pub fn unpin<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if !self.set_pinned(key, false) {
+            return false;
+        }
+        while self.len() > self.capacity() && self.remove_lru() {}
+        self.evict_overweight();
+        true
+    }
+
+    /// Indique si la clé est présente et épinglée.
+    pub fn is_pinned<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some_and(|index| self.arena[index].pinned)
+    }
+
+    fn set_pinned<Q>(&mut self, key: &Q, pinned: bool) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(index) = self.find(key) else {
+            return false;
+        };
+        self.arena[index].pinned = pinned;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_entries_are_skipped() {
+        let mut cache = LruCache::new(2);
+        cache.put("config", 1);
+        cache.put("a", 2);
+        assert!(cache.pin("config"));
+        cache.put("b", 3);
+        assert!(cache.contains(&"config"));
+        assert!(!cache.contains(&"a"));
+        assert!(!cache.pin("absent"));
+    }
+
+    #[test]
+    fn test_grows_when_everything_is_pinned() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.pin("a");
+        cache.pin("b");
+        cache.put("c", 3);
+        assert_eq!(cache.len(), 3);
+
+        // Le désépinglage ramène le cache sous sa capacité.
+        assert!(cache.unpin("a"));
+        assert!(!cache.is_pinned("a"));
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains(&"a"));
+        assert!(cache.contains(&"b"));
+    }
+}
@@ -0,0 +1,193 @@
+use super::ghost::GhostList;
+use super::{EvictionPolicy, PolicyCache, SlotList};
+
+/// Un cache ARC (Adaptive Replacement Cache).
+pub type ArcCache<K, V> = PolicyCache<K, V, ArcPolicy>;
+
+/// Politique ARC (Megiddo & Modha) : s'auto-ajuste entre récence et fréquence.
+///
+/// # Architecture
+/// * **T1** : Entrées vues une seule fois récemment (récence).
+/// * **T2** : Entrées vues au moins deux fois (fréquence).
+/// * **B1 / B2** : Listes fantômes des clés évincées de T1 / T2.
+/// * **p** : Taille cible de T1, ajustée à chaque retour d'une clé fantôme.
+///
+/// Un retour depuis B1 signale que T1 est trop petit (p augmente) ; un retour
+/// depuis B2 signale l'inverse. Un scan ne traverse donc que T1 sans chasser
+/// les entrées fréquentes de T2.
+pub struct ArcPolicy {
+    capacity: usize,
+    p: usize,
+    t1: SlotList,
+    t2: SlotList,
+    b1: GhostList,
+    b2: GhostList,
+    /// Empreinte de la clé de chaque emplacement (pour l'alimentation des fantômes).
+    hashes: Vec<u64>,
+    /// La clé en cours d'insertion revient de B2 (départage de REPLACE).
+    pending_from_b2: bool,
+    /// La clé en cours d'insertion revient d'une liste fantôme : elle va dans T2.
+    pending_ghost_hit: bool,
+}
+
+impl ArcPolicy {
+    /// Taille cible actuelle de T1.
+    pub fn target_recent_size(&self) -> usize {
+        self.p
+    }
+
+    /// Garantit |T1| + |B1| <= c et |T1| + |T2| + |B1| + |B2| <= 2c.
+    fn trim_ghosts(&mut self) {
+        while self.t1.len() + self.b1.len() > self.capacity && self.b1.pop_oldest().is_some() {}
+        while self.t1.len() + self.t2.len() + self.b1.len() + self.b2.len() > 2 * self.capacity
+            && self.b2.pop_oldest().is_some()
+        {}
+    }
+}
+
+impl EvictionPolicy for ArcPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        ArcPolicy {
+            capacity,
+            p: 0,
+            t1: SlotList::new(),
+            t2: SlotList::new(),
+            b1: GhostList::default(),
+            b2: GhostList::default(),
+            hashes: Vec::with_capacity(capacity),
+            pending_from_b2: false,
+            pending_ghost_hit: false,
+        }
+    }
+
+    fn on_miss(&mut self, hash: u64) {
+        if self.b1.contains(hash) {
+            let delta = (self.b2.len() / self.b1.len()).max(1);
+            self.p = (self.p + delta).min(self.capacity);
+            self.b1.remove(hash);
+            self.pending_ghost_hit = true;
+        } else if self.b2.contains(hash) {
+            let delta = (self.b1.len() / self.b2.len()).max(1);
+            self.p = self.p.saturating_sub(delta);
+            self.b2.remove(hash);
+            self.pending_from_b2 = true;
+            self.pending_ghost_hit = true;
+        } else if self.t1.len() + self.b1.len() >= self.capacity {
+            self.b1.pop_oldest();
+        } else if self.t1.len() + self.t2.len() + self.b1.len() + self.b2.len()
+            >= 2 * self.capacity
+        {
+            self.b2.pop_oldest();
+        }
+    }
+
+    fn on_insert(&mut self, slot: usize, hash: u64) {
+        if slot >= self.hashes.len() {
+            self.hashes.resize(slot + 1, 0);
+        }
+        self.hashes[slot] = hash;
+        if self.pending_ghost_hit {
+            self.t2.push_front(slot);
+        } else {
+            self.t1.push_front(slot);
+        }
+        self.pending_ghost_hit = false;
+        self.pending_from_b2 = false;
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.t1.remove(slot);
+        self.t2.move_to_front(slot);
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.t1.remove(slot) {
+            self.t2.remove(slot);
+        }
+    }
+
+    fn on_evict(&mut self, slot: usize) {
+        let hash = self.hashes[slot];
+        if self.t1.remove(slot) {
+            self.b1.push(hash);
+        } else if self.t2.remove(slot) {
+            self.b2.push(hash);
+        }
+        self.trim_ghosts();
+    }
+
+    /// Procédure REPLACE d'ARC.
+    fn select_victim(&mut self) -> Option<usize> {
+        let t1_len = self.t1.len();
+        let prefer_t1 =
+            t1_len > 0 && (t1_len > self.p || (self.pending_from_b2 && t1_len == self.p));
+        if prefer_t1 || self.t2.is_empty() {
+            self.t1.back()
+        } else {
+            self.t2.back()
+        }
+    }
+
+    fn clear(&mut self) {
+        self.p = 0;
+        self.t1.clear();
+        self.t2.clear();
+        self.b1.clear();
+        self.b2.clear();
+        self.hashes.clear();
+        self.pending_from_b2 = false;
+        self.pending_ghost_hit = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cache, LruCache};
+
+    fn hits(cache: &mut dyn Cache<u32, u32>, trace: &[u32]) -> usize {
+        let mut hits = 0;
+        for &key in trace {
+            if cache.get(&key).is_some() {
+                hits += 1;
+            } else {
+                cache.put(key, key);
+            }
+        }
+        hits
+    }
+
+    #[test]
+    fn test_frequent_entries_survive_scan() {
+        let mut cache = ArcCache::new(4);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        cache.get(&1);
+        cache.get(&2);
+        for key in 100..110 {
+            cache.put(key, key);
+        }
+        assert!(cache.contains(&1));
+        assert!(cache.contains(&2));
+    }
+
+    #[test]
+    fn test_beats_lru_on_mixed_workload() {
+        // Clés chaudes (lues deux fois) entrecoupées de scans plus grands que le cache.
+        let mut trace = Vec::new();
+        let mut next_scan = 1000;
+        for _ in 0..50 {
+            for hot in 0..4 {
+                trace.push(hot);
+                trace.push(hot);
+            }
+            for _ in 0..12 {
+                trace.push(next_scan);
+                next_scan += 1;
+            }
+        }
+        let arc = hits(&mut ArcCache::new(10), &trace);
+        let lru = hits(&mut LruCache::new(10), &trace);
+        assert!(arc > lru, "ARC {arc} <= LRU {lru}");
+    }
+}
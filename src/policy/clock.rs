@@ -0,0 +1,92 @@
+use super::{EvictionPolicy, PolicyCache};
+
+/// Un cache CLOCK (seconde chance).
+pub type ClockCache<K, V> = PolicyCache<K, V, ClockPolicy>;
+
+/// Politique CLOCK : approximation de LRU par bits de référence.
+///
+/// # Architecture
+/// Les emplacements du [`PolicyCache`] forment directement le tampon circulaire :
+/// * **Bits de référence** : positionnés par `on_access` (une simple écriture,
+///   sans réordonner de liste chaînée).
+/// * **Aiguille** : parcourt le tampon ; une entrée référencée perd son bit
+///   (seconde chance), la première entrée non référencée est évincée.
+#[derive(Default)]
+pub struct ClockPolicy {
+    referenced: Vec<bool>,
+    occupied: Vec<bool>,
+    hand: usize,
+}
+
+impl EvictionPolicy for ClockPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        ClockPolicy {
+            referenced: Vec::with_capacity(capacity),
+            occupied: Vec::with_capacity(capacity),
+            hand: 0,
+        }
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.occupied.len() {
+            self.occupied.resize(slot + 1, false);
+            self.referenced.resize(slot + 1, false);
+        }
+        self.occupied[slot] = true;
+        self.referenced[slot] = false;
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.referenced[slot] = true;
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        self.occupied[slot] = false;
+        self.referenced[slot] = false;
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        let len = self.occupied.len();
+        // Deux tours suffisent : le premier efface tous les bits de référence.
+        for _ in 0..2 * len {
+            let slot = self.hand;
+            self.hand = (self.hand + 1) % len;
+            if !self.occupied[slot] {
+                continue;
+            }
+            if self.referenced[slot] {
+                self.referenced[slot] = false;
+            } else {
+                return Some(slot);
+            }
+        }
+        None
+    }
+
+    fn clear(&mut self) {
+        self.referenced.clear();
+        self.occupied.clear();
+        self.hand = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_second_chance() {
+        let mut cache = ClockCache::new(3);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.put("C", 3);
+        cache.get(&"A");
+        cache.put("D", 4);
+        assert!(cache.contains(&"A"));
+        assert!(!cache.contains(&"B"));
+        cache.put("E", 5);
+        assert!(!cache.contains(&"C"));
+        assert_eq!(cache.len(), 3);
+    }
+}
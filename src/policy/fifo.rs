@@ -0,0 +1,56 @@
+use super::{EvictionPolicy, PolicyCache, SlotList};
+
+/// Un cache FIFO (First In, First Out).
+pub type FifoCache<K, V> = PolicyCache<K, V, FifoPolicy>;
+
+/// Politique FIFO : évince strictement dans l'ordre d'insertion.
+///
+/// Les lectures et mises à jour ne modifient pas l'ordre : `on_access` est
+/// un no-op, ce qui rend les `get` très peu coûteux (utile pour les scans).
+#[derive(Default)]
+pub struct FifoPolicy {
+    order: SlotList,
+}
+
+impl EvictionPolicy for FifoPolicy {
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::default()
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        self.order.push_front(slot);
+    }
+
+    fn on_access(&mut self, _slot: usize) {}
+
+    fn on_remove(&mut self, slot: usize) {
+        self.order.remove(slot);
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.order.back()
+    }
+
+    fn clear(&mut self) {
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_get_does_not_promote() {
+        let mut cache = FifoCache::new(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        assert_eq!(cache.get(&"A"), Some(&1));
+        cache.put("A", 10);
+        cache.put("C", 3);
+        assert_eq!(cache.get(&"A"), None);
+        assert_eq!(cache.get(&"B"), Some(&2));
+        assert_eq!(cache.get(&"C"), Some(&3));
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::BTreeSet;
+use std::hash::Hash;
+
+use super::{EvictionPolicy, PolicyCache};
+use crate::cache::Cache;
+
+/// Un cache GreedyDual-Size-Frequency.
+pub type GdsfCache<K, V> = PolicyCache<K, V, GdsfPolicy>;
+
+/// Coût et taille d'une entrée présente.
+#[derive(Clone, Copy)]
+struct SlotMeta {
+    freq: u64,
+    cost: u64,
+    size: u64,
+    /// Clé de l'entrée dans la file de priorité.
+    rank: (u64, u64),
+}
+
+/// Politique GDSF : évince l'entrée de plus faible `L + fréquence × coût / taille`.
+///
+/// # Architecture
+/// * **Métadonnées** : Fréquence, coût de recalcul et taille de chaque emplacement.
+/// * **File de priorité** : Un `BTreeSet<(priorité, séquence, slot)>` dont le
+///   premier élément est la victime (la séquence départage les égalités, le
+///   plus ancien d'abord). Les priorités étant positives, leur représentation
+///   binaire `f64::to_bits` respecte l'ordre numérique.
+/// * **Inflation `L`** : Priorité de la dernière victime, ajoutée aux entrées
+///   insérées ou lues ensuite ; elle fait vieillir les entrées qui ne sont
+///   plus consultées.
+///
+/// Une entrée écrite par `put` a un coût et une taille de 1 : sans
+/// [`put_with_cost`](PolicyCache::put_with_cost), la politique se comporte
+/// comme un LFU avec vieillissement.
+#[derive(Default)]
+pub struct GdsfPolicy {
+    inflation: f64,
+    seq: u64,
+    meta: Vec<Option<SlotMeta>>,
+    queue: BTreeSet<(u64, u64, usize)>,
+    /// Coût et taille fournis pour l'écriture en cours.
+    pending: Option<(u64, u64)>,
+}
+
+impl GdsfPolicy {
+    /// Valeur actuelle de l'inflation `L`.
+    pub fn inflation(&self) -> f64 {
+        self.inflation
+    }
+
+    /// Priorité actuelle d'un emplacement (`None` s'il est absent).
+    pub fn priority(&self, slot: usize) -> Option<f64> {
+        let meta = self.meta.get(slot).copied().flatten()?;
+        Some(f64::from_bits(meta.rank.0))
+    }
+
+    /// Recalcule la priorité de `slot` et le (ré)insère dans la file.
+    fn enqueue(&mut self, slot: usize, mut meta: SlotMeta) {
+        let priority = self.inflation + meta.freq as f64 * meta.cost as f64 / meta.size as f64;
+        self.seq += 1;
+        meta.rank = (priority.to_bits(), self.seq);
+        self.queue.insert((meta.rank.0, meta.rank.1, slot));
+        self.meta[slot] = Some(meta);
+    }
+
+    fn take(&mut self, slot: usize) -> Option<SlotMeta> {
+        let meta = self.meta.get_mut(slot)?.take()?;
+        self.queue.remove(&(meta.rank.0, meta.rank.1, slot));
+        Some(meta)
+    }
+}
+
+impl EvictionPolicy for GdsfPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        GdsfPolicy {
+            meta: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.meta.len() {
+            self.meta.resize(slot + 1, None);
+        }
+        let (cost, size) = self.pending.take().unwrap_or((1, 1));
+        let meta = SlotMeta {
+            freq: 1,
+            cost,
+            size,
+            rank: (0, 0),
+        };
+        self.enqueue(slot, meta);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        let Some(mut meta) = self.take(slot) else {
+            return;
+        };
+        meta.freq += 1;
+        if let Some((cost, size)) = self.pending.take() {
+            meta.cost = cost;
+            meta.size = size;
+        }
+        self.enqueue(slot, meta);
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        self.take(slot);
+    }
+
+    fn on_evict(&mut self, slot: usize) {
+        if let Some(meta) = self.take(slot) {
+            self.inflation = f64::from_bits(meta.rank.0);
+        }
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.queue.first().map(|&(_, _, slot)| slot)
+    }
+
+    fn clear(&mut self) {
+        self.inflation = 0.0;
+        self.meta.clear();
+        self.queue.clear();
+        self.pending = None;
+    }
+}
+
+impl<K, V> PolicyCache<K, V, GdsfPolicy>
+where
+    K: Hash + Eq,
+{
+    /// Insère ou met à jour une valeur avec son coût de recalcul et sa taille.
+    ///
+    /// Les unités sont libres (ex: millisecondes de rendu et octets) mais
+    /// doivent être cohérentes entre les entrées. Une taille nulle compte pour 1.
+    pub fn put_with_cost(&mut self, key: K, value: V, cost: u64, size: u64) {
+        self.policy.pending = Some((cost, size.max(1)));
+        self.put(key, value);
+        self.policy.pending = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cheap_entries_are_evicted_first() {
+        let mut cache = GdsfCache::new(2);
+        cache.put_with_cost("render", 1, 500, 100);
+        cache.put_with_cost("thumb", 2, 5, 10);
+        cache.put_with_cost("other", 3, 400, 100);
+        assert!(!cache.contains(&"thumb"));
+        assert!(cache.contains(&"render"));
+        // L'inflation vaut la priorité de la victime : 5 / 10.
+        assert_eq!(cache.policy().inflation(), 0.5);
+    }
+
+    #[test]
+    fn test_aging_evicts_stale_frequent_entries() {
+        let mut cache = GdsfCache::new(2);
+        cache.put("a", 0);
+        for _ in 0..3 {
+            cache.get(&"a");
+        }
+        cache.put("b", 0);
+        // Chaque victime relève l'inflation : les entrées récentes finissent
+        // par dépasser "a", fréquente mais plus consultée.
+        for key in ["c", "d", "e"] {
+            cache.put(key, 0);
+            cache.get(&key);
+        }
+        assert!(!cache.contains(&"a"));
+        assert!(cache.contains(&"e"));
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Liste "fantôme" : mémorise les empreintes (`hash`) de clés récemment
+/// évincées, sans leurs valeurs, dans l'ordre d'arrivée.
+///
+/// Utilisée par les politiques adaptatives (ARC, 2Q...) pour reconnaître
+/// une clé qui revient peu après son éviction.
+///
+/// # Architecture
+/// * **Map** : `hash -> génération` pour les tests d'appartenance en O(1).
+/// * **File** : `(hash, génération)` dans l'ordre d'arrivée ; les entrées
+///   retirées par `remove` y restent jusqu'à leur passage en tête
+///   (suppression paresseuse), la génération permettant de les ignorer.
+#[derive(Default)]
+pub(crate) struct GhostList {
+    members: HashMap<u64, u64>,
+    queue: VecDeque<(u64, u64)>,
+    generation: u64,
+}
+
+impl GhostList {
+    pub(crate) fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub(crate) fn contains(&self, hash: u64) -> bool {
+        self.members.contains_key(&hash)
+    }
+
+    /// Ajoute `hash` comme entrée la plus récente (le déplace s'il existait).
+    pub(crate) fn push(&mut self, hash: u64) {
+        self.generation += 1;
+        self.members.insert(hash, self.generation);
+        self.queue.push_back((hash, self.generation));
+        self.compact();
+    }
+
+    /// Retire `hash`. Retourne `true` s'il était présent.
+    pub(crate) fn remove(&mut self, hash: u64) -> bool {
+        self.members.remove(&hash).is_some()
+    }
+
+    /// Retire et retourne l'entrée la plus ancienne.
+    pub(crate) fn pop_oldest(&mut self) -> Option<u64> {
+        while let Some((hash, generation)) = self.queue.pop_front() {
+            if self.members.get(&hash) == Some(&generation) {
+                self.members.remove(&hash);
+                return Some(hash);
+            }
+        }
+        None
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.members.clear();
+        self.queue.clear();
+    }
+
+    /// Purge les entrées périmées quand elles deviennent majoritaires.
+    fn compact(&mut self) {
+        if self.queue.len() > 2 * self.members.len() + 16 {
+            let members = &self.members;
+            self.queue.retain(|(hash, g)| members.get(hash) == Some(g));
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use super::{EvictionPolicy, PolicyCache};
+
+/// Un cache LFU (Least Frequently Used).
+pub type LfuCache<K, V> = PolicyCache<K, V, LfuPolicy>;
+
+/// Un "bucket" regroupe les emplacements ayant la même fréquence d'accès.
+#[derive(Clone, Copy)]
+struct Bucket {
+    freq: u64,
+    /// Emplacement le plus récemment arrivé dans ce bucket.
+    head: Option<usize>,
+    /// Emplacement le plus ancien du bucket (victime en cas d'égalité).
+    tail: Option<usize>,
+    /// Bucket de fréquence inférieure.
+    prev: Option<usize>,
+    /// Bucket de fréquence supérieure.
+    next: Option<usize>,
+}
+
+/// Liens d'un emplacement dans son bucket.
+#[derive(Clone, Copy, Default)]
+struct SlotLinks {
+    bucket: Option<usize>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// Politique LFU : évince l'entrée la moins fréquemment utilisée.
+///
+/// # Architecture
+/// Structure "O(1) LFU" classique :
+/// * **Buckets** : Liste chaînée de buckets triés par fréquence croissante,
+///   stockés dans une arena (avec free-list).
+/// * **Slots** : Chaque emplacement est chaîné dans le bucket de sa fréquence.
+///
+/// Un accès déplace l'emplacement vers le bucket `freq + 1` (créé à la volée
+/// juste après le bucket courant) ; la victime est la queue du premier bucket.
+/// À fréquence égale, l'entrée la plus ancienne est évincée.
+#[derive(Default)]
+pub struct LfuPolicy {
+    buckets: Vec<Bucket>,
+    free_buckets: Vec<usize>,
+    /// Bucket de plus basse fréquence.
+    first: Option<usize>,
+    slots: Vec<SlotLinks>,
+}
+
+impl LfuPolicy {
+    /// Fréquence d'accès actuelle d'un emplacement (0 s'il est absent).
+    pub fn frequency(&self, slot: usize) -> u64 {
+        self.slots
+            .get(slot)
+            .and_then(|l| l.bucket)
+            .map_or(0, |b| self.buckets[b].freq)
+    }
+
+    /// Crée un bucket vide pour `freq`, inséré entre `prev` et `next`.
+    fn new_bucket(&mut self, freq: u64, prev: Option<usize>, next: Option<usize>) -> usize {
+        let bucket = Bucket {
+            freq,
+            head: None,
+            tail: None,
+            prev,
+            next,
+        };
+        let idx = match self.free_buckets.pop() {
+            Some(idx) => {
+                self.buckets[idx] = bucket;
+                idx
+            }
+            None => {
+                self.buckets.push(bucket);
+                self.buckets.len() - 1
+            }
+        };
+        match prev {
+            Some(p) => self.buckets[p].next = Some(idx),
+            None => self.first = Some(idx),
+        }
+        if let Some(n) = next {
+            self.buckets[n].prev = Some(idx);
+        }
+        idx
+    }
+
+    /// Chaîne `slot` en tête du bucket `bucket`.
+    fn attach(&mut self, slot: usize, bucket: usize) {
+        let old_head = self.buckets[bucket].head;
+        self.slots[slot] = SlotLinks {
+            bucket: Some(bucket),
+            prev: None,
+            next: old_head,
+        };
+        match old_head {
+            Some(h) => self.slots[h].prev = Some(slot),
+            None => self.buckets[bucket].tail = Some(slot),
+        }
+        self.buckets[bucket].head = Some(slot);
+    }
+
+    /// Retire `slot` de son bucket, et supprime le bucket s'il devient vide.
+    /// Retourne les voisins (prev, next) du bucket d'origine, utiles pour
+    /// réinsérer l'emplacement dans un bucket adjacent.
+    fn detach(&mut self, slot: usize) -> Option<(Option<usize>, Option<usize>)> {
+        let links = std::mem::take(&mut self.slots[slot]);
+        let bucket = links.bucket?;
+        match links.prev {
+            Some(p) => self.slots[p].next = links.next,
+            None => self.buckets[bucket].head = links.next,
+        }
+        match links.next {
+            Some(n) => self.slots[n].prev = links.prev,
+            None => self.buckets[bucket].tail = links.prev,
+        }
+
+        let Bucket { prev, next, .. } = self.buckets[bucket];
+        if self.buckets[bucket].head.is_none() {
+            match prev {
+                Some(p) => self.buckets[p].next = next,
+                None => self.first = next,
+            }
+            if let Some(n) = next {
+                self.buckets[n].prev = prev;
+            }
+            self.free_buckets.push(bucket);
+            // Le bucket disparaît : le prédécesseur devient le point d'ancrage.
+            return Some((prev, next));
+        }
+        Some((Some(bucket), next))
+    }
+}
+
+impl EvictionPolicy for LfuPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        LfuPolicy {
+            slots: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.slots.len() {
+            self.slots.resize(slot + 1, SlotLinks::default());
+        }
+        let bucket = match self.first {
+            Some(b) if self.buckets[b].freq == 1 => b,
+            first => self.new_bucket(1, None, first),
+        };
+        self.attach(slot, bucket);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        let freq = self.frequency(slot);
+        let Some((anchor, next)) = self.detach(slot) else {
+            return;
+        };
+        let target = match next {
+            Some(n) if self.buckets[n].freq == freq + 1 => n,
+            _ => self.new_bucket(freq + 1, anchor, next),
+        };
+        self.attach(slot, target);
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if slot < self.slots.len() {
+            self.detach(slot);
+        }
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.buckets[self.first?].tail
+    }
+
+    fn clear(&mut self) {
+        self.buckets.clear();
+        self.free_buckets.clear();
+        self.first = None;
+        self.slots.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_evicts_least_frequent() {
+        let mut cache = LfuCache::new(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.get(&"A");
+        cache.get(&"A");
+        cache.get(&"B");
+        cache.put("C", 3);
+        assert_eq!(cache.peek(&"B"), None);
+        assert_eq!(cache.peek(&"A"), Some(&1));
+        assert_eq!(cache.peek(&"C"), Some(&3));
+    }
+
+    #[test]
+    fn test_ties_evict_oldest() {
+        let mut cache = LfuCache::new(3);
+        cache.put(1, ());
+        cache.put(2, ());
+        cache.put(3, ());
+        cache.put(4, ());
+        assert!(!cache.contains(&1));
+        cache.get(&4);
+        cache.put(5, ());
+        assert!(!cache.contains(&2));
+        assert!(cache.contains(&4));
+    }
+
+    #[test]
+    fn test_frequency_tracking_after_removal() {
+        let mut cache = LfuCache::new(3);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        for _ in 0..3 {
+            cache.get(&"A");
+        }
+        cache.remove(&"B");
+        cache.put("C", 3);
+        cache.put("D", 4);
+        cache.put("E", 5);
+        assert!(cache.contains(&"A"));
+        assert_eq!(cache.len(), 3);
+    }
+}
@@ -0,0 +1,145 @@
+/// Liens d'un emplacement dans une [`SlotList`].
+#[derive(Clone, Copy, Default)]
+struct Link {
+    prev: Option<usize>,
+    next: Option<usize>,
+    linked: bool,
+}
+
+/// Liste doublement chaînée d'emplacements, indexée par numéro de slot.
+///
+/// C'est la brique commune des politiques basées sur un ordre (LRU, FIFO, MRU...) :
+/// les liens sont stockés dans un `Vec` parallèle à l'arena du cache, comme
+/// pour le `LruCache`, ce qui évite toute allocation par nœud.
+///
+/// Convention : le **front** est le côté "récent", le **back** le côté "ancien".
+#[derive(Default)]
+pub struct SlotList {
+    links: Vec<Link>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl SlotList {
+    /// Crée une liste vide.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nombre d'emplacements chaînés.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Indique si la liste est vide.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Indique si `slot` fait partie de la liste.
+    pub fn contains(&self, slot: usize) -> bool {
+        self.links.get(slot).is_some_and(|l| l.linked)
+    }
+
+    /// Emplacement en tête (le plus récent).
+    pub fn front(&self) -> Option<usize> {
+        self.head
+    }
+
+    /// Emplacement en queue (le plus ancien).
+    pub fn back(&self) -> Option<usize> {
+        self.tail
+    }
+
+    /// Emplacement suivant `slot` (vers la queue).
+    pub fn next_of(&self, slot: usize) -> Option<usize> {
+        self.links[slot].next
+    }
+
+    /// Emplacement précédant `slot` (vers la tête).
+    pub fn prev_of(&self, slot: usize) -> Option<usize> {
+        self.links[slot].prev
+    }
+
+    /// Ajoute `slot` en tête. `slot` ne doit pas déjà être chaîné.
+    pub fn push_front(&mut self, slot: usize) {
+        self.ensure(slot);
+        self.links[slot] = Link {
+            prev: None,
+            next: self.head,
+            linked: true,
+        };
+        match self.head {
+            Some(h) => self.links[h].prev = Some(slot),
+            None => self.tail = Some(slot),
+        }
+        self.head = Some(slot);
+        self.len += 1;
+    }
+
+    /// Ajoute `slot` en queue. `slot` ne doit pas déjà être chaîné.
+    pub fn push_back(&mut self, slot: usize) {
+        self.ensure(slot);
+        self.links[slot] = Link {
+            prev: self.tail,
+            next: None,
+            linked: true,
+        };
+        match self.tail {
+            Some(t) => self.links[t].next = Some(slot),
+            None => self.head = Some(slot),
+        }
+        self.tail = Some(slot);
+        self.len += 1;
+    }
+
+    /// Retire `slot` de la liste. Retourne `false` s'il n'y figurait pas.
+    pub fn remove(&mut self, slot: usize) -> bool {
+        if !self.contains(slot) {
+            return false;
+        }
+        let Link { prev, next, .. } = self.links[slot];
+        match prev {
+            Some(p) => self.links[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.links[n].prev = prev,
+            None => self.tail = prev,
+        }
+        self.links[slot] = Link::default();
+        self.len -= 1;
+        true
+    }
+
+    /// Retire et retourne l'emplacement en queue.
+    pub fn pop_back(&mut self) -> Option<usize> {
+        let slot = self.tail?;
+        self.remove(slot);
+        Some(slot)
+    }
+
+    /// Déplace `slot` en tête (l'ajoute s'il n'était pas chaîné).
+    pub fn move_to_front(&mut self, slot: usize) {
+        if self.head == Some(slot) {
+            return;
+        }
+        self.remove(slot);
+        self.push_front(slot);
+    }
+
+    /// Vide la liste.
+    pub fn clear(&mut self) {
+        self.links.clear();
+        self.head = None;
+        self.tail = None;
+        self.len = 0;
+    }
+
+    fn ensure(&mut self, slot: usize) {
+        if slot >= self.links.len() {
+            self.links.resize(slot + 1, Link::default());
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::{EvictionPolicy, SlotList};
+
+/// Politique LRU : évince l'entrée dont le dernier accès est le plus ancien.
+///
+/// Équivalente au [`LruCache`](crate::LruCache), qui reste l'implémentation
+/// dédiée (et persistable) ; cette version sert de référence pour le cœur générique.
+#[derive(Default)]
+pub struct LruPolicy {
+    order: SlotList,
+}
+
+impl EvictionPolicy for LruPolicy {
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::default()
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        self.order.push_front(slot);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.order.move_to_front(slot);
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        self.order.remove(slot);
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.order.back()
+    }
+
+    fn clear(&mut self) {
+        self.order.clear();
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use super::{EvictionPolicy, PolicyCache};
+
+/// Un cache LRU-K.
+pub type LrukCache<K, V> = PolicyCache<K, V, LrukPolicy>;
+
+/// Politique LRU-K : évince selon le K-ième accès le plus récent.
+///
+/// # Architecture
+/// * **Historique** : Pour chaque emplacement, les dates (logiques) de ses
+///   `k` derniers accès.
+/// * **File de priorité** : Un `BTreeSet<(rang, date, slot)>` dont le premier
+///   élément est la victime :
+///     * rang 0 : entrées vues moins de `k` fois (distance infinie),
+///       départagées par leur dernier accès (LRU) ;
+///     * rang 1 : les autres, ordonnées par leur K-ième accès le plus récent.
+///
+/// Une rafale d'accès corrélés ne suffit donc pas à protéger une entrée :
+/// seul un historique de `k` références espacées compte.
+pub struct LrukPolicy {
+    k: usize,
+    clock: u64,
+    history: Vec<VecDeque<u64>>,
+    queue: BTreeSet<(u8, u64, usize)>,
+}
+
+impl LrukPolicy {
+    /// Valeur de `k` utilisée par défaut (LRU-2).
+    pub const DEFAULT_K: usize = 2;
+
+    /// Crée la politique avec un `k` explicite.
+    ///
+    /// # Panics
+    /// Panique si `k` est 0.
+    pub fn with_k(k: usize) -> Self {
+        assert!(k > 0, "k doit être > 0");
+        LrukPolicy {
+            k,
+            clock: 0,
+            history: Vec::new(),
+            queue: BTreeSet::new(),
+        }
+    }
+
+    /// Valeur de `k` de la politique.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Clé de priorité d'un emplacement, d'après son historique.
+    fn priority(&self, slot: usize) -> (u8, u64, usize) {
+        let history = &self.history[slot];
+        if history.len() < self.k {
+            (0, history.back().copied().unwrap_or(0), slot)
+        } else {
+            (1, history[0], slot)
+        }
+    }
+
+    /// Enregistre un accès et repositionne l'emplacement dans la file.
+    fn record(&mut self, slot: usize) {
+        if !self.history[slot].is_empty() {
+            self.queue.remove(&self.priority(slot));
+        }
+        self.clock += 1;
+        let history = &mut self.history[slot];
+        if history.len() == self.k {
+            history.pop_front();
+        }
+        history.push_back(self.clock);
+        self.queue.insert(self.priority(slot));
+    }
+}
+
+impl EvictionPolicy for LrukPolicy {
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::with_k(Self::DEFAULT_K)
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.history.len() {
+            self.history.resize_with(slot + 1, VecDeque::new);
+        }
+        self.history[slot].clear();
+        self.record(slot);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.record(slot);
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.history[slot].is_empty() {
+            self.queue.remove(&self.priority(slot));
+            self.history[slot].clear();
+        }
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.queue.first().map(|&(_, _, slot)| slot)
+    }
+
+    fn clear(&mut self) {
+        self.clock = 0;
+        self.history.clear();
+        self.queue.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_single_reference_evicted_first() {
+        let mut cache = LrukCache::new(2);
+        cache.put("A", 1);
+        cache.get(&"A");
+        cache.put("B", 2);
+        // B n'a qu'une référence : il part avant A, pourtant plus ancien.
+        cache.put("C", 3);
+        assert!(cache.contains(&"A"));
+        assert!(!cache.contains(&"B"));
+    }
+
+    #[test]
+    fn test_kth_reference_orders_victims() {
+        let mut cache: LrukCache<&str, i32> = PolicyCache::with_policy(2, LrukPolicy::with_k(2));
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.get(&"A");
+        cache.get(&"B");
+        // Rafale sur A : son avant-dernier accès devient plus récent que celui de B.
+        cache.get(&"A");
+        cache.get(&"A");
+        cache.put("C", 3);
+        assert!(cache.contains(&"A"));
+        assert!(!cache.contains(&"B"));
+    }
+}
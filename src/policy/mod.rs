@@ -0,0 +1,298 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::cache::Cache;
+use crate::index::KeyIndex;
+
+mod arc;
+mod clock;
+mod fifo;
+mod gdsf;
+mod ghost;
+mod lfu;
+mod list;
+mod lru;
+mod lruk;
+mod mru;
+mod random;
+mod sampled;
+mod set;
+mod slru;
+mod tinylfu;
+mod twoq;
+
+pub use arc::{ArcCache, ArcPolicy};
+pub use clock::{ClockCache, ClockPolicy};
+pub use fifo::{FifoCache, FifoPolicy};
+pub use gdsf::{GdsfCache, GdsfPolicy};
+pub use lfu::{LfuCache, LfuPolicy};
+pub use list::SlotList;
+pub use lru::LruPolicy;
+pub use lruk::{LrukCache, LrukPolicy};
+pub use mru::{MruCache, MruPolicy};
+pub use random::{RandomCache, RandomPolicy};
+pub use sampled::{SampledLruCache, SampledLruPolicy};
+pub use slru::{SlruCache, SlruPolicy};
+pub use tinylfu::{TinyLfuCache, TinyLfuPolicy};
+pub use twoq::{TwoQCache, TwoQPolicy};
+
+/// Stratégie d'éviction enfichable dans un [`PolicyCache`].
+///
+/// La politique ne voit jamais les clés ni les valeurs : elle raisonne
+/// uniquement sur des **numéros d'emplacement** (`slot`), stables tant que
+/// l'entrée est présente, et sur l'empreinte (`hash`) de la clé.
+///
+/// # Contrat
+/// * `on_miss` est appelé avant l'insertion d'une nouvelle clé, avant toute éviction.
+/// * `on_insert` est appelé une fois par nouvelle entrée.
+/// * `on_access` est appelé à chaque lecture ou mise à jour d'une entrée.
+/// * `select_victim` désigne une entrée sans la retirer ; le cache appelle
+///   ensuite `on_evict` pour cet emplacement.
+/// * `on_remove` est appelé pour toute entrée retirée explicitement.
+pub trait EvictionPolicy {
+    /// Crée la politique pour un cache de capacité donnée.
+    fn with_capacity(capacity: usize) -> Self
+    where
+        Self: Sized;
+
+    /// Une clé absente du cache va être insérée (avant l'éventuelle éviction).
+    ///
+    /// Permet aux politiques adaptatives de consulter leur historique pour
+    /// orienter le choix de la victime.
+    fn on_miss(&mut self, _hash: u64) {}
+
+    /// Une nouvelle entrée vient d'être insérée à l'emplacement `slot`.
+    ///
+    /// `hash` identifie la clé ; il permet aux politiques qui gardent un
+    /// historique (listes fantômes, compteurs de fréquence) de la reconnaître.
+    fn on_insert(&mut self, slot: usize, hash: u64);
+
+    /// L'entrée à l'emplacement `slot` vient d'être lue ou mise à jour.
+    fn on_access(&mut self, slot: usize);
+
+    /// L'entrée à l'emplacement `slot` a été retirée explicitement.
+    fn on_remove(&mut self, slot: usize);
+
+    /// L'entrée à l'emplacement `slot` a été évincée par la politique.
+    ///
+    /// Par défaut, équivalent à `on_remove`.
+    fn on_evict(&mut self, slot: usize) {
+        self.on_remove(slot);
+    }
+
+    /// Choisit l'entrée à évincer lorsque le cache est plein.
+    fn select_victim(&mut self) -> Option<usize>;
+
+    /// Oublie toutes les entrées.
+    fn clear(&mut self);
+}
+
+/// Une entrée stockée dans l'arena d'un [`PolicyCache`].
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+/// Cache générique dont l'algorithme d'éviction est fourni par `P`.
+///
+/// # Architecture
+/// * **KeyIndex** : Associe `hash(Clé) -> Slot` (accès O(1)), comme le `LruCache`.
+/// * **Slots** : Arena de `Option<Entry>` aux indices **stables** ; les trous
+///   laissés par les suppressions sont recyclés via une free-list.
+/// * **Policy** : Reçoit les événements (insertion, accès, suppression) et
+///   désigne les victimes.
+///
+/// Les politiques concrètes (LRU, FIFO, MRU...) se contentent ainsi de gérer
+/// un ordre sur les slots, en partageant ce cœur testé.
+pub struct PolicyCache<K, V, P> {
+    capacity: usize,
+    index: KeyIndex,
+    slots: Vec<Option<Entry<K, V>>>,
+    free: Vec<usize>,
+    len: usize,
+    policy: P,
+}
+
+impl<K, V, P> PolicyCache<K, V, P>
+where
+    K: Hash + Eq,
+    P: EvictionPolicy,
+{
+    /// Crée un cache vide dont la politique est construite via `P::with_capacity`.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_policy(capacity, P::with_capacity(capacity))
+    }
+
+    /// Crée un cache vide avec une politique déjà configurée.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn with_policy(capacity: usize, policy: P) -> Self {
+        assert!(capacity > 0, "La capacité doit être > 0");
+        PolicyCache {
+            capacity,
+            index: KeyIndex::with_capacity(capacity),
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            len: 0,
+            policy,
+        }
+    }
+
+    /// Accès en lecture à la politique (ex: pour inspecter son état).
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.find(key, |slot| {
+            &self.slots[slot]
+                .as_ref()
+                .expect("slot indexé mais vide")
+                .key
+        })
+    }
+
+    /// Libère un emplacement et retourne son contenu.
+    fn remove_slot(&mut self, slot: usize, evicted: bool) -> (K, V) {
+        self.index.remove(slot);
+        if evicted {
+            self.policy.on_evict(slot);
+        } else {
+            self.policy.on_remove(slot);
+        }
+        let entry = self.slots[slot].take().expect("slot indexé mais vide");
+        self.free.push(slot);
+        self.len -= 1;
+        (entry.key, entry.value)
+    }
+
+    /// Évince l'entrée désignée par la politique.
+    fn evict(&mut self) -> Option<(K, V)> {
+        let victim = self.policy.select_victim()?;
+        Some(self.remove_slot(victim, true))
+    }
+}
+
+impl<K, V, P> Cache<K, V> for PolicyCache<K, V, P>
+where
+    K: Hash + Eq,
+    P: EvictionPolicy,
+{
+    fn put(&mut self, key: K, value: V) {
+        if let Some(slot) = self.find(&key) {
+            if let Some(entry) = self.slots[slot].as_mut() {
+                entry.value = value;
+            }
+            self.policy.on_access(slot);
+            return;
+        }
+
+        let hash = self.index.hash(&key);
+        self.policy.on_miss(hash);
+        if self.len >= self.capacity {
+            self.evict();
+        }
+
+        let entry = Some(Entry { key, value });
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.slots[slot] = entry;
+                slot
+            }
+            None => {
+                self.slots.push(entry);
+                self.slots.len() - 1
+            }
+        };
+        self.index.insert(slot, hash);
+        self.len += 1;
+        self.policy.on_insert(slot, hash);
+    }
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let slot = self.find(key)?;
+        self.policy.on_access(slot);
+        self.slots[slot].as_ref().map(|e| &e.value)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        let slot = self.find(key)?;
+        self.slots[slot].as_ref().map(|e| &e.value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = self.find(key)?;
+        Some(self.remove_slot(slot, false).1)
+    }
+
+    fn clear(&mut self) {
+        self.index.clear();
+        self.slots.clear();
+        self.free.clear();
+        self.len = 0;
+        self.policy.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Parcourt les entrées dans l'ordre des emplacements (sans lien avec la politique).
+    fn iter(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+        Box::new(
+            self.slots
+                .iter()
+                .filter_map(|slot| slot.as_ref().map(|e| (&e.key, &e.value))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_policy_matches_lru_cache() {
+        let mut generic: PolicyCache<u32, u32, LruPolicy> = PolicyCache::new(4);
+        let mut reference = crate::LruCache::new(4);
+        let mut seed = 7u32;
+        for step in 0..2000 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let key = (seed >> 16) % 10;
+            if seed & 4 == 0 {
+                assert_eq!(generic.get(&key), reference.get(&key));
+            } else if seed & 8 == 0 {
+                assert_eq!(generic.remove(&key), reference.remove(&key));
+            } else {
+                generic.put(key, step);
+                reference.put(key, step);
+            }
+            assert_eq!(generic.len(), reference.len());
+        }
+    }
+
+    #[test]
+    fn test_slots_are_recycled() {
+        let mut cache: PolicyCache<&str, i32, LruPolicy> = PolicyCache::new(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.put("C", 3);
+        cache.remove(&"B");
+        cache.put("D", 4);
+        assert_eq!(cache.slots.len(), 2);
+        assert_eq!(cache.peek(&"A"), None);
+        assert_eq!(cache.iter().count(), 2);
+    }
+}
@@ -0,0 +1,72 @@
+use super::{EvictionPolicy, PolicyCache, SlotList};
+
+/// Un cache MRU (Most Recently Used).
+pub type MruCache<K, V> = PolicyCache<K, V, MruPolicy>;
+
+/// Politique MRU : évince l'entrée utilisée **le plus récemment**.
+///
+/// Contre-intuitive, elle surpasse LRU sur les scans cycliques plus grands
+/// que le cache : les anciennes entrées, qui reviendront bientôt, sont conservées.
+#[derive(Default)]
+pub struct MruPolicy {
+    order: SlotList,
+}
+
+impl EvictionPolicy for MruPolicy {
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::default()
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        self.order.push_front(slot);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.order.move_to_front(slot);
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        self.order.remove(slot);
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.order.front()
+    }
+
+    fn clear(&mut self) {
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_evicts_most_recent() {
+        let mut cache = MruCache::new(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.get(&"A");
+        cache.put("C", 3);
+        assert_eq!(cache.peek(&"A"), None);
+        assert_eq!(cache.peek(&"B"), Some(&2));
+    }
+
+    #[test]
+    fn test_cyclic_scan_keeps_hits() {
+        let mut cache = MruCache::new(3);
+        let mut hits = 0;
+        for _ in 0..10 {
+            for key in 0..4 {
+                if cache.get(&key).is_some() {
+                    hits += 1;
+                } else {
+                    cache.put(key, key);
+                }
+            }
+        }
+        assert!(hits > 0);
+    }
+}
@@ -0,0 +1,76 @@
+use super::set::SlotSet;
+use super::{EvictionPolicy, PolicyCache};
+use crate::rng::XorShift64;
+
+/// Un cache à remplacement aléatoire.
+pub type RandomCache<K, V> = PolicyCache<K, V, RandomPolicy>;
+
+/// Politique aléatoire : évince une entrée tirée uniformément.
+///
+/// Sans aucune métadonnée à mettre à jour lors des lectures, elle sert de
+/// référence ("baseline") dans les comparaisons de taux de succès.
+pub struct RandomPolicy {
+    members: SlotSet,
+    rng: XorShift64,
+}
+
+impl RandomPolicy {
+    /// Crée la politique avec une graine fixe (tirages reproductibles).
+    pub fn with_seed(seed: u64) -> Self {
+        RandomPolicy {
+            members: SlotSet::default(),
+            rng: XorShift64::with_seed(seed),
+        }
+    }
+}
+
+impl EvictionPolicy for RandomPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        RandomPolicy {
+            members: SlotSet::with_capacity(capacity),
+            rng: XorShift64::from_entropy(),
+        }
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        self.members.insert(slot);
+    }
+
+    fn on_access(&mut self, _slot: usize) {}
+
+    fn on_remove(&mut self, slot: usize) {
+        self.members.remove(slot);
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.members.sample(&mut self.rng)
+    }
+
+    fn clear(&mut self) {
+        self.members.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_seeded_eviction_is_reproducible() {
+        let run = || {
+            let mut cache: RandomCache<u32, ()> =
+                PolicyCache::with_policy(4, RandomPolicy::with_seed(42));
+            for key in 0..20 {
+                cache.put(key, ());
+            }
+            let mut keys: Vec<u32> = cache.iter().map(|(k, _)| *k).collect();
+            keys.sort();
+            keys
+        };
+        let first = run();
+        assert_eq!(first.len(), 4);
+        assert_eq!(first, run());
+        assert!(first.contains(&19));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::rng::XorShift64;
+
+/// Ensemble dense d'emplacements permettant un tirage uniforme en O(1).
+///
+/// # Architecture
+/// * **Membres** : Vecteur dense des emplacements présents.
+/// * **Positions** : `slot -> position` dans ce vecteur, pour retirer en O(1)
+///   via `swap_remove`.
+#[derive(Default)]
+pub(crate) struct SlotSet {
+    members: Vec<usize>,
+    positions: Vec<Option<usize>>,
+}
+
+impl SlotSet {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        SlotSet {
+            members: Vec::with_capacity(capacity),
+            positions: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub(crate) fn insert(&mut self, slot: usize) {
+        if slot >= self.positions.len() {
+            self.positions.resize(slot + 1, None);
+        }
+        if self.positions[slot].is_none() {
+            self.positions[slot] = Some(self.members.len());
+            self.members.push(slot);
+        }
+    }
+
+    pub(crate) fn remove(&mut self, slot: usize) {
+        let Some(pos) = self.positions.get_mut(slot).and_then(Option::take) else {
+            return;
+        };
+        self.members.swap_remove(pos);
+        if let Some(&moved) = self.members.get(pos) {
+            self.positions[moved] = Some(pos);
+        }
+    }
+
+    /// Tire un emplacement uniformément (None si l'ensemble est vide).
+    pub(crate) fn sample(&self, rng: &mut XorShift64) -> Option<usize> {
+        if self.members.is_empty() {
+            return None;
+        }
+        Some(self.members[rng.below(self.members.len())])
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.members.clear();
+        self.positions.clear();
+    }
+}
@@ -0,0 +1,113 @@
+use super::{EvictionPolicy, PolicyCache, SlotList};
+
+/// Un cache SLRU (Segmented LRU).
+pub type SlruCache<K, V> = PolicyCache<K, V, SlruPolicy>;
+
+/// Politique SLRU : deux segments LRU, probatoire et protégé.
+///
+/// * Les nouvelles entrées arrivent dans le segment **probatoire**.
+/// * Un second accès les promeut dans le segment **protégé** ; si celui-ci
+///   déborde, son entrée la plus ancienne redescend en tête du probatoire.
+/// * La victime est prise dans le probatoire en priorité.
+///
+/// Un scan ne touche que le segment probatoire : les entrées réutilisées,
+/// protégées, y résistent.
+pub struct SlruPolicy {
+    probation: SlotList,
+    protected: SlotList,
+    protected_capacity: usize,
+}
+
+impl SlruPolicy {
+    /// Part de la capacité réservée au segment protégé par défaut.
+    pub const DEFAULT_PROTECTED_RATIO: f64 = 0.8;
+
+    /// Crée la politique avec une taille de segment protégé explicite.
+    pub fn with_protected_capacity(protected_capacity: usize) -> Self {
+        SlruPolicy {
+            probation: SlotList::new(),
+            protected: SlotList::new(),
+            protected_capacity,
+        }
+    }
+
+    /// Indique si l'emplacement est dans le segment protégé.
+    pub fn is_protected(&self, slot: usize) -> bool {
+        self.protected.contains(slot)
+    }
+}
+
+impl EvictionPolicy for SlruPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        let protected = (capacity as f64 * Self::DEFAULT_PROTECTED_RATIO) as usize;
+        Self::with_protected_capacity(protected.max(1))
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        self.probation.push_front(slot);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        if self.protected.contains(slot) {
+            self.protected.move_to_front(slot);
+            return;
+        }
+        self.probation.remove(slot);
+        self.protected.push_front(slot);
+        if self.protected.len() > self.protected_capacity
+            && let Some(demoted) = self.protected.pop_back()
+        {
+            self.probation.push_front(demoted);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.probation.remove(slot) {
+            self.protected.remove(slot);
+        }
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        self.probation.back().or_else(|| self.protected.back())
+    }
+
+    fn clear(&mut self) {
+        self.probation.clear();
+        self.protected.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_scan_does_not_flush_protected() {
+        let mut cache = SlruCache::new(5);
+        cache.put("hot", 0);
+        cache.get(&"hot");
+        for key in ["a", "b", "c", "d", "e", "f", "g"] {
+            cache.put(key, 1);
+        }
+        assert!(cache.contains(&"hot"));
+        assert!(cache.policy().is_protected(0));
+    }
+
+    #[test]
+    fn test_protected_overflow_demotes() {
+        let mut cache: SlruCache<u32, u32> =
+            PolicyCache::with_policy(3, SlruPolicy::with_protected_capacity(1));
+        cache.put(1, 1);
+        cache.put(2, 2);
+        cache.get(&1);
+        cache.get(&2);
+        // 1 est redescendu en probatoire : il redevient candidat à l'éviction.
+        assert!(!cache.policy().is_protected(0));
+        cache.put(3, 3);
+        cache.put(4, 4);
+        assert!(!cache.contains(&1));
+        assert!(cache.contains(&2));
+        assert!(cache.contains(&3));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::sketch::FrequencySketch;
+
+use super::{EvictionPolicy, PolicyCache, SlotList, SlruPolicy};
+
+/// Un cache W-TinyLFU.
+pub type TinyLfuCache<K, V> = PolicyCache<K, V, TinyLfuPolicy>;
+
+/// Politique W-TinyLFU : fenêtre LRU + région principale SLRU + filtre d'admission.
+///
+/// # Architecture
+/// * **Fenêtre** : Petit LRU (1 % de la capacité) qui accueille toutes les
+///   nouvelles entrées ; il absorbe les rafales récentes.
+/// * **Principal** : Un SLRU (probatoire + protégé) pour le reste de la capacité.
+/// * **Sketch** : Un count-min qui estime la fréquence récente de chaque clé,
+///   y compris des clés absentes du cache.
+///
+/// Quand la fenêtre déborde, son entrée la plus ancienne (le *candidat*) affronte
+/// la victime de la région principale : seule la plus fréquente des deux reste.
+/// Les clés vues une seule fois ne peuvent donc pas chasser les entrées chaudes.
+pub struct TinyLfuPolicy {
+    window: SlotList,
+    window_capacity: usize,
+    main: SlruPolicy,
+    sketch: FrequencySketch,
+    hashes: Vec<u64>,
+}
+
+impl TinyLfuPolicy {
+    /// Fréquence estimée pour un emplacement présent.
+    fn frequency(&self, slot: usize) -> u8 {
+        self.sketch.estimate_hash(self.hashes[slot])
+    }
+}
+
+impl EvictionPolicy for TinyLfuPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        let window_capacity = (capacity / 100).max(1);
+        let main_capacity = capacity.saturating_sub(window_capacity).max(1);
+        TinyLfuPolicy {
+            window: SlotList::new(),
+            window_capacity,
+            main: SlruPolicy::with_capacity(main_capacity),
+            sketch: FrequencySketch::with_capacity(capacity),
+            hashes: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn on_miss(&mut self, hash: u64) {
+        self.sketch.increment_hash(hash);
+    }
+
+    fn on_insert(&mut self, slot: usize, hash: u64) {
+        if slot >= self.hashes.len() {
+            self.hashes.resize(slot + 1, 0);
+        }
+        // Le sketch n'est pas incrémenté ici : la clé a déjà été comptée par
+        // `on_miss`, avant l'éviction qui lui a fait de la place.
+        self.hashes[slot] = hash;
+        self.window.push_front(slot);
+        // Le duel n'a lieu que dans `select_victim`, cache plein ; avant,
+        // le débordement de la fenêtre passe directement dans la région
+        // principale.
+        if self.window.len() > self.window_capacity
+            && let Some(overflow) = self.window.pop_back()
+        {
+            self.main.on_insert(overflow, self.hashes[overflow]);
+        }
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.sketch.increment_hash(self.hashes[slot]);
+        if self.window.contains(slot) {
+            self.window.move_to_front(slot);
+        } else {
+            self.main.on_access(slot);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.window.remove(slot) {
+            self.main.on_remove(slot);
+        }
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        if self.window.len() < self.window_capacity {
+            return self.main.select_victim().or_else(|| self.window.back());
+        }
+        let candidate = self.window.back()?;
+        let Some(victim) = self.main.select_victim() else {
+            return Some(candidate);
+        };
+        if self.frequency(candidate) > self.frequency(victim) {
+            // Le candidat est admis dans la région principale.
+            self.window.remove(candidate);
+            self.main.on_insert(candidate, self.hashes[candidate]);
+            Some(victim)
+        } else {
+            Some(candidate)
+        }
+    }
+
+    fn clear(&mut self) {
+        self.window.clear();
+        self.main.clear();
+        self.sketch.clear();
+        self.hashes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_one_hit_wonders_do_not_evict_hot_keys() {
+        let mut cache = TinyLfuCache::new(100);
+        for _ in 0..5 {
+            for key in 0..50 {
+                if cache.get(&key).is_none() {
+                    cache.put(key, key);
+                }
+            }
+        }
+        for key in 1000..3000 {
+            cache.put(key, key);
+        }
+        let survivors = (0..50).filter(|key| cache.contains(key)).count();
+        assert!(survivors >= 45, "seulement {survivors} clés chaudes conservées");
+    }
+
+    #[test]
+    fn test_capacity_is_respected() {
+        let mut cache = TinyLfuCache::new(10);
+        for key in 0..100 {
+            cache.put(key, ());
+            assert!(cache.len() <= 10);
+        }
+    }
+}
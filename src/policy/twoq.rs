@@ -0,0 +1,134 @@
+use super::ghost::GhostList;
+use super::{EvictionPolicy, PolicyCache, SlotList};
+
+/// Un cache 2Q.
+pub type TwoQCache<K, V> = PolicyCache<K, V, TwoQPolicy>;
+
+/// Politique 2Q (Johnson & Shasha, version complète).
+///
+/// # Architecture
+/// * **A1in** : File FIFO des entrées vues une seule fois (environ 25 % de la capacité).
+/// * **A1out** : File fantôme des clés sorties de A1in (environ 50 % de la capacité).
+/// * **Am** : Région LRU des entrées redemandées après leur passage dans A1in.
+///
+/// Une clé n'entre dans Am que si elle revient alors qu'elle est dans A1out :
+/// un scan ne fait que traverser A1in.
+pub struct TwoQPolicy {
+    a1in: SlotList,
+    a1out: GhostList,
+    am: SlotList,
+    kin: usize,
+    kout: usize,
+    hashes: Vec<u64>,
+    /// La clé en cours d'insertion revient de A1out : elle va dans Am.
+    pending_am: bool,
+}
+
+impl TwoQPolicy {
+    /// Crée la politique avec des tailles explicites pour A1in et A1out.
+    pub fn with_sizes(kin: usize, kout: usize) -> Self {
+        TwoQPolicy {
+            a1in: SlotList::new(),
+            a1out: GhostList::default(),
+            am: SlotList::new(),
+            kin: kin.max(1),
+            kout: kout.max(1),
+            hashes: Vec::new(),
+            pending_am: false,
+        }
+    }
+}
+
+impl EvictionPolicy for TwoQPolicy {
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_sizes(capacity / 4, capacity / 2)
+    }
+
+    fn on_miss(&mut self, hash: u64) {
+        self.pending_am = self.a1out.remove(hash);
+    }
+
+    fn on_insert(&mut self, slot: usize, hash: u64) {
+        if slot >= self.hashes.len() {
+            self.hashes.resize(slot + 1, 0);
+        }
+        self.hashes[slot] = hash;
+        if self.pending_am {
+            self.am.push_front(slot);
+        } else {
+            self.a1in.push_front(slot);
+        }
+        self.pending_am = false;
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        // Dans A1in, un accès ne change rien : c'est une FIFO.
+        if self.am.contains(slot) {
+            self.am.move_to_front(slot);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.a1in.remove(slot) {
+            self.am.remove(slot);
+        }
+    }
+
+    fn on_evict(&mut self, slot: usize) {
+        if self.a1in.remove(slot) {
+            self.a1out.push(self.hashes[slot]);
+            while self.a1out.len() > self.kout && self.a1out.pop_oldest().is_some() {}
+        } else {
+            self.am.remove(slot);
+        }
+    }
+
+    fn select_victim(&mut self) -> Option<usize> {
+        if self.a1in.len() > self.kin || self.am.is_empty() {
+            self.a1in.back().or_else(|| self.am.back())
+        } else {
+            self.am.back()
+        }
+    }
+
+    fn clear(&mut self) {
+        self.a1in.clear();
+        self.a1out.clear();
+        self.am.clear();
+        self.hashes.clear();
+        self.pending_am = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_returning_key_enters_am() {
+        let mut cache = TwoQCache::new(4);
+        for key in 0..6 {
+            cache.put(key, key);
+        }
+        // 0 est sorti de A1in vers A1out : son retour l'installe dans Am.
+        assert!(!cache.contains(&0));
+        cache.put(0, 0);
+        for key in 100..110 {
+            cache.put(key, key);
+        }
+        assert!(cache.contains(&0));
+    }
+
+    #[test]
+    fn test_scan_only_touches_a1in() {
+        let mut cache = TwoQCache::new(8);
+        cache.put(1, 1);
+        cache.get(&1);
+        for key in 10..40 {
+            cache.put(key, key);
+        }
+        assert!(!cache.contains(&1));
+        assert_eq!(cache.len(), 8);
+    }
+}
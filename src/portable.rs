@@ -0,0 +1,79 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::cache::LruCache;
+use crate::error::CacheError;
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Display + FromStr,
+    V: Display + FromStr,
+    <K as FromStr>::Err: Debug,
+    <V as FromStr>::Err: Debug,
+{
+    /// Sauvegarde le cache en mémoire, au format texte de
+    /// [`save_to_writer`](Self::save_to_writer), sans passer par un fichier.
+    ///
+    /// Destiné aux plateformes sans système de fichiers (WebAssembly :
+    /// `localStorage`, IndexedDB...).
+    pub fn save_to_string(&self) -> String {
+        String::from_utf8(self.save_to_bytes()).expect("le format texte est en UTF-8")
+    }
+
+    /// Comme [`save_to_string`](Self::save_to_string), sous forme d'octets.
+    pub fn save_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.save_to_writer(&mut bytes)
+            .expect("l'écriture en mémoire n'échoue pas");
+        bytes
+    }
+
+    /// Recharge un cache sauvegardé par [`save_to_string`](Self::save_to_string),
+    /// avec la capacité enregistrée.
+    ///
+    /// Un texte vide (rien encore sauvegardé) donne un cache vide de
+    /// capacité `capacity`. Comme [`load_strict`](Self::load_strict), la
+    /// première erreur est remontée.
+    pub fn load_from_str(capacity: usize, text: &str) -> Result<Self, CacheError> {
+        Self::load_from_bytes(capacity, text.as_bytes())
+    }
+
+    /// Recharge un cache sauvegardé par [`save_to_bytes`](Self::save_to_bytes).
+    pub fn load_from_bytes(capacity: usize, bytes: &[u8]) -> Result<Self, CacheError> {
+        if bytes.is_empty() {
+            return Ok(LruCache::new(capacity));
+        }
+        Self::load_from_reader_strict(capacity, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::Cache;
+
+    #[test]
+    fn test_string_round_trip() {
+        let mut cache = LruCache::new(3);
+        cache.put("a=b".to_string(), 1);
+        cache.put("#c".to_string(), 2);
+        cache.get(&"a=b".to_string());
+
+        let text = cache.save_to_string();
+        let loaded: LruCache<String, i32> = LruCache::load_from_str(10, &text).unwrap();
+        assert_eq!(loaded.capacity(), 3);
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+        let loaded: LruCache<String, i32> =
+            LruCache::load_from_bytes(10, &cache.save_to_bytes()).unwrap();
+        assert_eq!(loaded.to_vec(), cache.to_vec());
+
+        let empty: LruCache<String, i32> = LruCache::load_from_str(5, "").unwrap();
+        assert_eq!(empty.capacity(), 5);
+        let altered = text.replace("=1", "=7");
+        assert!(matches!(
+            LruCache::<String, i32>::load_from_str(5, &altered),
+            Err(CacheError::Corrupted(_))
+        ));
+    }
+}
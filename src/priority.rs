@@ -0,0 +1,188 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::cache::{LruCache, WriteOptions};
+
+/// Niveau de priorité d'une entrée face à l'éviction.
+///
+/// L'éviction choisit toujours l'entrée la moins récemment utilisée parmi
+/// celles du niveau le plus bas présent dans le cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Entrée bon marché à recalculer, évincée en premier.
+    Low,
+    /// Niveau des entrées écrites par `put`.
+    #[default]
+    Normal,
+    /// Entrée coûteuse, évincée en dernier.
+    High,
+}
+
+impl Priority {
+    /// Nombre de niveaux (taille des compteurs par niveau).
+    pub(crate) const LEVELS: usize = 3;
+
+    const ALL: [Priority; Self::LEVELS] = [Priority::Low, Priority::Normal, Priority::High];
+}
+
+/// Extrémités de la liste de récence d'un niveau de priorité.
+///
+/// Chaque niveau chaîne ses entrées (`level_prev` / `level_next`) dans le
+/// même ordre que la liste principale : sa Queue est la victime du niveau.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct LevelEnds {
+    pub(crate) head: Option<usize>,
+    pub(crate) tail: Option<usize>,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Insère ou met à jour une valeur avec un niveau de priorité.
+    ///
+    /// Comme pour le TTL, un `put` ultérieur sur la même clé rétablit
+    /// [`Priority::Normal`].
+    pub fn put_with_priority(&mut self, key: K, value: V, priority: Priority) {
+        let expires_at = self.default_deadline();
+        self.put_with_options(
+            key,
+            value,
+            WriteOptions {
+                expires_at,
+                priority,
+                tags: None,
+            },
+        );
+    }
+
+    /// Niveau de priorité d'une entrée présente.
+    pub fn priority<Q>(&self, key: &Q) -> Option<Priority>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).map(|index| self.arena[index].priority)
+    }
+
+    /// Entrée à évincer : la moins récemment utilisée, hors épinglées, du
+    /// niveau de priorité le plus bas. O(1) tant que la Queue du niveau est
+    /// éligible.
+    pub(crate) fn eviction_victim(&self) -> Option<usize> {
+        Priority::ALL.into_iter().find_map(|level| {
+            let mut current = self.levels[level as usize].tail;
+            while let Some(index) = current {
+                if !self.arena[index].pinned {
+                    return Some(index);
+                }
+                current = self.arena[index].level_prev;
+            }
+            None
+        })
+    }
+
+    /// Place `index` en Tête de la liste de son niveau.
+    pub(crate) fn link_level_front(&mut self, index: usize) {
+        let ends = &mut self.levels[self.arena[index].priority as usize];
+        let old_head = ends.head.replace(index);
+        if old_head.is_none() {
+            ends.tail = Some(index);
+        }
+        let node = &mut self.arena[index];
+        node.level_prev = None;
+        node.level_next = old_head;
+        if let Some(old_head) = old_head {
+            self.arena[old_head].level_prev = Some(index);
+        }
+    }
+
+    /// Détache `index` de la liste de son niveau en recousant ses voisins.
+    pub(crate) fn unlink_level(&mut self, index: usize) {
+        let node = &mut self.arena[index];
+        let (prev, next) = (node.level_prev.take(), node.level_next.take());
+        let ends = &mut self.levels[node.priority as usize];
+        match prev {
+            Some(prev) => self.arena[prev].level_next = next,
+            None => ends.head = next,
+        }
+        match next {
+            Some(next) => self.arena[next].level_prev = prev,
+            None => ends.tail = prev,
+        }
+    }
+
+    /// Repointe vers `index` les voisins d'un nœud déplacé par `swap_remove`.
+    pub(crate) fn relink_level(&mut self, index: usize) {
+        let node = &self.arena[index];
+        let (prev, next) = (node.level_prev, node.level_next);
+        let ends = &mut self.levels[node.priority as usize];
+        match prev {
+            Some(prev) => self.arena[prev].level_next = Some(index),
+            None => ends.head = Some(index),
+        }
+        match next {
+            Some(next) => self.arena[next].level_prev = Some(index),
+            None => ends.tail = Some(index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_lowest_priority_is_evicted_first() {
+        let mut cache = LruCache::new(3);
+        cache.put_with_priority("render", 1, Priority::High);
+        cache.put("page", 2);
+        cache.put_with_priority("thumb", 3, Priority::Low);
+        cache.get(&"thumb");
+
+        // "thumb" est le plus récent, mais le seul de priorité basse.
+        cache.put("other", 4);
+        assert!(!cache.contains(&"thumb"));
+        // Puis la Queue de niveau Normal, jamais "render".
+        cache.put("last", 5);
+        assert!(!cache.contains(&"page"));
+        assert_eq!(cache.priority(&"render"), Some(Priority::High));
+    }
+
+    #[test]
+    fn test_put_restores_normal_priority() {
+        let mut cache = LruCache::new(2);
+        cache.put_with_priority("a", 1, Priority::High);
+        cache.put("a", 2);
+        assert_eq!(cache.priority(&"a"), Some(Priority::Normal));
+        cache.put("b", 3);
+        cache.put("c", 4);
+        assert!(!cache.contains(&"a"));
+        assert_eq!(cache.priority(&"a"), None);
+    }
+
+    #[test]
+    fn test_level_lists_follow_recency() {
+        const LEVELS: [Priority; 3] = [Priority::Low, Priority::Normal, Priority::High];
+        let mut cache = LruCache::new(6);
+        let mut seed = 7u32;
+        for step in 0..2000 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let key = (seed >> 16) % 12;
+            match seed % 5 {
+                0 => {
+                    cache.remove(&key);
+                }
+                1 => {
+                    cache.get(&key);
+                }
+                _ => cache.put_with_priority(key, step, LEVELS[(seed >> 8) as usize % 3]),
+            }
+            assert_eq!(cache.check_consistency(), Ok(()));
+            // La victime est la plus ancienne entrée du niveau le plus bas.
+            let lowest = cache.iter().filter_map(|(key, _)| cache.priority(key)).min();
+            let oldest = cache.iter().rfind(|(key, _)| cache.priority(*key) == lowest).map(|(key, _)| *key);
+            assert_eq!(cache.eviction_victim().map(|index| cache.arena[index].key), oldest);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::hash::Hash;
+
+use crate::cache::LruCache;
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Remet en Tête les entrées lues depuis leur placement qui ont atteint
+    /// la Queue, jusqu'à trouver une entrée non marquée.
+    ///
+    /// # Complexité
+    /// O(1) amorti : chaque lecture cause au plus un déplacement. Si tout
+    /// le cache est marqué, un tour complet démarque toutes les entrées.
+    pub(crate) fn settle_recency(&mut self) {
+        while let Some(tail) = self.tail
+            && self.arena[tail].referenced
+        {
+            self.move_to_head(tail);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, LruCache};
+
+    #[test]
+    fn test_reads_defer_reordering_until_eviction() {
+        let mut cache = LruCache::builder(3).lazy_recency().build();
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        // La lecture ne déplace rien...
+        let order: Vec<_> = cache.iter().map(|(key, _)| *key).collect();
+        assert_eq!(order, ["c", "b", "a"]);
+        // ... mais "a" obtient une seconde chance : "b" est évincé.
+        cache.put("d", 4);
+        assert!(cache.contains(&"a"));
+        assert!(!cache.contains(&"b"));
+        assert_eq!(cache.entry_info("a").map(|info| info.hits), Some(1));
+    }
+
+    #[test]
+    fn test_all_referenced_still_evicts() {
+        let mut cache = LruCache::builder(2).lazy_recency().build();
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.get(&"a");
+        cache.get(&"b");
+        cache.put("c", 3);
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains(&"a"));
+        assert!(cache.check_consistency().is_ok());
+    }
+}
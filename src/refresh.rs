@@ -0,0 +1,74 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::cache::LruCache;
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Âge à partir duquel une entrée lue est rechargée par anticipation.
+    pub fn refresh_after(&self) -> Option<Duration> {
+        self.refresh_after
+    }
+
+    /// Lit une valeur comme [`get`](crate::Cache::get), et demande son
+    /// rechargement si elle a dépassé l'âge `refresh_after`.
+    ///
+    /// La valeur courante est toujours servie : `refresh` reçoit seulement la
+    /// clé, à charge pour lui de lancer le rechargement (thread, canal vers
+    /// un worker...) puis de réinsérer la nouvelle valeur via `put`.
+    /// Le rechargement n'est demandé qu'une fois par écriture, même si
+    /// l'entrée est relue avant que la nouvelle valeur n'arrive.
+    ///
+    /// # Complexité
+    /// O(1), hors coût de `refresh`.
+    pub fn get_with_refresh<Q>(&mut self, key: &Q, refresh: impl FnOnce(&K)) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_live(key)?;
+        let now = self.clock.now();
+        let node = &mut self.arena[index];
+        if node.refresh_at.is_some_and(|at| now >= at) {
+            node.refresh_at = None;
+            refresh(&node.key);
+        }
+        self.promote(index);
+        Some(&self.arena[index].value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, LruCache, MockClock};
+    use std::time::Duration;
+
+    #[test]
+    fn test_refresh_requested_once_past_threshold() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(10)
+            .ttl(Duration::from_secs(10))
+            .refresh_after(Duration::from_secs(8))
+            .clock(clock.clone())
+            .build();
+        cache.put("k", 1);
+
+        let mut requested = Vec::new();
+        assert_eq!(cache.get_with_refresh("k", |k| requested.push(*k)), Some(&1));
+        assert!(requested.is_empty());
+
+        clock.advance(Duration::from_secs(8));
+        assert_eq!(cache.get_with_refresh("k", |k| requested.push(*k)), Some(&1));
+        assert_eq!(cache.get_with_refresh("k", |k| requested.push(*k)), Some(&1));
+        assert_eq!(requested, vec!["k"]);
+
+        // La nouvelle valeur réarme le rafraîchissement et le TTL.
+        cache.put("k", 2);
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.get_with_refresh("k", |k| requested.push(*k)), Some(&2));
+        assert_eq!(requested, vec!["k"]);
+    }
+}
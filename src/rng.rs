@@ -0,0 +1,40 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+/// Petit générateur pseudo-aléatoire (xorshift64*).
+///
+/// Suffisant pour l'échantillonnage des politiques d'éviction : rapide,
+/// sans dépendance, et reproductible lorsqu'on fournit une graine.
+/// **Ne convient pas** à un usage cryptographique.
+#[derive(Clone)]
+pub(crate) struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// Crée un générateur à graine fixe (0 est remplacé par une constante).
+    pub(crate) fn with_seed(seed: u64) -> Self {
+        XorShift64 {
+            state: if seed == 0 { 0x2545_F491_4F6C_DD1D } else { seed },
+        }
+    }
+
+    /// Crée un générateur dont la graine provient de l'aléa du processus.
+    pub(crate) fn from_entropy() -> Self {
+        Self::with_seed(RandomState::new().hash_one(0x5EED_u64))
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Entier uniforme dans `0..bound` (`bound` > 0).
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        ((u128::from(self.next_u64()) * bound as u128) >> 64) as usize
+    }
+}
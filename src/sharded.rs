@@ -0,0 +1,230 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread;
+
+use crate::cache::{Cache, LruCache};
+use crate::singleflight::SingleFlight;
+
+/// Statistiques d'une partition d'un [`ShardedLruCache`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShardStats {
+    /// Nombre d'entrées.
+    pub len: usize,
+    /// Capacité de la partition.
+    pub capacity: usize,
+    /// Lectures ayant trouvé la clé.
+    pub hits: u64,
+    /// Lectures n'ayant pas trouvé la clé.
+    pub misses: u64,
+}
+
+struct Shard<K, V> {
+    cache: Mutex<LruCache<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Cache concurrent partitionné : les clés sont réparties par hash entre
+/// plusieurs [`LruCache`] verrouillés indépendamment.
+///
+/// # Architecture
+/// * **Partitions** : Chaque partition a son propre `Mutex`, les threads
+///   ne se bloquent que s'ils touchent la même partition.
+/// * **Capacité** : Répartie équitablement ; l'éviction est LRU *par
+///   partition*, donc une approximation du LRU global.
+/// * **Itération** : Les partitions sont verrouillées l'une après l'autre,
+///   le parcours n'est donc pas un instantané atomique du cache.
+pub struct ShardedLruCache<K, V> {
+    hasher: RandomState,
+    shards: Vec<Shard<K, V>>,
+    flights: SingleFlight<K, V>,
+}
+
+impl<K, V> ShardedLruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Crée un cache avec 4 partitions par cœur disponible.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn new(capacity: usize) -> Self {
+        let cores = thread::available_parallelism().map_or(1, |n| n.get());
+        Self::with_shards(capacity, cores * 4)
+    }
+
+    /// Crée un cache de capacité totale `capacity` réparti en `shards` partitions.
+    ///
+    /// Le nombre de partitions est borné par la capacité (chaque partition
+    /// contient au moins une entrée).
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn with_shards(capacity: usize, shards: usize) -> Self {
+        assert!(capacity > 0, "La capacité doit être > 0");
+        let count = shards.clamp(1, capacity);
+        let shards = (0..count)
+            .map(|i| Shard {
+                // Les `capacity % count` premières partitions reçoivent une entrée de plus.
+                cache: Mutex::new(LruCache::new(capacity / count + usize::from(i < capacity % count))),
+                hits: AtomicU64::new(0),
+                misses: AtomicU64::new(0),
+            })
+            .collect();
+        ShardedLruCache {
+            hasher: RandomState::new(),
+            shards,
+            flights: SingleFlight::new(),
+        }
+    }
+
+    fn shard(&self, key: &K) -> &Shard<K, V> {
+        let hash = self.hasher.hash_one(key);
+        &self.shards[(hash % self.shards.len() as u64) as usize]
+    }
+
+    fn lock(shard: &Shard<K, V>) -> MutexGuard<'_, LruCache<K, V>> {
+        shard.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Retourne un clone de la valeur et la marque comme récente dans sa partition.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let shard = self.shard(key);
+        let value = Self::lock(shard).get(key).cloned();
+        let counter = if value.is_some() { &shard.hits } else { &shard.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    /// Retourne un clone de la valeur sans modifier la récence.
+    pub fn peek(&self, key: &K) -> Option<V> {
+        Self::lock(self.shard(key)).peek(key).cloned()
+    }
+
+    /// Insère ou met à jour une valeur.
+    pub fn put(&self, key: K, value: V) {
+        Self::lock(self.shard(&key)).put(key, value);
+    }
+
+    /// Supprime une clé et retourne sa valeur.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        Self::lock(self.shard(key)).remove(key)
+    }
+
+    /// Retourne la valeur de `key`, en la calculant avec `compute` si absente.
+    ///
+    /// Comme [`SyncLruCache::get_or_compute`](crate::SyncLruCache::get_or_compute) :
+    /// un seul calcul par clé, sans tenir le verrou de la partition.
+    pub fn get_or_compute(&self, key: K, compute: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        self.flights.run(&key, || {
+            if let Some(value) = self.peek(&key) {
+                return value;
+            }
+            let value = compute();
+            self.put(key.clone(), value.clone());
+            value
+        })
+    }
+
+    /// Vide toutes les partitions.
+    pub fn clear(&self) {
+        for shard in &self.shards {
+            Self::lock(shard).clear();
+        }
+    }
+
+    /// Nombre total d'entrées.
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|shard| Self::lock(shard).len()).sum()
+    }
+
+    /// Indique si toutes les partitions sont vides.
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(|shard| Self::lock(shard).is_empty())
+    }
+
+    /// Capacité totale.
+    pub fn capacity(&self) -> usize {
+        self.shards.iter().map(|shard| Self::lock(shard).capacity()).sum()
+    }
+
+    /// Nombre de partitions.
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Applique `f` à chaque entrée, partition par partition, sans effet sur la récence.
+    pub fn for_each(&self, mut f: impl FnMut(&K, &V)) {
+        for shard in &self.shards {
+            for (key, value) in Self::lock(shard).iter() {
+                f(key, value);
+            }
+        }
+    }
+
+    /// Statistiques de chaque partition, pour repérer un déséquilibre.
+    pub fn shard_stats(&self) -> Vec<ShardStats> {
+        self.shards
+            .iter()
+            .map(|shard| {
+                let cache = Self::lock(shard);
+                ShardStats {
+                    len: cache.len(),
+                    capacity: cache.capacity(),
+                    hits: shard.hits.load(Ordering::Relaxed),
+                    misses: shard.misses.load(Ordering::Relaxed),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_capacity_split_across_shards() {
+        let cache: ShardedLruCache<u32, u32> = ShardedLruCache::with_shards(10, 4);
+        assert_eq!(cache.shard_count(), 4);
+        assert_eq!(cache.capacity(), 10);
+        let capacities: Vec<_> = cache.shard_stats().iter().map(|s| s.capacity).collect();
+        assert_eq!(capacities, vec![3, 3, 2, 2]);
+    }
+
+    #[test]
+    fn test_concurrent_access() {
+        let cache = Arc::new(ShardedLruCache::with_shards(1000, 8));
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for i in 0..100 {
+                        cache.put(t * 100 + i, i);
+                        assert_eq!(cache.get(&(t * 100 + i)), Some(i));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(cache.len(), 400);
+        assert_eq!(cache.get(&1000), None);
+
+        let mut sum = 0;
+        cache.for_each(|_, v| sum += v);
+        assert_eq!(sum, 4 * (0..100).sum::<u32>());
+
+        let stats = cache.shard_stats();
+        assert_eq!(stats.iter().map(|s| s.hits).sum::<u64>(), 400);
+        assert_eq!(stats.iter().map(|s| s.misses).sum::<u64>(), 1);
+    }
+}
@@ -0,0 +1,57 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::cache::{Cache, LruCache};
+
+impl<K: Hash + Eq, V: Clone> LruCache<K, V> {
+    /// Comme [`get`](Cache::get), mais retourne une copie de la valeur :
+    /// le cache n'est plus emprunté et reste utilisable pendant qu'on la garde.
+    pub fn get_cloned(&mut self, key: &K) -> Option<V> {
+        self.get(key).cloned()
+    }
+}
+
+impl<K: Hash + Eq, V: ?Sized> LruCache<K, Arc<V>> {
+    /// Comme [`get`](Cache::get), pour un cache de valeurs partagées :
+    /// retourne un nouvel `Arc` (un simple incrément de compteur), que
+    /// l'on peut conserver ou envoyer à un autre thread.
+    ///
+    /// # Exemple
+    /// ```
+    /// use std::sync::Arc;
+    /// use cache_lru_project::{Cache, LruCache};
+    ///
+    /// let mut cache: LruCache<u32, Arc<str>> = LruCache::new(10);
+    /// cache.put(1, Arc::from("gros document"));
+    /// let doc = cache.get_arc(&1).unwrap();
+    /// cache.clear();
+    /// assert_eq!(&*doc, "gros document");
+    /// ```
+    pub fn get_arc(&mut self, key: &K) -> Option<Arc<V>> {
+        self.get(key).map(Arc::clone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_cloned_releases_borrow() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", vec![1, 2]);
+        cache.put("b", vec![3]);
+        let a = cache.get_cloned(&"a").unwrap();
+        // `a` est promu et survit à l'insertion suivante.
+        cache.put("c", a.clone());
+        assert_eq!(cache.get_cloned(&"c"), Some(vec![1, 2]));
+        assert_eq!(cache.get_cloned(&"b"), None);
+
+        let mut shared: LruCache<&str, Arc<Vec<u8>>> = LruCache::new(1);
+        shared.put("x", Arc::new(vec![0; 1024]));
+        let value = shared.get_arc(&"x").unwrap();
+        assert_eq!(Arc::strong_count(&value), 2);
+        shared.put("y", Arc::new(Vec::new()));
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
@@ -0,0 +1,307 @@
+use std::cmp::Reverse;
+use std::fmt;
+use std::hash::Hash;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use crate::cache::{Cache, LruCache};
+use crate::policy::{
+    ArcCache, ClockCache, FifoCache, GdsfCache, LfuCache, LrukCache, MruCache, RandomCache,
+    SampledLruCache, SlruCache, TinyLfuCache, TwoQCache,
+};
+
+/// Politique d'éviction choisie à l'exécution, pour la simulation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyKind {
+    Lru,
+    Fifo,
+    Mru,
+    Lfu,
+    Clock,
+    Arc,
+    TwoQ,
+    Slru,
+    Lruk,
+    Random,
+    SampledLru,
+    TinyLfu,
+    Gdsf,
+}
+
+impl PolicyKind {
+    /// Toutes les politiques disponibles.
+    pub const ALL: [PolicyKind; 13] = [
+        PolicyKind::Lru,
+        PolicyKind::Fifo,
+        PolicyKind::Mru,
+        PolicyKind::Lfu,
+        PolicyKind::Clock,
+        PolicyKind::Arc,
+        PolicyKind::TwoQ,
+        PolicyKind::Slru,
+        PolicyKind::Lruk,
+        PolicyKind::Random,
+        PolicyKind::SampledLru,
+        PolicyKind::TinyLfu,
+        PolicyKind::Gdsf,
+    ];
+
+    /// Nom court, tel qu'accepté par `FromStr`.
+    pub fn name(self) -> &'static str {
+        match self {
+            PolicyKind::Lru => "lru",
+            PolicyKind::Fifo => "fifo",
+            PolicyKind::Mru => "mru",
+            PolicyKind::Lfu => "lfu",
+            PolicyKind::Clock => "clock",
+            PolicyKind::Arc => "arc",
+            PolicyKind::TwoQ => "2q",
+            PolicyKind::Slru => "slru",
+            PolicyKind::Lruk => "lru-k",
+            PolicyKind::Random => "random",
+            PolicyKind::SampledLru => "sampled-lru",
+            PolicyKind::TinyLfu => "tinylfu",
+            PolicyKind::Gdsf => "gdsf",
+        }
+    }
+
+    /// Crée un cache vide de cette politique.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn build<K: Hash + Eq + 'static, V: 'static>(self, capacity: usize) -> Box<dyn Cache<K, V>> {
+        match self {
+            PolicyKind::Lru => Box::new(LruCache::new(capacity)),
+            PolicyKind::Fifo => Box::new(FifoCache::new(capacity)),
+            PolicyKind::Mru => Box::new(MruCache::new(capacity)),
+            PolicyKind::Lfu => Box::new(LfuCache::new(capacity)),
+            PolicyKind::Clock => Box::new(ClockCache::new(capacity)),
+            PolicyKind::Arc => Box::new(ArcCache::new(capacity)),
+            PolicyKind::TwoQ => Box::new(TwoQCache::new(capacity)),
+            PolicyKind::Slru => Box::new(SlruCache::new(capacity)),
+            PolicyKind::Lruk => Box::new(LrukCache::new(capacity)),
+            PolicyKind::Random => Box::new(RandomCache::new(capacity)),
+            PolicyKind::SampledLru => Box::new(SampledLruCache::new(capacity)),
+            PolicyKind::TinyLfu => Box::new(TinyLfuCache::new(capacity)),
+            PolicyKind::Gdsf => Box::new(GdsfCache::new(capacity)),
+        }
+    }
+}
+
+impl fmt::Display for PolicyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for PolicyKind {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.to_ascii_lowercase();
+        PolicyKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| format!("politique inconnue : {name}"))
+    }
+}
+
+/// Résultat du rejeu d'une trace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimulationReport {
+    pub policy: PolicyKind,
+    pub capacity: usize,
+    /// Nombre d'accès rejoués.
+    pub accesses: u64,
+    pub hits: u64,
+    pub misses: u64,
+    /// Entrées évincées pour faire de la place.
+    pub evictions: u64,
+}
+
+impl SimulationReport {
+    /// Proportion d'accès servis par le cache (0 pour une trace vide).
+    pub fn hit_ratio(&self) -> f64 {
+        if self.accesses == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / self.accesses as f64
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<12} capacité {:>8} : {:>6.2} % de hits ({} / {}), {} évictions",
+            self.policy.name(),
+            self.capacity,
+            self.hit_ratio() * 100.0,
+            self.hits,
+            self.accesses,
+            self.evictions
+        )
+    }
+}
+
+/// Lit une trace d'accès : une clé par ligne, ou un CSV `horodatage,clé`.
+///
+/// Les lignes vides et celles commençant par `#` sont ignorées, de même
+/// qu'une ligne d'en-tête CSV (premier champ non numérique en tête de
+/// fichier). Les horodatages ne servent qu'à reconnaître le format : les
+/// accès sont rejoués dans l'ordre du fichier.
+pub fn read_trace(reader: impl BufRead) -> io::Result<Vec<String>> {
+    let mut keys = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once(',') {
+            Some((timestamp, _)) if i == 0 && timestamp.trim().parse::<f64>().is_err() => {}
+            Some((_, key)) => keys.push(key.trim().to_string()),
+            None => keys.push(line.to_string()),
+        }
+    }
+    Ok(keys)
+}
+
+/// Rejoue `trace` sur un cache `policy` de capacité `capacity`, partant
+/// vide : chaque accès est une lecture, suivie d'une insertion en cas de miss.
+///
+/// # Panics
+/// Panique si `capacity` est 0.
+pub fn simulate<K: Hash + Eq + Clone + 'static>(
+    trace: &[K],
+    capacity: usize,
+    policy: PolicyKind,
+) -> SimulationReport {
+    let mut cache = policy.build::<K, ()>(capacity);
+    let mut report = SimulationReport {
+        policy,
+        capacity,
+        accesses: 0,
+        hits: 0,
+        misses: 0,
+        evictions: 0,
+    };
+    for key in trace {
+        report.accesses += 1;
+        if cache.get(key).is_some() {
+            report.hits += 1;
+            continue;
+        }
+        report.misses += 1;
+        let before = cache.len();
+        cache.put(key.clone(), ());
+        if cache.len() == before {
+            report.evictions += 1;
+        }
+    }
+    report
+}
+
+/// Taux de hits de `policy` pour chacune des `capacities` : la courbe
+/// qui aide à dimensionner un cache avant de le déployer.
+pub fn hit_ratio_curve<K: Hash + Eq + Clone + 'static>(
+    trace: &[K],
+    capacities: &[usize],
+    policy: PolicyKind,
+) -> Vec<SimulationReport> {
+    capacities
+        .iter()
+        .map(|&capacity| simulate(trace, capacity, policy))
+        .collect()
+}
+
+/// Résultats de plusieurs politiques sur une même trace, du meilleur taux
+/// de hits au moins bon (voir [`compare_policies`]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComparisonReport {
+    pub results: Vec<SimulationReport>,
+}
+
+impl ComparisonReport {
+    /// Politique au meilleur taux de hits (`None` si aucune n'a été testée).
+    pub fn best(&self) -> Option<&SimulationReport> {
+        self.results.first()
+    }
+
+    /// Résultat d'une politique donnée.
+    pub fn get(&self, policy: PolicyKind) -> Option<&SimulationReport> {
+        self.results.iter().find(|report| report.policy == policy)
+    }
+}
+
+impl fmt::Display for ComparisonReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for report in &self.results {
+            writeln!(f, "{report}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Rejoue la même trace sur chacune des `policies`, pour choisir celle qui
+/// convient le mieux à une charge donnée.
+///
+/// À taux de hits égal, l'ordre de `policies` est conservé.
+///
+/// # Panics
+/// Panique si `capacity` est 0.
+pub fn compare_policies<K: Hash + Eq + Clone + 'static>(
+    trace: &[K],
+    capacity: usize,
+    policies: &[PolicyKind],
+) -> ComparisonReport {
+    let mut results: Vec<SimulationReport> = policies
+        .iter()
+        .map(|&policy| simulate(trace, capacity, policy))
+        .collect();
+    results.sort_by_key(|report| Reverse(report.hits));
+    ComparisonReport { results }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_trace_formats() {
+        let plain = read_trace("a\n\n# commentaire\nb\na\n".as_bytes()).unwrap();
+        assert_eq!(plain, ["a", "b", "a"]);
+        let csv = read_trace("timestamp,key\n1.5,a\n2, b\n".as_bytes()).unwrap();
+        assert_eq!(csv, ["a", "b"]);
+        assert_eq!("2Q".parse::<PolicyKind>(), Ok(PolicyKind::TwoQ));
+        assert!("belady".parse::<PolicyKind>().is_err());
+    }
+
+    #[test]
+    fn test_simulate_lru_and_curve() {
+        let trace = ["a", "b", "c", "a", "d", "a", "b"];
+        let report = simulate(&trace, 2, PolicyKind::Lru);
+        // Seul le second `a` après `d` trouve encore son entrée.
+        assert_eq!((report.hits, report.misses, report.evictions), (1, 6, 4));
+
+        let curve = hit_ratio_curve(&trace, &[1, 3, 4], PolicyKind::Lru);
+        let hits: Vec<u64> = curve.iter().map(|report| report.hits).collect();
+        assert_eq!(hits, [0, 2, 3]);
+        assert!((curve[2].hit_ratio() - 3.0 / 7.0).abs() < 1e-9);
+        for kind in PolicyKind::ALL {
+            assert_eq!(simulate(&trace, 3, kind).accesses, 7);
+        }
+    }
+
+    #[test]
+    fn test_compare_policies() {
+        // Un balayage cyclique plus grand que le cache : LRU et FIFO ne
+        // trouvent jamais rien, MRU garde une partie du cycle.
+        let trace: Vec<u32> = (0..10).cycle().take(100).collect();
+        let report = compare_policies(&trace, 5, &[PolicyKind::Lru, PolicyKind::Fifo, PolicyKind::Mru]);
+        assert_eq!(report.best().map(|best| best.policy), Some(PolicyKind::Mru));
+        assert_eq!(report.get(PolicyKind::Lru).map(|lru| lru.hits), Some(0));
+        assert_eq!(report.results[1].policy, PolicyKind::Lru);
+        assert_eq!(report.to_string().lines().count(), 3);
+    }
+}
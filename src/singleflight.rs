@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+
+/// État d'un calcul en cours pour une clé.
+enum State<V> {
+    Running,
+    Done(V),
+    /// Le calcul a paniqué : un des appelants en attente prend le relais.
+    Abandoned,
+}
+
+struct Flight<V> {
+    state: Mutex<State<V>>,
+    done: Condvar,
+}
+
+/// Déduplication des calculs concurrents par clé (protection contre le
+/// *cache stampede*).
+///
+/// Quand plusieurs threads ratent la même clé en même temps, un seul exécute
+/// le calcul ; les autres attendent et reçoivent un clone de son résultat.
+/// C'est la brique utilisée par les variantes concurrentes du cache pour
+/// leur `get_or_compute`, mais elle peut protéger n'importe quelle source.
+///
+/// # Exemple
+/// ```
+/// use cache_lru_project::SingleFlight;
+///
+/// let flights: SingleFlight<&str, u32> = SingleFlight::new();
+/// assert_eq!(flights.run(&"k", || 42), 42);
+/// ```
+pub struct SingleFlight<K, V> {
+    inflight: Mutex<HashMap<K, Arc<Flight<V>>>>,
+}
+
+impl<K, V> SingleFlight<K, V> {
+    /// Crée un groupe sans calcul en cours.
+    pub fn new() -> Self {
+        SingleFlight {
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K, V> SingleFlight<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Exécute `compute` pour `key`, sauf si un calcul est déjà en cours
+    /// pour cette clé : on attend alors son résultat.
+    ///
+    /// Si le calcul en cours panique, un des appelants en attente le relance.
+    pub fn run(&self, key: &K, compute: impl FnOnce() -> V) -> V {
+        let mut compute = Some(compute);
+        loop {
+            let (flight, leader) = {
+                let mut inflight = self.inflight.lock().unwrap_or_else(PoisonError::into_inner);
+                match inflight.get(key) {
+                    Some(flight) => (Arc::clone(flight), false),
+                    None => {
+                        let flight = Arc::new(Flight {
+                            state: Mutex::new(State::Running),
+                            done: Condvar::new(),
+                        });
+                        inflight.insert(key.clone(), Arc::clone(&flight));
+                        (flight, true)
+                    }
+                }
+            };
+
+            if leader {
+                let guard = LeaderGuard {
+                    group: self,
+                    key,
+                    flight: &flight,
+                };
+                let value = (compute.take().expect("calcul déjà consommé"))();
+                guard.complete(value.clone());
+                return value;
+            }
+
+            let mut state = flight.state.lock().unwrap_or_else(PoisonError::into_inner);
+            while matches!(*state, State::Running) {
+                state = flight.done.wait(state).unwrap_or_else(PoisonError::into_inner);
+            }
+            if let State::Done(value) = &*state {
+                return value.clone();
+            }
+            // Calcul abandonné : on retente, éventuellement en tant que leader.
+        }
+    }
+
+    /// Nombre de clés en cours de calcul.
+    pub fn in_flight(&self) -> usize {
+        self.inflight.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+}
+
+impl<K, V> Default for SingleFlight<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Publie le résultat du leader, ou signale l'abandon s'il panique.
+struct LeaderGuard<'a, K: Hash + Eq, V> {
+    group: &'a SingleFlight<K, V>,
+    key: &'a K,
+    flight: &'a Flight<V>,
+}
+
+impl<K: Hash + Eq, V> LeaderGuard<'_, K, V> {
+    fn complete(self, value: V) {
+        self.finish(State::Done(value));
+        std::mem::forget(self);
+    }
+
+    fn finish(&self, outcome: State<V>) {
+        self.group
+            .inflight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(self.key);
+        *self.flight.state.lock().unwrap_or_else(PoisonError::into_inner) = outcome;
+        self.flight.done.notify_all();
+    }
+}
+
+impl<K: Hash + Eq, V> Drop for LeaderGuard<'_, K, V> {
+    fn drop(&mut self) {
+        self.finish(State::Abandoned);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_concurrent_misses_compute_once() {
+        let flights = Arc::new(SingleFlight::new());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(8));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (flights, calls, barrier) = (flights.clone(), calls.clone(), barrier.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    flights.run(&"k", || {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(50));
+                        7
+                    })
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 7);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(flights.in_flight(), 0);
+    }
+
+    #[test]
+    fn test_panicking_leader_is_replaced() {
+        let flights: SingleFlight<&str, u32> = SingleFlight::new();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            flights.run(&"k", || panic!("source indisponible"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(flights.in_flight(), 0);
+        assert_eq!(flights.run(&"k", || 3), 3);
+    }
+}
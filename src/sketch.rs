@@ -0,0 +1,146 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+
+/// Sketch count-min à compteurs saturants, avec vieillissement périodique.
+///
+/// Estime à moindre coût la popularité récente des clés qui traversent un
+/// cache, y compris celles qui n'y sont pas (ou plus) : c'est le filtre
+/// d'admission de [`TinyLfuCache`](crate::TinyLfuCache), utilisable seul.
+///
+/// # Architecture
+/// * **Table** : `DEPTH` lignes de `width` compteurs `u8` (saturés à 15).
+/// * **Index** : Chaque ligne dérive sa colonne du hash via un mélange distinct.
+/// * **Estimation** : Minimum des `DEPTH` compteurs (jamais sous-estimée).
+/// * **Vieillissement** : Après `sample_size` incréments, tous les compteurs
+///   sont divisés par deux, pour que la popularité passée s'estompe.
+///
+/// # Exemple
+/// ```
+/// use cache_lru_project::FrequencySketch;
+///
+/// let mut sketch = FrequencySketch::with_capacity(1000);
+/// for _ in 0..3 {
+///     sketch.increment("populaire");
+/// }
+/// sketch.increment("rare");
+/// assert!(sketch.frequency("populaire") >= 3);
+/// assert!(sketch.frequency("populaire") > sketch.frequency("rare"));
+/// ```
+pub struct FrequencySketch {
+    hasher: RandomState,
+    table: Vec<u8>,
+    width: usize,
+    additions: usize,
+    sample_size: usize,
+}
+
+const DEPTH: usize = 4;
+const MAX_COUNT: u8 = 15;
+const SEEDS: [u64; DEPTH] = [
+    0x9E37_79B9_7F4A_7C15,
+    0xC2B2_AE3D_27D4_EB4F,
+    0x1656_67B1_9E37_79F9,
+    0x27D4_EB2F_1656_67C5,
+];
+
+impl FrequencySketch {
+    /// Crée un sketch dimensionné pour suivre environ `capacity` clés actives.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let width = capacity.max(16).next_power_of_two();
+        FrequencySketch {
+            hasher: RandomState::new(),
+            table: vec![0; width * DEPTH],
+            width,
+            additions: 0,
+            sample_size: 10 * capacity.max(16),
+        }
+    }
+
+    /// Enregistre une occurrence de `key`.
+    pub fn increment<Q: Hash + ?Sized>(&mut self, key: &Q) {
+        self.increment_hash(self.hasher.hash_one(key));
+    }
+
+    /// Estime le nombre d'occurrences récentes de `key` (au plus 15).
+    pub fn frequency<Q: Hash + ?Sized>(&self, key: &Q) -> u8 {
+        self.estimate_hash(self.hasher.hash_one(key))
+    }
+
+    /// Enregistre une occurrence d'un hash déjà calculé par l'appelant.
+    pub fn increment_hash(&mut self, hash: u64) {
+        let mut changed = false;
+        for row in 0..DEPTH {
+            let cell = self.cell(row, hash);
+            if self.table[cell] < MAX_COUNT {
+                self.table[cell] += 1;
+                changed = true;
+            }
+        }
+        if changed {
+            self.additions += 1;
+            if self.additions >= self.sample_size {
+                self.halve();
+            }
+        }
+    }
+
+    /// Estime le nombre d'occurrences récentes d'un hash déjà calculé.
+    pub fn estimate_hash(&self, hash: u64) -> u8 {
+        (0..DEPTH)
+            .map(|row| self.table[self.cell(row, hash)])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Divise tous les compteurs par deux (fait automatiquement tous les
+    /// `10 × capacity` incréments).
+    pub fn halve(&mut self) {
+        for counter in &mut self.table {
+            *counter >>= 1;
+        }
+        self.additions /= 2;
+    }
+
+    /// Remet tous les compteurs à zéro.
+    pub fn clear(&mut self) {
+        self.table.fill(0);
+        self.additions = 0;
+    }
+
+    fn cell(&self, row: usize, hash: u64) -> usize {
+        let mut h = (hash ^ SEEDS[row]).wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+        h ^= h >> 33;
+        row * self.width + (h as usize & (self.width - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frequency_never_underestimates() {
+        let mut sketch = FrequencySketch::with_capacity(100);
+        for key in 0..50u32 {
+            for _ in 0..key % 8 {
+                sketch.increment(&key);
+            }
+        }
+        for key in 0..50u32 {
+            assert!(sketch.frequency(&key) >= (key % 8) as u8);
+        }
+    }
+
+    #[test]
+    fn test_counters_saturate_and_age() {
+        let mut sketch = FrequencySketch::with_capacity(16);
+        for _ in 0..40 {
+            sketch.increment("clé");
+        }
+        assert_eq!(sketch.frequency("clé"), MAX_COUNT);
+        sketch.halve();
+        assert_eq!(sketch.frequency("clé"), MAX_COUNT / 2);
+        sketch.clear();
+        assert_eq!(sketch.frequency("clé"), 0);
+    }
+}
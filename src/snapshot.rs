@@ -0,0 +1,118 @@
+use std::hash::Hash;
+
+use crate::cache::{Cache, LruCache};
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Copie des entrées non expirées, de la moins récente (Tail) à la plus
+    /// récente (Head) : réinsérées dans cet ordre, elles retrouvent leur récence.
+    ///
+    /// Avec [`from_pairs`](Self::from_pairs), c'est le point d'appui pour
+    /// intégrer le cache dans un état sérialisé par serde :
+    /// ```ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Snapshot<K, V> {
+    ///     capacity: usize,
+    ///     entries: Vec<(K, V)>,
+    /// }
+    ///
+    /// let snapshot = Snapshot { capacity: cache.capacity(), entries: cache.to_vec() };
+    /// let json = serde_json::to_string(&snapshot)?;
+    /// let snapshot: Snapshot<String, u64> = serde_json::from_str(&json)?;
+    /// let cache = LruCache::from_pairs(snapshot.capacity, snapshot.entries);
+    /// ```
+    pub fn to_vec(&self) -> Vec<(K, V)>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.iter()
+            .rev()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    /// Crée un cache à partir de paires ordonnées de la moins récente à la
+    /// plus récente (le format de [`to_vec`](Self::to_vec)).
+    ///
+    /// Au-delà de `capacity`, les premières paires sont évincées.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn from_pairs(capacity: usize, pairs: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut cache = LruCache::new(capacity);
+        for (key, value) in pairs {
+            cache.put(key, value);
+        }
+        cache
+    }
+
+    /// Pré-remplit le cache avec des entrées classées de la plus chaude à
+    /// la plus froide (ex: une liste de clés populaires connue au démarrage).
+    ///
+    /// Les entrées sont insérées de la plus froide à la plus chaude, pour
+    /// que les plus chaudes finissent en Tête ; seules les `capacity`
+    /// premières sont retenues. Les clés déjà présentes ne sont pas
+    /// remplacées, et le [`doorkeeper`](crate::LruCacheBuilder::doorkeeper)
+    /// ne filtre pas ces entrées. Retourne le nombre d'entrées insérées.
+    pub fn warm(&mut self, entries: impl IntoIterator<Item = (K, V)>) -> usize {
+        let hottest: Vec<_> = entries.into_iter().take(self.capacity()).collect();
+        self.without_admission(|cache| {
+            let mut inserted = 0;
+            for (key, value) in hottest.into_iter().rev() {
+                if cache.put_if_absent(key, value).is_none() {
+                    inserted += 1;
+                }
+            }
+            inserted
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_preserves_order() {
+        let mut cache = LruCache::new(3);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        cache.get(&"a");
+        let pairs = cache.to_vec();
+        assert_eq!(pairs, vec![("b", 2), ("c", 3), ("a", 1)]);
+
+        let mut restored = LruCache::from_pairs(3, pairs);
+        restored.put("d", 4);
+        assert!(!restored.contains(&"b"));
+        assert_eq!(restored.to_vec(), vec![("c", 3), ("a", 1), ("d", 4)]);
+    }
+
+    #[test]
+    fn test_from_pairs_keeps_most_recent() {
+        let cache = LruCache::from_pairs(2, (0..5).map(|i| (i, i)));
+        assert_eq!(cache.to_vec(), vec![(3, 3), (4, 4)]);
+    }
+
+    #[test]
+    fn test_warm_puts_hottest_at_head() {
+        let mut cache = LruCache::new(3);
+        cache.put("b", 0);
+        let hot = [("a", 1), ("b", 2), ("c", 3), ("d", 4)];
+        assert_eq!(cache.warm(hot), 2);
+        assert_eq!(cache.to_vec(), vec![("b", 0), ("c", 3), ("a", 1)]);
+    }
+
+    #[test]
+    fn test_warm_bypasses_doorkeeper() {
+        let mut cache = LruCache::builder(3).doorkeeper(100).build();
+        assert_eq!(cache.warm([("a", 1), ("b", 2)]), 2);
+        assert_eq!(cache.to_vec(), vec![("b", 2), ("a", 1)]);
+        // Le filtre reste actif pour les écritures ordinaires.
+        cache.put("c", 3);
+        assert!(!cache.contains(&"c"));
+    }
+}
@@ -0,0 +1,144 @@
+use std::hash::Hash;
+
+use crate::cache::LruCache;
+
+/// Compteurs d'activité d'un cache (voir [`LruCacheBuilder::record_stats`](crate::LruCacheBuilder::record_stats)).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lectures ayant trouvé une entrée valide.
+    pub hits: u64,
+    /// Lectures n'ayant trouvé aucune entrée (ou une entrée expirée).
+    pub misses: u64,
+    /// Écritures d'une nouvelle clé.
+    pub insertions: u64,
+    /// Écritures d'une clé déjà présente.
+    pub updates: u64,
+    /// Entrées évincées faute de place.
+    pub evictions: u64,
+    /// Entrées retirées parce qu'expirées.
+    pub expirations: u64,
+    /// Misses sur des clés récemment évincées, qu'un cache plus grand
+    /// aurait servis (voir [`LruCacheBuilder::ghost_capacity`](crate::LruCacheBuilder::ghost_capacity)).
+    pub ghost_hits: u64,
+}
+
+/// Événement comptabilisé par les statistiques.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Stat {
+    Hit,
+    Miss,
+    Insertion,
+    Update,
+    Eviction,
+    Expiration,
+    GhostHit,
+}
+
+impl CacheStats {
+    fn apply(&mut self, stat: Stat) {
+        let counter = match stat {
+            Stat::Hit => &mut self.hits,
+            Stat::Miss => &mut self.misses,
+            Stat::Insertion => &mut self.insertions,
+            Stat::Update => &mut self.updates,
+            Stat::Eviction => &mut self.evictions,
+            Stat::Expiration => &mut self.expirations,
+            Stat::GhostHit => &mut self.ghost_hits,
+        };
+        *counter += 1;
+    }
+
+    /// Nombre total de lectures.
+    pub fn requests(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// Proportion de lectures réussies, entre 0 et 1 (0 sans aucune lecture).
+    pub fn hit_rate(&self) -> f64 {
+        match self.requests() {
+            0 => 0.0,
+            requests => self.hits as f64 / requests as f64,
+        }
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Statistiques accumulées depuis la création ou le dernier [`reset_stats`](Self::reset_stats).
+    ///
+    /// Tous les compteurs restent à zéro si le cache n'a pas été construit
+    /// avec [`record_stats`](crate::LruCacheBuilder::record_stats).
+    pub fn stats(&self) -> CacheStats {
+        self.stats.unwrap_or_default()
+    }
+
+    /// Remet les compteurs à zéro.
+    pub fn reset_stats(&mut self) {
+        if let Some(stats) = self.stats.as_mut() {
+            *stats = CacheStats::default();
+        }
+        if let Some(ghosts) = self.ghosts.as_mut() {
+            ghosts.reset_hits();
+        }
+    }
+
+    /// Comptabilise un événement, si le suivi des statistiques (ou des métriques) est activé.
+    pub(crate) fn record(&mut self, stat: Stat) {
+        if let Some(stats) = self.stats.as_mut() {
+            stats.apply(stat);
+        }
+        #[cfg(feature = "metrics")]
+        self.emit(stat);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cache, MockClock};
+    use std::time::Duration;
+
+    #[test]
+    fn test_counters_track_activity() {
+        let clock = MockClock::new();
+        let mut cache = LruCache::builder(2).record_stats().clock(clock.clone()).build();
+        cache.put("a", 1);
+        cache.put("a", 2);
+        cache.put("b", 3);
+        cache.put("c", 4);
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(&3));
+        cache.put_with_ttl("d", 5, Duration::from_secs(1));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(cache.get(&"d"), None);
+
+        let stats = cache.stats();
+        assert_eq!(
+            stats,
+            CacheStats {
+                hits: 1,
+                misses: 2,
+                insertions: 4,
+                updates: 1,
+                evictions: 2,
+                expirations: 1,
+                ghost_hits: 0,
+            }
+        );
+        assert!((stats.hit_rate() - 1.0 / 3.0).abs() < 1e-9);
+
+        cache.reset_stats();
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.get(&"a");
+        assert_eq!(cache.stats(), CacheStats::default());
+        assert_eq!(cache.stats().hit_rate(), 0.0);
+    }
+}
@@ -0,0 +1,298 @@
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::cache::{Cache, LruCache};
+
+/// Stockage durable derrière un cache (fichier, base de données, service distant...).
+pub trait Store<K, V> {
+    /// Erreur remontée par le stockage.
+    type Error;
+
+    /// Enregistre `value` sous `key`.
+    fn write(&mut self, key: &K, value: &V) -> Result<(), Self::Error>;
+
+    /// Supprime `key` du stockage. Supprimer une clé absente n'est pas une erreur.
+    fn delete(&mut self, key: &K) -> Result<(), Self::Error>;
+}
+
+/// Cache en écriture traversante (*write-through*).
+///
+/// Chaque écriture est d'abord appliquée au [`Store`] ; le cache n'est mis
+/// à jour qu'en cas de succès. Le cache ne contient donc jamais de donnée
+/// absente du stockage (l'inverse reste possible : le cache est borné).
+pub struct WriteThrough<K, V, S> {
+    cache: LruCache<K, V>,
+    store: S,
+}
+
+impl<K, V, S> WriteThrough<K, V, S>
+where
+    K: Hash + Eq,
+    S: Store<K, V>,
+{
+    /// Associe un cache à son stockage.
+    pub fn new(cache: LruCache<K, V>, store: S) -> Self {
+        WriteThrough { cache, store }
+    }
+
+    /// Écrit dans le stockage puis, si l'écriture réussit, dans le cache.
+    pub fn put(&mut self, key: K, value: V) -> Result<(), S::Error> {
+        self.store.write(&key, &value)?;
+        self.cache.put(key, value);
+        Ok(())
+    }
+
+    /// Supprime la clé du stockage puis du cache.
+    ///
+    /// En cas d'erreur du stockage, le cache est laissé intact.
+    pub fn remove(&mut self, key: &K) -> Result<Option<V>, S::Error> {
+        self.store.delete(key)?;
+        Ok(self.cache.remove(key))
+    }
+
+    /// Lit une valeur en cache (voir [`Cache::get`]).
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Cache sous-jacent, en lecture seule : toute écriture doit passer par le stockage.
+    pub fn cache(&self) -> &LruCache<K, V> {
+        &self.cache
+    }
+
+    /// Stockage sous-jacent.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Sépare le cache de son stockage.
+    pub fn into_parts(self) -> (LruCache<K, V>, S) {
+        (self.cache, self.store)
+    }
+}
+
+/// Nombre d'entrées modifiées au-delà duquel [`WriteBehind`] écrit d'office.
+pub const DEFAULT_BATCH_SIZE: usize = 64;
+
+/// Cache en écriture différée (*write-behind*).
+///
+/// `put` ne touche que le cache et marque l'entrée comme modifiée ; les
+/// modifications sont écrites par lots dans le [`Store`] :
+/// * quand `batch_size` entrées modifiées sont en attente ;
+/// * quand `flush_interval` est écoulé depuis la dernière écriture (vérifié à chaque `put`) ;
+/// * lors d'un appel explicite à [`flush`](Self::flush), et à la destruction.
+///
+/// Une entrée modifiée évincée ou expirée est écrite avant la fin du `put`
+/// qui l'a chassée ; en cas d'échec du stockage elle reste en attente.
+pub struct WriteBehind<K, V, S: Store<K, V>> {
+    cache: LruCache<K, V>,
+    store: S,
+    /// Entrées modifiées pas encore écrites (dans le cache ou mises de côté).
+    pending: usize,
+    batch_size: usize,
+    flush_interval: Option<Duration>,
+    last_flush: Instant,
+}
+
+impl<K, V, S> WriteBehind<K, V, S>
+where
+    K: Hash + Eq,
+    S: Store<K, V>,
+{
+    /// Associe un cache à son stockage, avec un lot de [`DEFAULT_BATCH_SIZE`].
+    pub fn new(cache: LruCache<K, V>, store: S) -> Self {
+        let last_flush = cache.clock.now();
+        WriteBehind {
+            cache,
+            store,
+            pending: 0,
+            batch_size: DEFAULT_BATCH_SIZE,
+            flush_interval: None,
+            last_flush,
+        }
+    }
+
+    /// Écrit d'office dès que `batch_size` entrées modifiées sont en attente.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Écrit d'office au premier `put` survenant `interval` après la dernière écriture.
+    pub fn with_flush_interval(mut self, interval: Duration) -> Self {
+        self.flush_interval = Some(interval);
+        self
+    }
+
+    /// Met à jour le cache et marque l'entrée comme modifiée.
+    ///
+    /// L'erreur éventuelle provient d'une écriture par lot déclenchée par
+    /// ce `put` ; la nouvelle valeur est, elle, toujours conservée.
+    pub fn put(&mut self, key: K, value: V) -> Result<(), S::Error> {
+        self.cache.put(key, value);
+        if let Some(head) = self.cache.head
+            && !std::mem::replace(&mut self.cache.arena[head].dirty, true)
+        {
+            self.pending += 1;
+        }
+        self.write_evicted()?;
+
+        let interval_elapsed = self
+            .flush_interval
+            .is_some_and(|interval| self.cache.clock.now() >= self.last_flush + interval);
+        if self.pending >= self.batch_size || interval_elapsed {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Lit une valeur en cache (voir [`Cache::get`]).
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Supprime la clé du stockage puis du cache, modification en attente comprise.
+    pub fn remove(&mut self, key: &K) -> Result<Option<V>, S::Error> {
+        self.store.delete(key)?;
+        let before = self.cache.evicted_dirty.len();
+        self.cache.evicted_dirty.retain(|(k, _)| k != key);
+        self.pending -= before - self.cache.evicted_dirty.len();
+        if self.cache.find(key).is_some_and(|i| self.cache.arena[i].dirty) {
+            self.pending -= 1;
+        }
+        Ok(self.cache.remove(key))
+    }
+
+    /// Écrit toutes les modifications en attente et retourne leur nombre.
+    ///
+    /// En cas d'erreur, les entrées non écrites restent en attente.
+    pub fn flush(&mut self) -> Result<usize, S::Error> {
+        let mut written = self.write_evicted()?;
+        for index in 0..self.cache.arena.len() {
+            let node = &mut self.cache.arena[index];
+            if node.dirty {
+                self.store.write(&node.key, &node.value)?;
+                node.dirty = false;
+                self.pending -= 1;
+                written += 1;
+            }
+        }
+        self.last_flush = self.cache.clock.now();
+        Ok(written)
+    }
+
+    /// Nombre d'entrées modifiées pas encore écrites dans le stockage.
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// Cache sous-jacent, en lecture seule.
+    pub fn cache(&self) -> &LruCache<K, V> {
+        &self.cache
+    }
+
+    /// Stockage sous-jacent.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Écrit les entrées modifiées sorties du cache.
+    fn write_evicted(&mut self) -> Result<usize, S::Error> {
+        let mut written = 0;
+        while let Some((key, value)) = self.cache.evicted_dirty.last() {
+            self.store.write(key, value)?;
+            self.cache.evicted_dirty.pop();
+            self.pending -= 1;
+            written += 1;
+        }
+        Ok(written)
+    }
+}
+
+impl<K, V, S: Store<K, V>> Drop for WriteBehind<K, V, S> {
+    /// Dernière tentative d'écriture ; les erreurs ne peuvent plus être remontées.
+    fn drop(&mut self) {
+        for (key, value) in self.cache.evicted_dirty.drain(..) {
+            let _ = self.store.write(&key, &value);
+        }
+        for node in self.cache.arena.iter().filter(|node| node.dirty) {
+            let _ = self.store.write(&node.key, &node.value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Stockage en mémoire qui peut être mis en panne.
+    #[derive(Default)]
+    struct MapStore {
+        data: HashMap<u32, u32>,
+        down: bool,
+    }
+
+    impl Store<u32, u32> for MapStore {
+        type Error = &'static str;
+
+        fn write(&mut self, key: &u32, value: &u32) -> Result<(), Self::Error> {
+            if self.down {
+                return Err("stockage indisponible");
+            }
+            self.data.insert(*key, *value);
+            Ok(())
+        }
+
+        fn delete(&mut self, key: &u32) -> Result<(), Self::Error> {
+            if self.down {
+                return Err("stockage indisponible");
+            }
+            self.data.remove(key);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_writes_reach_store_first() {
+        let mut cache = WriteThrough::new(LruCache::new(1), MapStore::default());
+        cache.put(1, 10).unwrap();
+        cache.put(2, 20).unwrap();
+        // Évincé du cache, mais conservé par le stockage.
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.store().data.get(&1), Some(&10));
+
+        assert_eq!(cache.remove(&2), Ok(Some(20)));
+        assert!(!cache.store().data.contains_key(&2));
+    }
+
+    #[test]
+    fn test_failed_write_leaves_cache_untouched() {
+        let store = MapStore {
+            down: true,
+            ..MapStore::default()
+        };
+        let mut cache = WriteThrough::new(LruCache::new(4), store);
+        assert_eq!(cache.put(1, 10), Err("stockage indisponible"));
+        assert!(cache.cache().is_empty());
+    }
+
+    #[test]
+    fn test_write_behind_batches_and_flushes_evictions() {
+        let mut cache = WriteBehind::new(LruCache::new(2), MapStore::default()).with_batch_size(3);
+        cache.put(1, 10).unwrap();
+        cache.put(1, 11).unwrap();
+        assert_eq!(cache.pending(), 1);
+        assert!(cache.store().data.is_empty());
+
+        cache.put(2, 20).unwrap();
+        // L'éviction de 1 (modifiée) l'écrit immédiatement.
+        cache.put(3, 30).unwrap();
+        assert_eq!(cache.store().data.get(&1), Some(&11));
+        assert_eq!(cache.pending(), 2);
+
+        assert_eq!(cache.flush(), Ok(2));
+        assert_eq!(cache.pending(), 0);
+        assert_eq!(cache.store().data.len(), 3);
+    }
+}
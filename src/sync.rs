@@ -0,0 +1,354 @@
+use std::hash::Hash;
+use std::ops::Deref;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::autosave::FinalSave;
+use crate::cache::{Cache, LruCache};
+use crate::singleflight::SingleFlight;
+
+/// Version partageable entre threads d'un [`LruCache`].
+///
+/// Toutes les méthodes prennent `&self` : il suffit de placer le cache
+/// dans un `Arc`. Un `RwLock` protège le cache ; comme une lecture modifie
+/// la récence, `get` prend le verrou exclusif, sauf si le cache a été
+/// construit avec [`buffered_reads`](crate::LruCacheBuilder::buffered_reads) :
+/// les lectures se font alors en parallèle sous le verrou partagé.
+///
+/// Les lectures retournent un clone de la valeur : pour des valeurs
+/// coûteuses à cloner, stocker des `Arc<V>` ou lire via
+/// [`get_guard`](Self::get_guard).
+///
+/// # Exemple
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use cache_lru_project::SyncLruCache;
+///
+/// let cache = Arc::new(SyncLruCache::new(100));
+/// let writer = Arc::clone(&cache);
+/// thread::spawn(move || writer.put("k", 1)).join().unwrap();
+/// assert_eq!(cache.get(&"k"), Some(1));
+/// ```
+pub struct SyncLruCache<K, V> {
+    /// Sauvegarde finale de la sauvegarde périodique éventuelle ; déclarée
+    /// avant `inner` pour être détruite (et exécutée) avant le cache.
+    final_save: Option<FinalSave>,
+    /// Partagé (faiblement) avec le thread de sauvegarde périodique éventuel.
+    inner: Arc<RwLock<LruCache<K, V>>>,
+    /// Lectures sous verrou partagé (le cache mémorise ses accès).
+    shared_reads: bool,
+    flights: SingleFlight<K, V>,
+}
+
+impl<K: Hash + Eq, V> SyncLruCache<K, V> {
+    /// Crée un cache partagé vide.
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn new(capacity: usize) -> Self {
+        Self::from_cache(LruCache::new(capacity))
+    }
+
+    /// Partage un cache existant (ex: construit via [`LruCache::builder`]).
+    ///
+    /// Démarre la sauvegarde périodique si le cache a été construit avec
+    /// [`auto_save_every`](crate::LruCacheBuilder::auto_save_every).
+    pub fn from_cache(cache: LruCache<K, V>) -> Self {
+        let shared_reads = cache.reads.is_some();
+        let inner = Arc::new(RwLock::new(cache));
+        let final_save = {
+            let cache = inner.read().unwrap_or_else(PoisonError::into_inner);
+            let auto_save = cache.save_target.as_ref().and_then(|target| target.auto_save.as_ref());
+            auto_save.map(|auto_save| auto_save.start(&inner))
+        };
+        SyncLruCache {
+            final_save,
+            shared_reads,
+            inner,
+            flights: SingleFlight::new(),
+        }
+    }
+
+    /// Verrouille le cache en écriture, pour une séquence d'opérations atomique.
+    ///
+    /// Un verrou empoisonné (panique d'un autre thread) est récupéré : le
+    /// cache reste cohérent entre deux opérations.
+    pub fn write(&self) -> RwLockWriteGuard<'_, LruCache<K, V>> {
+        self.inner.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Verrouille le cache en lecture partagée.
+    pub fn read(&self) -> RwLockReadGuard<'_, LruCache<K, V>> {
+        self.inner.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Comme [`get`](Self::get), mais sans cloner la valeur : le guard
+    /// retourné donne accès à `&V` en tenant le verrou partagé du cache.
+    ///
+    /// Les écritures attendent la destruction du guard : le garder le moins
+    /// longtemps possible. Sans `buffered_reads`, la promotion se fait sous
+    /// le verrou exclusif, rétrogradé ensuite en verrou partagé.
+    pub fn get_guard(&self, key: &K) -> Option<ValueGuard<'_, K, V>> {
+        let (cache, index) = if self.shared_reads {
+            let cache = self.read();
+            let index = cache.find_shared(key)?;
+            (cache, index)
+        } else {
+            let mut cache = self.write();
+            let index = cache.find_live(key)?;
+            cache.promote(index);
+            (RwLockWriteGuard::downgrade(cache), index)
+        };
+        Some(ValueGuard { cache, index })
+    }
+}
+
+impl<K, V> SyncLruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Retourne un clone de la valeur et la marque comme récente.
+    pub fn get(&self, key: &K) -> Option<V> {
+        if self.shared_reads {
+            self.read().get_shared(key).cloned()
+        } else {
+            self.write().get(key).cloned()
+        }
+    }
+
+    /// Retourne un clone de la valeur sans modifier la récence.
+    pub fn peek(&self, key: &K) -> Option<V> {
+        self.read().peek(key).cloned()
+    }
+
+    /// Insère ou met à jour une valeur.
+    pub fn put(&self, key: K, value: V) {
+        self.write().put(key, value);
+    }
+
+    /// Insère `value` seulement si la clé est absente, sous un seul verrou.
+    ///
+    /// Retourne un clone de la valeur déjà présente (voir [`LruCache::put_if_absent`]).
+    pub fn put_if_absent(&self, key: K, value: V) -> Option<V> {
+        self.write().put_if_absent(key, value).cloned()
+    }
+
+    /// Remplace la valeur de `key` par `new` si elle vaut `expected`, sous
+    /// un seul verrou (voir [`LruCache::compare_and_swap`]).
+    pub fn compare_and_swap(&self, key: K, expected: &V, new: V) -> Result<(), V>
+    where
+        V: PartialEq,
+    {
+        self.write().compare_and_swap(key, expected, new)
+    }
+
+    /// Modifie la valeur de `key` en place sous le verrou exclusif
+    /// (voir [`LruCache::modify`]).
+    pub fn modify(&self, key: &K, f: impl FnOnce(&mut V)) -> bool {
+        self.write().modify(key, f)
+    }
+
+    /// Supprime une clé et retourne sa valeur.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        self.write().remove(key)
+    }
+
+    /// Indique si la clé est présente.
+    pub fn contains(&self, key: &K) -> bool {
+        self.read().contains(key)
+    }
+
+    /// Vide le cache.
+    pub fn clear(&self) {
+        self.write().clear();
+    }
+
+    /// Nombre d'entrées.
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Indique si le cache est vide.
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Capacité maximale.
+    pub fn capacity(&self) -> usize {
+        self.read().capacity()
+    }
+
+    /// Retourne la valeur de `key`, en la calculant avec `compute` si absente.
+    ///
+    /// Les échecs simultanés sur une même clé ne lancent qu'un seul calcul
+    /// (voir [`SingleFlight`]) ; le verrou du cache n'est pas tenu pendant
+    /// le calcul, les autres clés restent donc accessibles.
+    pub fn get_or_compute(&self, key: K, compute: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        self.flights.run(&key, || {
+            // Un calcul concurrent a pu se terminer entre-temps.
+            if let Some(value) = self.get(&key) {
+                return value;
+            }
+            let value = compute();
+            self.put(key.clone(), value.clone());
+            value
+        })
+    }
+
+    /// Récupère le cache, une fois qu'il n'est plus partagé.
+    ///
+    /// La sauvegarde finale d'une sauvegarde périodique n'a alors pas lieu :
+    /// appeler [`save`](LruCache::save) si besoin.
+    pub fn into_inner(self) -> LruCache<K, V> {
+        if let Some(final_save) = self.final_save {
+            final_save.disarm();
+        }
+        let mut inner = self.inner;
+        // Le thread de sauvegarde ne garde une référence forte que le temps d'un instantané.
+        loop {
+            match Arc::try_unwrap(inner) {
+                Ok(lock) => return lock.into_inner().unwrap_or_else(PoisonError::into_inner),
+                Err(shared) => {
+                    inner = shared;
+                    std::thread::yield_now();
+                }
+            }
+        }
+    }
+}
+
+/// Valeur lue sans clonage dans un [`SyncLruCache`] (voir
+/// [`get_guard`](SyncLruCache::get_guard)) ; tient le verrou partagé.
+pub struct ValueGuard<'a, K, V> {
+    cache: RwLockReadGuard<'a, LruCache<K, V>>,
+    index: usize,
+}
+
+impl<K, V> Deref for ValueGuard<'_, K, V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.cache.arena[self.index].value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::Barrier;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_shared_across_threads() {
+        let cache = Arc::new(SyncLruCache::new(1000));
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for i in 0..100 {
+                        cache.put(t * 100 + i, i);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(cache.len(), 400);
+        assert_eq!(cache.get(&205), Some(5));
+    }
+
+    #[test]
+    fn test_get_or_compute_deduplicates_misses() {
+        let cache = Arc::new(SyncLruCache::new(10));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(8));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (cache, calls, barrier) = (cache.clone(), calls.clone(), barrier.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    cache.get_or_compute("k", || {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(50));
+                        42
+                    })
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 42);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.peek(&"k"), Some(42));
+    }
+
+    #[test]
+    fn test_buffered_reads_run_under_shared_lock() {
+        let cache = Arc::new(SyncLruCache::from_cache(
+            LruCache::builder(2).buffered_reads().build(),
+        ));
+        cache.put("a", 1);
+        cache.put("b", 2);
+        // Une lecture ne bloque pas un lecteur déjà présent.
+        let guard = cache.read();
+        assert_eq!(cache.get(&"a"), Some(1));
+        drop(guard);
+        // La lecture de "a" a été prise en compte avant l'éviction.
+        cache.put("c", 3);
+        assert_eq!(cache.peek(&"a"), Some(1));
+        assert_eq!(cache.peek(&"b"), None);
+    }
+
+    #[test]
+    fn test_compare_and_swap_has_no_lost_updates() {
+        let cache = Arc::new(SyncLruCache::new(10));
+        cache.put("counter", 0);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        loop {
+                            let current = cache.peek(&"counter").unwrap();
+                            if cache.compare_and_swap("counter", &current, current + 1).is_ok() {
+                                break;
+                            }
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(cache.peek(&"counter"), Some(400));
+        assert_eq!(cache.put_if_absent("counter", 0), Some(400));
+    }
+
+    #[test]
+    fn test_get_guard_reads_without_clone() {
+        struct Large(Vec<u8>);
+
+        for cache in [
+            SyncLruCache::from_cache(LruCache::new(2)),
+            SyncLruCache::from_cache(LruCache::builder(2).buffered_reads().build()),
+        ] {
+            cache.write().put("a", Large(vec![1; 1024]));
+            cache.write().put("b", Large(vec![2; 1024]));
+            assert_eq!(cache.get_guard(&"a").map(|value| value.0[0]), Some(1));
+            assert!(cache.get_guard(&"z").is_none());
+            // La lecture a promu "a" : "b" est évincé.
+            cache.write().put("c", Large(Vec::new()));
+            assert!(cache.read().peek(&"a").is_some());
+            assert!(cache.read().peek(&"b").is_none());
+        }
+    }
+}
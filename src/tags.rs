@@ -0,0 +1,65 @@
+use std::hash::Hash;
+
+use crate::cache::{LruCache, WriteOptions};
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Insère ou met à jour une valeur étiquetée par `tags`, pour pouvoir
+    /// l'invalider ensuite avec [`invalidate_tag`](Self::invalidate_tag).
+    ///
+    /// Seule l'empreinte (hash) de chaque tag est conservée. Comme pour le
+    /// TTL, un `put` ultérieur sur la même clé efface les tags.
+    pub fn put_with_tags<T: Hash>(&mut self, key: K, value: V, tags: &[T]) {
+        let tags = tags.iter().map(|tag| self.index.hash(tag)).collect();
+        let options = WriteOptions {
+            expires_at: self.default_deadline(),
+            tags: Some(tags),
+            ..Default::default()
+        };
+        self.put_with_options(key, value, options);
+    }
+
+    /// Supprime toutes les entrées portant `tag` et retourne leur nombre.
+    ///
+    /// Les entrées retirées sont notifiées avec [`RemovalCause::Explicit`](crate::RemovalCause::Explicit).
+    ///
+    /// # Complexité
+    /// O(n) : toutes les entrées sont examinées. Les tags étant comparés par
+    /// empreinte 64 bits, une collision (improbable) invaliderait une entrée
+    /// de trop, jamais une de moins.
+    pub fn invalidate_tag<T: Hash + ?Sized>(&mut self, tag: &T) -> usize {
+        let hash = self.index.hash(tag);
+        self.remove_where(|node| node.tags.as_ref().is_some_and(|tags| tags.contains(&hash)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+
+    #[test]
+    fn test_invalidate_tag_removes_tagged_entries() {
+        let mut cache = LruCache::new(10);
+        cache.put_with_tags("SELECT * FROM users", 1, &["users"]);
+        cache.put_with_tags("SELECT * FROM orders JOIN users", 2, &["orders", "users"]);
+        cache.put_with_tags("SELECT * FROM orders", 3, &["orders"]);
+        cache.put("untagged", 4);
+
+        assert_eq!(cache.invalidate_tag("users"), 2);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&"SELECT * FROM orders"));
+        assert_eq!(cache.invalidate_tag("users"), 0);
+    }
+
+    #[test]
+    fn test_put_clears_tags() {
+        let mut cache = LruCache::new(2);
+        cache.put_with_tags("a", 1, &[42u32]);
+        cache.put("a", 2);
+        assert_eq!(cache.invalidate_tag(&42u32), 0);
+        assert_eq!(cache.peek(&"a"), Some(&2));
+    }
+}
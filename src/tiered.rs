@@ -0,0 +1,237 @@
+use std::fs;
+use std::hash::Hash;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::cache::{Cache, LruCache};
+use crate::codec::{DisplayCodec, ValueCodec};
+
+/// Extension des fichiers de valeurs du niveau disque.
+const ENTRY_EXTENSION: &str = "entry";
+
+/// Cache à deux niveaux : un [`LruCache`] en mémoire, adossé à un niveau
+/// LRU plus grand sur disque.
+///
+/// Une entrée évincée de la mémoire est rétrogradée sur disque (un fichier
+/// par valeur dans `dir`) ; une lecture qui la retrouve sur disque la
+/// promeut en mémoire. Le niveau disque évince à son tour ses entrées les
+/// plus anciennes au-delà de sa capacité.
+///
+/// L'index du niveau disque est en mémoire : le niveau disque ne survit pas
+/// au processus, et les fichiers restés dans `dir` sont supprimés à l'ouverture.
+pub struct TieredCache<K, V, C = DisplayCodec> {
+    memory: LruCache<K, V>,
+    /// Index du niveau disque : clé -> numéro du fichier de la valeur.
+    disk: LruCache<K, u64>,
+    dir: PathBuf,
+    codec: C,
+    next_file: u64,
+}
+
+impl<K, V> TieredCache<K, V>
+where
+    K: Hash + Eq,
+    DisplayCodec: ValueCodec<V>,
+{
+    /// Crée un cache de `memory_capacity` entrées en mémoire et
+    /// `disk_capacity` entrées sur disque, dans le répertoire `dir`.
+    pub fn new(memory_capacity: usize, disk_capacity: usize, dir: impl AsRef<Path>) -> io::Result<Self> {
+        Self::with_codec(memory_capacity, disk_capacity, dir, DisplayCodec)
+    }
+}
+
+impl<K, V, C> TieredCache<K, V, C>
+where
+    K: Hash + Eq,
+    C: ValueCodec<V>,
+{
+    /// Comme [`new`](TieredCache::new), avec un codec pour écrire les valeurs sur disque.
+    ///
+    /// # Panics
+    /// Panique si l'une des capacités est 0.
+    pub fn with_codec(
+        memory_capacity: usize,
+        disk_capacity: usize,
+        dir: impl AsRef<Path>,
+        codec: C,
+    ) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == ENTRY_EXTENSION) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(TieredCache {
+            memory: LruCache::new(memory_capacity),
+            disk: LruCache::new(disk_capacity),
+            dir,
+            codec,
+            next_file: 0,
+        })
+    }
+
+    fn file_path(&self, file: u64) -> PathBuf {
+        self.dir.join(format!("{file}.{ENTRY_EXTENSION}"))
+    }
+
+    /// Écrit une valeur sur disque, en évinçant au besoin l'entrée disque la plus ancienne.
+    fn demote(&mut self, key: K, value: &V) -> io::Result<()> {
+        let file = self.next_file;
+        self.next_file += 1;
+        fs::write(self.file_path(file), self.codec.encode_value(value))?;
+        if let Some(old) = self.disk.peek(&key).copied() {
+            remove_file(&self.file_path(old))?;
+        } else if self.disk.is_full() {
+            for (_, evicted) in self.disk.drain_lru(1) {
+                remove_file(&self.file_path(evicted))?;
+            }
+        }
+        self.disk.put(key, file);
+        Ok(())
+    }
+
+    /// Retire une valeur du disque et la décode.
+    fn take_from_disk(&mut self, key: &K) -> io::Result<Option<V>> {
+        let Some(file) = self.disk.remove(key) else {
+            return Ok(None);
+        };
+        let path = self.file_path(file);
+        let text = fs::read_to_string(&path)?;
+        remove_file(&path)?;
+        self.codec
+            .decode_value(&text)
+            .map(Some)
+            .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+    }
+
+    /// Insère en mémoire, en rétrogradant au besoin l'entrée mémoire la plus ancienne.
+    fn insert_in_memory(&mut self, key: K, value: V) -> io::Result<()> {
+        if self.memory.is_full() && !self.memory.contains(&key) {
+            for (old_key, old_value) in self.memory.drain_lru(1) {
+                self.demote(old_key, &old_value)?;
+            }
+        }
+        self.memory.put(key, value);
+        Ok(())
+    }
+
+    /// Insère une entrée en mémoire ; une ancienne version sur disque est supprimée.
+    ///
+    /// Échoue si l'entrée rétrogradée ne peut pas être écrite sur disque.
+    pub fn put(&mut self, key: K, value: V) -> io::Result<()> {
+        if let Some(file) = self.disk.remove(&key) {
+            remove_file(&self.file_path(file))?;
+        }
+        self.insert_in_memory(key, value)
+    }
+
+    /// Lit une valeur, en la promouvant en mémoire si elle était sur disque.
+    pub fn get(&mut self, key: &K) -> io::Result<Option<&V>>
+    where
+        K: Clone,
+    {
+        if !self.memory.contains(key) {
+            let Some(value) = self.take_from_disk(key)? else {
+                return Ok(None);
+            };
+            self.insert_in_memory(key.clone(), value)?;
+        }
+        Ok(self.memory.get(key))
+    }
+
+    /// Supprime une entrée des deux niveaux et retourne sa valeur.
+    pub fn remove(&mut self, key: &K) -> io::Result<Option<V>> {
+        match self.memory.remove(key) {
+            Some(value) => Ok(Some(value)),
+            None => self.take_from_disk(key),
+        }
+    }
+
+    /// Indique si la clé est présente dans l'un des niveaux.
+    pub fn contains(&self, key: &K) -> bool {
+        self.memory.contains(key) || self.disk.contains(key)
+    }
+
+    /// Indique si la clé est présente en mémoire.
+    pub fn in_memory(&self, key: &K) -> bool {
+        self.memory.contains(key)
+    }
+
+    /// Nombre total d'entrées (mémoire et disque).
+    pub fn len(&self) -> usize {
+        self.memory.len() + self.disk.len()
+    }
+
+    /// Indique si les deux niveaux sont vides.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Vide les deux niveaux et supprime les fichiers.
+    pub fn clear(&mut self) -> io::Result<()> {
+        self.memory.clear();
+        for (_, file) in self.disk.drain_lru(usize::MAX) {
+            remove_file(&self.file_path(file))?;
+        }
+        Ok(())
+    }
+
+    /// Niveau mémoire, en lecture seule.
+    pub fn memory(&self) -> &LruCache<K, V> {
+        &self.memory
+    }
+}
+
+/// Supprime un fichier ; un fichier déjà absent n'est pas une erreur.
+pub(crate) fn remove_file(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("lru_tiered_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_demotion_and_promotion() {
+        let dir = temp_dir("promote");
+        let mut cache: TieredCache<u32, String> = TieredCache::new(2, 10, &dir).unwrap();
+        for i in 0..5 {
+            cache.put(i, format!("valeur {i}")).unwrap();
+        }
+        assert_eq!(cache.len(), 5);
+        assert!(!cache.in_memory(&0));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 3);
+
+        assert_eq!(cache.get(&0).unwrap().map(String::as_str), Some("valeur 0"));
+        assert!(cache.in_memory(&0));
+        assert!(!cache.in_memory(&3));
+        assert_eq!(cache.remove(&1).unwrap().as_deref(), Some("valeur 1"));
+        assert_eq!(cache.len(), 4);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_disk_tier_is_bounded() {
+        let dir = temp_dir("bounded");
+        let mut cache: TieredCache<u32, u32> = TieredCache::new(1, 2, &dir).unwrap();
+        for i in 0..6 {
+            cache.put(i, i * 10).unwrap();
+        }
+        assert_eq!(cache.len(), 3);
+        assert!(!cache.contains(&2));
+        assert_eq!(cache.get(&3).unwrap(), Some(&30));
+        cache.clear().unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::cache::LruCache;
+use crate::listener::RemovalCause;
+
+/// Opération du cache décrite par un [`TraceEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// Lecture, réussie ou non.
+    Get {
+        /// `true` si la clé a été trouvée.
+        hit: bool,
+    },
+    /// Insertion ou mise à jour.
+    Put,
+    /// Retrait automatique d'une entrée (éviction ou expiration).
+    Evict(RemovalCause),
+    /// Sauvegarde sur disque.
+    Save {
+        /// Nombre d'entrées écrites.
+        entries: usize,
+    },
+}
+
+/// Événement d'instrumentation émis par un cache (voir [`TraceSink`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceEvent<'a> {
+    /// Opération concernée.
+    pub operation: Operation,
+    /// Représentation `Debug` de la clé, si activée via
+    /// [`trace_keys`](crate::LruCacheBuilder::trace_keys).
+    pub key: Option<&'a str>,
+    /// Durée de l'opération (absente pour les évictions, mesurées par l'opération qui les cause).
+    pub elapsed: Option<Duration>,
+}
+
+/// Destination des événements d'instrumentation.
+///
+/// Une implémentation relaie typiquement vers `tracing` :
+/// ```ignore
+/// struct Tracing;
+/// impl TraceSink for Tracing {
+///     fn record(&self, event: &TraceEvent<'_>) {
+///         tracing::debug!(operation = ?event.operation, key = event.key, elapsed = ?event.elapsed, "cache");
+///     }
+/// }
+/// ```
+pub trait TraceSink: Send + Sync {
+    /// Reçoit un événement. Appelé pendant l'opération : doit rester rapide.
+    fn record(&self, event: &TraceEvent<'_>);
+}
+
+/// Configuration de l'instrumentation d'un cache.
+pub(crate) struct Tracer<K> {
+    pub(crate) sink: Arc<dyn TraceSink>,
+    /// Formatage des clés (opt-in, exige `K: Debug`).
+    pub(crate) key_repr: Option<fn(&K) -> String>,
+}
+
+impl<K> Tracer<K> {
+    pub(crate) fn new(sink: Arc<dyn TraceSink>) -> Self {
+        Tracer {
+            sink,
+            key_repr: None,
+        }
+    }
+
+    pub(crate) fn with_keys(mut self) -> Self
+    where
+        K: Debug,
+    {
+        self.key_repr = Some(|key| format!("{key:?}"));
+        self
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Début de la mesure d'une opération (`None` si l'instrumentation est désactivée).
+    pub(crate) fn trace_start(&self) -> Option<Instant> {
+        self.tracer.as_ref().map(|_| Instant::now())
+    }
+
+    /// Émet un événement pour `operation`, avec sa durée si `started` est fourni.
+    pub(crate) fn trace(&self, operation: Operation, key: Option<&K>, started: Option<Instant>) {
+        let Some(tracer) = &self.tracer else {
+            return;
+        };
+        let key = key.zip(tracer.key_repr).map(|(key, repr)| repr(key));
+        tracer.sink.record(&TraceEvent {
+            operation,
+            key: key.as_deref(),
+            elapsed: started.map(|started| started.elapsed()),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cache;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Collector(Mutex<Vec<(Operation, Option<String>, bool)>>);
+
+    impl TraceSink for Collector {
+        fn record(&self, event: &TraceEvent<'_>) {
+            let key = event.key.map(str::to_owned);
+            self.0.lock().unwrap().push((event.operation, key, event.elapsed.is_some()));
+        }
+    }
+
+    #[test]
+    fn test_operations_are_traced_with_keys() {
+        let collector = Arc::new(Collector::default());
+        let mut cache = LruCache::builder(1).tracer(collector.clone()).trace_keys().build();
+        cache.put("a", 1);
+        cache.get(&"a");
+        cache.put("b", 2);
+        cache.get(&"a");
+
+        let key = |k: &str| Some(format!("{k:?}"));
+        assert_eq!(
+            *collector.0.lock().unwrap(),
+            vec![
+                (Operation::Put, key("a"), true),
+                (Operation::Get { hit: true }, key("a"), true),
+                (Operation::Evict(RemovalCause::Evicted), key("a"), false),
+                (Operation::Put, key("b"), true),
+                (Operation::Get { hit: false }, key("a"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keys_are_hidden_by_default() {
+        let collector = Arc::new(Collector::default());
+        let mut cache = LruCache::builder(1).tracer(collector.clone()).build();
+        cache.put("secret", 1);
+        assert_eq!(collector.0.lock().unwrap()[0].1, None);
+    }
+}
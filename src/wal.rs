@@ -0,0 +1,252 @@
+use std::fmt::{Debug, Display};
+use std::fs::{File, OpenOptions};
+use std::hash::Hash;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::str::FromStr;
+
+use crate::cache::{Cache, LruCache};
+use crate::persistence::{escape, split_record, unescape};
+
+/// Nombre d'opérations journalisées au-delà duquel [`WalCache`] compacte.
+pub const DEFAULT_COMPACTION_THRESHOLD: usize = 10_000;
+
+/// Cache persisté par journal d'écriture (*write-ahead log*).
+///
+/// Chaque `put`, `remove` ou `clear` est ajouté au journal `{path}.wal`
+/// avant d'être appliqué : une mise à jour coûte une ligne, et rien n'est
+/// perdu entre deux sauvegardes. Au-delà d'un seuil, le journal est compacté
+/// en un instantané `path` (format de [`LruCache::save_to_file`]) puis vidé.
+///
+/// À l'ouverture, l'instantané est chargé puis le journal rejoué. Les
+/// lectures ne sont pas journalisées : la récence rechargée est celle des
+/// écritures. Une dernière ligne incomplète (arrêt pendant l'écriture) est ignorée.
+pub struct WalCache<K, V> {
+    cache: LruCache<K, V>,
+    path: String,
+    log: BufWriter<File>,
+    /// Opérations présentes dans le journal.
+    records: usize,
+    compaction_threshold: usize,
+}
+
+impl<K, V> WalCache<K, V>
+where
+    K: Hash + Eq + Display + FromStr,
+    V: Display + FromStr,
+    <K as FromStr>::Err: Debug,
+    <V as FromStr>::Err: Debug,
+{
+    /// Ouvre (ou crée) le cache persisté dans `path` et son journal.
+    ///
+    /// La capacité enregistrée dans l'instantané, s'il existe, remplace `capacity`.
+    pub fn open(capacity: usize, path: &str) -> io::Result<Self> {
+        let mut cache = LruCache::new_persistent(capacity, path)?;
+        let log_path = Self::log_path(path);
+        let (records, valid_len) = match File::open(&log_path) {
+            Ok(file) => Self::replay(&mut cache, BufReader::new(file))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (0, 0),
+            Err(err) => return Err(err),
+        };
+        let log = OpenOptions::new().create(true).append(true).open(&log_path)?;
+        // Retire une ligne incomplète, qui corromprait la suivante.
+        if log.metadata()?.len() > valid_len {
+            log.set_len(valid_len)?;
+        }
+        let mut wal = WalCache {
+            cache,
+            path: path.to_owned(),
+            log: BufWriter::new(log),
+            records,
+            compaction_threshold: DEFAULT_COMPACTION_THRESHOLD,
+        };
+        if wal.records > wal.compaction_threshold {
+            wal.compact()?;
+        }
+        Ok(wal)
+    }
+
+    /// Compacte le journal dès qu'il dépasse `records` opérations.
+    pub fn with_compaction_threshold(mut self, records: usize) -> Self {
+        self.compaction_threshold = records;
+        self
+    }
+
+    fn log_path(path: &str) -> String {
+        format!("{path}.wal")
+    }
+
+    /// Rejoue les opérations du journal. Retourne leur nombre et la
+    /// longueur en octets des lignes complètes.
+    fn replay(cache: &mut LruCache<K, V>, mut reader: impl BufRead) -> io::Result<(usize, u64)> {
+        let (mut records, mut valid_len) = (0, 0);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            // Ligne sans fin : écriture interrompue, donc jamais appliquée.
+            let Some(record) = line.strip_suffix('\n') else {
+                return Ok((records, valid_len));
+            };
+            valid_len += read as u64;
+            let mut chars = record.chars();
+            match chars.next() {
+                Some('+') => {
+                    let parsed = split_record(chars.as_str()).and_then(|(key, value)| {
+                        Some((K::from_str(&key).ok()?, V::from_str(&value).ok()?))
+                    });
+                    if let Some((key, value)) = parsed {
+                        cache.put(key, value);
+                    }
+                }
+                Some('-') => {
+                    if let Ok(key) = K::from_str(&unescape(chars.as_str())) {
+                        cache.remove(&key);
+                    }
+                }
+                Some('*') => cache.clear(),
+                _ => continue,
+            }
+            records += 1;
+        }
+    }
+
+    /// Ajoute une opération au journal et la transmet au système.
+    fn append(&mut self, record: &str) -> io::Result<()> {
+        writeln!(self.log, "{record}")?;
+        self.log.flush()?;
+        self.records += 1;
+        Ok(())
+    }
+
+    /// Compacte si le journal a dépassé son seuil.
+    fn maybe_compact(&mut self) -> io::Result<()> {
+        if self.records > self.compaction_threshold {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /// Journalise puis insère `value` sous `key`.
+    ///
+    /// En cas d'erreur d'écriture du journal, le cache n'est pas modifié.
+    pub fn put(&mut self, key: K, value: V) -> io::Result<()> {
+        let mut record = String::from("+");
+        escape(&key.to_string(), &mut record);
+        record.push('=');
+        escape(&value.to_string(), &mut record);
+        self.append(&record)?;
+        self.cache.put(key, value);
+        self.maybe_compact()
+    }
+
+    /// Journalise puis supprime `key`.
+    ///
+    /// Une clé absente n'est pas journalisée.
+    pub fn remove(&mut self, key: &K) -> io::Result<Option<V>> {
+        if !self.cache.contains(key) {
+            return Ok(None);
+        }
+        let mut record = String::from("-");
+        escape(&key.to_string(), &mut record);
+        self.append(&record)?;
+        let removed = self.cache.remove(key);
+        self.maybe_compact()?;
+        Ok(removed)
+    }
+
+    /// Journalise puis vide le cache.
+    pub fn clear(&mut self) -> io::Result<()> {
+        self.append("*")?;
+        self.cache.clear();
+        self.maybe_compact()
+    }
+
+    /// Lit une valeur en cache (voir [`Cache::get`]).
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Écrit l'état courant dans l'instantané (atomiquement), puis vide le journal.
+    ///
+    /// Un arrêt entre les deux laisse un journal déjà inclus dans
+    /// l'instantané : le rejouer redonne le même contenu.
+    pub fn compact(&mut self) -> io::Result<()> {
+        self.log.flush()?;
+        self.cache.save_to_file(&self.path)?;
+        let log = File::create(Self::log_path(&self.path))?;
+        log.sync_all()?;
+        self.log = BufWriter::new(log);
+        self.records = 0;
+        Ok(())
+    }
+
+    /// Force l'écriture du journal sur disque (`fsync`).
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.log.flush()?;
+        self.log.get_ref().sync_data()
+    }
+
+    /// Nombre d'opérations dans le journal depuis le dernier compactage.
+    pub fn log_len(&self) -> usize {
+        self.records
+    }
+
+    /// Cache sous-jacent, en lecture seule : toute écriture doit être journalisée.
+    pub fn cache(&self) -> &LruCache<K, V> {
+        &self.cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn temp_path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("lru_wal_{name}_{}.txt", std::process::id()));
+        path.to_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn test_replay_after_restart() {
+        let path = temp_path("replay");
+        {
+            let mut wal: WalCache<String, u32> = WalCache::open(3, &path).unwrap();
+            wal.put("a=b".to_string(), 1).unwrap();
+            wal.put("c".to_string(), 2).unwrap();
+            wal.remove(&"c".to_string()).unwrap();
+            wal.put("d".to_string(), 3).unwrap();
+        }
+        // Écriture interrompue : la ligne incomplète est ignorée.
+        let mut log = OpenOptions::new().append(true).open(format!("{path}.wal")).unwrap();
+        log.write_all(b"+e=4").unwrap();
+
+        let mut wal: WalCache<String, u32> = WalCache::open(3, &path).unwrap();
+        assert_eq!(wal.log_len(), 4);
+        assert_eq!(wal.cache().to_vec(), vec![("a=b".to_string(), 1), ("d".to_string(), 3)]);
+        wal.put("f".to_string(), 5).unwrap();
+        drop(wal);
+        let wal: WalCache<String, u32> = WalCache::open(3, &path).unwrap();
+        assert_eq!(wal.cache().peek(&"f".to_string()), Some(&5));
+        assert!(!wal.cache().contains(&"e".to_string()));
+        fs::remove_file(format!("{path}.wal")).unwrap();
+    }
+
+    #[test]
+    fn test_compaction_truncates_log() {
+        let path = temp_path("compact");
+        let mut wal: WalCache<u32, u32> = WalCache::open(10, &path).unwrap().with_compaction_threshold(5);
+        for i in 0..12 {
+            wal.put(i % 4, i).unwrap();
+        }
+        assert!(wal.log_len() <= 5);
+        assert!(fs::metadata(&path).is_ok());
+        drop(wal);
+
+        let wal: WalCache<u32, u32> = WalCache::open(10, &path).unwrap();
+        assert_eq!(wal.cache().to_vec(), vec![(0, 8), (1, 9), (2, 10), (3, 11)]);
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(format!("{path}.wal")).unwrap();
+    }
+}
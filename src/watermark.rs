@@ -0,0 +1,55 @@
+use std::hash::Hash;
+
+use crate::cache::{Cache, LruCache};
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Libère au moins une place avant une insertion dans un cache plein :
+    /// une seule entrée, ou jusqu'au niveau bas si
+    /// [`low_watermark`](crate::LruCacheBuilder::low_watermark) est configuré.
+    ///
+    /// Si tout est épinglé, le cache dépasse temporairement sa capacité.
+    pub(crate) fn make_room(&mut self) {
+        if self.arena.len() < self.capacity() {
+            return;
+        }
+        let target = self
+            .low_watermark
+            .map_or(self.capacity(), |low| low.min(self.capacity()))
+            .max(1);
+        while self.arena.len() >= target && self.remove_lru() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, LruCache};
+
+    #[test]
+    fn test_evicts_down_to_low_watermark() {
+        let mut cache = LruCache::builder(10).low_watermark(0.7).build();
+        for key in 0..10u32 {
+            cache.put(key, ());
+        }
+        assert_eq!(cache.len(), 10);
+        cache.put(10, ());
+        // Plein : les 4 plus anciennes sortent d'un coup, il reste 7 entrées.
+        assert_eq!(cache.len(), 7);
+        assert!(!cache.contains(&3));
+        assert!(cache.contains(&4));
+        for key in 11..14u32 {
+            cache.put(key, ());
+        }
+        assert_eq!(cache.len(), 10);
+        assert!(cache.contains(&4));
+    }
+
+    #[test]
+    fn test_tiny_cache_keeps_one_entry() {
+        let mut cache = LruCache::builder(2).low_watermark(0.1).build();
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.put("c", 3);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains(&"c"));
+    }
+}
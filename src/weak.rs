@@ -0,0 +1,130 @@
+use std::hash::Hash;
+use std::sync::{Arc, Weak};
+
+use crate::cache::{Cache, LruCache};
+
+/// Cache de déduplication qui ne retient pas ses valeurs en mémoire.
+///
+/// Les valeurs sont stockées sous forme de `Weak<V>` : une entrée reste
+/// utilisable tant qu'un `Arc<V>` existe ailleurs, et devient morte quand
+/// le dernier est relâché. Les entrées mortes sont retirées paresseusement :
+/// lors d'une lecture qui les rencontre, et avant toute éviction (une entrée
+/// morte libère sa place avant qu'une entrée vivante ne soit évincée).
+///
+/// # Exemple
+/// ```
+/// use std::sync::Arc;
+/// use cache_lru_project::WeakValueCache;
+///
+/// let mut cache = WeakValueCache::new(100);
+/// let a = cache.get_or_insert_with("config", || vec![0u8; 1024]);
+/// let b = cache.get_or_insert_with("config", || unreachable!());
+/// assert!(Arc::ptr_eq(&a, &b));
+/// drop((a, b));
+/// assert_eq!(cache.get(&"config"), None);
+/// ```
+pub struct WeakValueCache<K, V> {
+    inner: LruCache<K, Weak<V>>,
+}
+
+impl<K: Hash + Eq, V> WeakValueCache<K, V> {
+    /// Crée un cache vide d'au plus `capacity` entrées (mortes comprises).
+    ///
+    /// # Panics
+    /// Panique si `capacity` est 0.
+    pub fn new(capacity: usize) -> Self {
+        WeakValueCache {
+            inner: LruCache::new(capacity),
+        }
+    }
+
+    /// Retourne la valeur de `key` si elle est encore référencée ailleurs,
+    /// et la marque comme récente. Une entrée morte est retirée.
+    pub fn get(&mut self, key: &K) -> Option<Arc<V>> {
+        let value = self.inner.get(key)?.upgrade();
+        if value.is_none() {
+            self.inner.remove(key);
+        }
+        value
+    }
+
+    /// Enregistre `value` sous `key`, sans en prolonger la durée de vie.
+    pub fn insert(&mut self, key: K, value: &Arc<V>) {
+        if self.inner.is_full() && !self.inner.contains(&key) {
+            self.purge();
+        }
+        self.inner.put(key, Arc::downgrade(value));
+    }
+
+    /// Retourne la valeur vivante de `key`, ou crée-la avec `make` et
+    /// l'enregistre : tous les appelants partagent alors le même `Arc`.
+    pub fn get_or_insert_with(&mut self, key: K, make: impl FnOnce() -> V) -> Arc<V> {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = Arc::new(make());
+        self.insert(key, &value);
+        value
+    }
+
+    /// Retire `key` et retourne sa valeur si elle était encore vivante.
+    pub fn remove(&mut self, key: &K) -> Option<Arc<V>> {
+        self.inner.remove(key)?.upgrade()
+    }
+
+    /// Retire toutes les entrées mortes et retourne leur nombre.
+    pub fn purge(&mut self) -> usize {
+        self.inner.remove_where(|node| node.value.strong_count() == 0)
+    }
+
+    /// Nombre d'entrées, mortes pas encore retirées comprises.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Nombre d'entrées dont la valeur est encore vivante (parcours complet).
+    pub fn live_count(&self) -> usize {
+        self.inner
+            .iter()
+            .filter(|(_, value)| value.strong_count() > 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dead_entries_make_room_before_eviction() {
+        let mut cache = WeakValueCache::new(2);
+        let kept = cache.get_or_insert_with(1, || "un".to_string());
+        let dropped = cache.get_or_insert_with(2, || "deux".to_string());
+        drop(dropped);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.live_count(), 1);
+
+        // L'entrée 1 est la plus ancienne, mais l'entrée morte 2 part d'abord.
+        let three = Arc::new("trois".to_string());
+        cache.insert(3, &three);
+        assert!(Arc::ptr_eq(&cache.get(&1).unwrap(), &kept));
+        assert_eq!(cache.remove(&3).as_deref().map(String::as_str), Some("trois"));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_get_reaps_dead_entry() {
+        let mut cache = WeakValueCache::new(4);
+        let value = Arc::new(5);
+        cache.insert("k", &value);
+        assert_eq!(cache.get(&"k"), Some(Arc::clone(&value)));
+        drop(value);
+        assert_eq!(cache.get(&"k"), None);
+        assert!(cache.is_empty());
+        assert_eq!(cache.purge(), 0);
+    }
+}
@@ -0,0 +1,170 @@
+use std::any::Any;
+use std::hash::Hash;
+
+use crate::cache::LruCache;
+
+/// Calcule le poids d'une entrée (ex: sa taille en octets), pour borner le
+/// cache par poids total plutôt que par nombre d'entrées.
+///
+/// Implémenté automatiquement pour toute closure `Fn(&K, &V) -> u32`.
+pub trait Weigher<K, V>: Send + Sync {
+    /// Poids de l'entrée. Doit rester stable tant que l'entrée n'est pas réécrite.
+    fn weight(&self, key: &K, value: &V) -> u32;
+}
+
+impl<K, V, F> Weigher<K, V> for F
+where
+    F: Fn(&K, &V) -> u32 + Send + Sync,
+{
+    fn weight(&self, key: &K, value: &V) -> u32 {
+        self(key, value)
+    }
+}
+
+/// [`Weigher`] rangé sans ses types de clé et de valeur : un
+/// `Box<dyn Weigher<K, V>>` obligerait des clés empruntées à survivre au
+/// cache (dropck), alors qu'un pointeur de fonction n'a pas de destructeur.
+pub(crate) struct BoxedWeigher<K, V> {
+    weigher: Box<dyn Any + Send + Sync>,
+    weight: fn(&(dyn Any + Send + Sync), &K, &V) -> u32,
+}
+
+impl<K, V> BoxedWeigher<K, V> {
+    pub(crate) fn new<W: Weigher<K, V> + 'static>(weigher: W) -> Self {
+        BoxedWeigher {
+            weigher: Box::new(weigher),
+            weight: |weigher, key, value| weigher.downcast_ref::<W>().map_or(1, |weigher| weigher.weight(key, value)),
+        }
+    }
+
+    pub(crate) fn weight(&self, key: &K, value: &V) -> u32 {
+        (self.weight)(&*self.weigher, key, value)
+    }
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Somme des poids des entrées présentes (leur nombre, sans `Weigher`).
+    pub fn total_weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    /// Poids total maximal configuré via [`max_weight`](crate::LruCacheBuilder::max_weight).
+    pub fn max_weight(&self) -> Option<u64> {
+        self.max_weight
+    }
+
+    /// Poids d'une entrée selon le `Weigher` configuré.
+    pub(crate) fn weigh(&self, key: &K, value: &V) -> u32 {
+        self.weigher.as_ref().map_or(1, |weigher| weigher.weight(key, value))
+    }
+
+    /// Indique si l'entrée pèse à elle seule plus que le maximum : elle ne
+    /// peut pas être gardée, et est refusée avant toute éviction.
+    pub(crate) fn is_oversize(&self, key: &K, value: &V) -> bool {
+        self.max_weight
+            .is_some_and(|max_weight| u64::from(self.weigh(key, value)) > max_weight)
+    }
+
+    /// Évince depuis la Queue (hors entrées épinglées) jusqu'à ce que le poids total tienne sous le maximum.
+    ///
+    /// Les entrées trop lourdes à elles seules (voir [`is_oversize`](Self::is_oversize))
+    /// ne sont jamais écrites : elles ne vident donc pas le cache.
+    pub(crate) fn evict_overweight(&mut self) {
+        let Some(max_weight) = self.max_weight else {
+            return;
+        };
+        while self.total_weight > max_weight && self.remove_lru() {}
+    }
+
+    /// Comme [`evict_overweight`](Self::evict_overweight), mais s'arrête
+    /// avant d'évincer l'entrée `kept` : retourne son emplacement (suivi à
+    /// travers les `swap_remove`), en `Err` si elle est la prochaine
+    /// victime alors que le poids dépasse encore le maximum.
+    pub(crate) fn evict_overweight_keeping(&mut self, mut kept: usize) -> Result<usize, usize> {
+        let Some(max_weight) = self.max_weight else {
+            return Ok(kept);
+        };
+        while self.total_weight > max_weight {
+            let Some(victim) = self.next_victim() else {
+                break;
+            };
+            if victim == kept {
+                return Err(kept);
+            }
+            self.evict_victim(victim);
+            // `swap_remove` a déplacé la dernière entrée de l'arena à `victim`.
+            if kept == self.arena.len() {
+                kept = victim;
+            }
+        }
+        Ok(kept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cache, LruCache};
+
+    #[test]
+    fn test_eviction_by_weight() {
+        let mut cache = LruCache::builder(100)
+            .max_weight(10, |_: &&str, v: &String| v.len() as u32)
+            .build();
+        cache.put("a", "xxxx".to_string());
+        cache.put("b", "xxxx".to_string());
+        assert_eq!(cache.total_weight(), 8);
+
+        // 8 + 6 > 10 : "a" (LRU) sort, puis le total tient.
+        cache.put("c", "xxxxxx".to_string());
+        assert_eq!(cache.total_weight(), 10);
+        assert!(!cache.contains(&"a"));
+
+        // Une mise à jour plus lourde évince aussi.
+        cache.put("c", "xxxxxxxx".to_string());
+        assert_eq!(cache.total_weight(), 8);
+        assert_eq!(cache.len(), 1);
+
+        // Trop lourde pour le cache : refusée de fait.
+        cache.put("d", "x".repeat(11));
+        assert!(!cache.contains(&"d"));
+    }
+
+    #[test]
+    fn test_oversize_put_keeps_other_entries() {
+        let mut cache = LruCache::builder(100)
+            .max_weight(10, |_: &&str, v: &String| v.len() as u32)
+            .build();
+        cache.put("a", "xxxx".to_string());
+        cache.put("b", "xxxx".to_string());
+
+        // Refusée avant toute éviction : "a" et "b" restent.
+        cache.put("big", "x".repeat(11));
+        assert!(!cache.contains(&"big"));
+        assert!(cache.contains(&"a") && cache.contains(&"b"));
+        assert_eq!(cache.total_weight(), 8);
+
+        // Sur une clé présente, seule l'ancienne valeur part.
+        cache.put("a", "x".repeat(11));
+        assert!(!cache.contains(&"a") && cache.contains(&"b"));
+
+        // Idem pour une valeur qui grossit sur place.
+        cache.put("c", "xx".to_string());
+        assert!(cache.modify(&"c", |v| v.push_str(&"x".repeat(9))));
+        assert!(!cache.contains(&"c") && cache.contains(&"b"));
+        assert_eq!(cache.total_weight(), 4);
+        assert_eq!(cache.check_consistency(), Ok(()));
+    }
+
+    #[test]
+    fn test_default_weight_counts_entries() {
+        let mut cache = LruCache::new(3);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        cache.remove(&1);
+        assert_eq!(cache.total_weight(), 1);
+        assert_eq!(cache.max_weight(), None);
+    }
+}
@@ -0,0 +1,130 @@
+use std::time::{Duration, Instant};
+
+/// Nombre de buckets par niveau (puissance de 2).
+const SLOTS: usize = 64;
+const SLOT_BITS: u32 = 6;
+/// Nombre de niveaux : 64^4 ticks couverts avant la liste de débordement.
+const LEVELS: usize = 4;
+
+/// Roue temporelle hiérarchique (à la Varghese & Lauck).
+///
+/// # Architecture
+/// * **Niveaux** : `LEVELS` roues de `SLOTS` buckets ; le niveau `n` a une
+///   granularité de `64^n` ticks.
+/// * **Cascade** : Quand le niveau 0 fait un tour complet, le bucket courant
+///   du niveau supérieur est redistribué vers les niveaux inférieurs.
+/// * **Débordement** : Les échéances au-delà de la dernière roue attendent
+///   dans une liste, reprogrammée à chaque tour du dernier niveau.
+///
+/// Programmer et faire avancer la roue coûte O(1) amorti par élément, quel que
+/// soit le nombre d'échéances en attente.
+pub(crate) struct TimerWheel {
+    origin: Instant,
+    resolution: Duration,
+    current: u64,
+    levels: Vec<Vec<Vec<(u64, u64)>>>,
+    overflow: Vec<(u64, u64)>,
+}
+
+impl TimerWheel {
+    /// Crée une roue démarrant à `origin`, avec des ticks de `resolution`.
+    pub(crate) fn new(origin: Instant, resolution: Duration) -> Self {
+        TimerWheel {
+            origin,
+            resolution: resolution.max(Duration::from_millis(1)),
+            current: 0,
+            levels: (0..LEVELS).map(|_| vec![Vec::new(); SLOTS]).collect(),
+            overflow: Vec::new(),
+        }
+    }
+
+    /// Tick correspondant à un instant, arrondi au tick supérieur (`ceil`)
+    /// pour une échéance, ou inférieur pour l'instant présent.
+    fn tick_of(&self, at: Instant, ceil: bool) -> u64 {
+        let elapsed = at.saturating_duration_since(self.origin).as_nanos();
+        let res = self.resolution.as_nanos();
+        let tick = if ceil { elapsed.div_ceil(res) } else { elapsed / res };
+        u64::try_from(tick).unwrap_or(u64::MAX)
+    }
+
+    /// Programme `item` pour l'instant `at` (jamais déclenché avant `at`).
+    pub(crate) fn schedule(&mut self, at: Instant, item: u64) {
+        let tick = self.tick_of(at, true).max(self.current + 1);
+        self.place(tick, item);
+    }
+
+    fn place(&mut self, tick: u64, item: u64) {
+        let delta = tick - self.current;
+        for level in 0..LEVELS {
+            let span = 1u64 << (SLOT_BITS * (level as u32 + 1));
+            if delta < span {
+                let bucket = ((tick >> (SLOT_BITS * level as u32)) as usize) & (SLOTS - 1);
+                self.levels[level][bucket].push((tick, item));
+                return;
+            }
+        }
+        self.overflow.push((tick, item));
+    }
+
+    /// Fait avancer la roue jusqu'à `now` et ajoute à `fired` les éléments échus.
+    pub(crate) fn advance(&mut self, now: Instant, fired: &mut Vec<u64>) {
+        let target = self.tick_of(now, false);
+        while self.current < target {
+            self.current += 1;
+            self.cascade();
+            let bucket = (self.current as usize) & (SLOTS - 1);
+            for (_, item) in self.levels[0][bucket].drain(..) {
+                fired.push(item);
+            }
+        }
+    }
+
+    /// Redistribue les niveaux supérieurs quand les niveaux inférieurs bouclent.
+    fn cascade(&mut self) {
+        for level in 1..LEVELS {
+            let mask = (1u64 << (SLOT_BITS * level as u32)) - 1;
+            if self.current & mask != 0 {
+                return;
+            }
+            let bucket = ((self.current >> (SLOT_BITS * level as u32)) as usize) & (SLOTS - 1);
+            let pending = std::mem::take(&mut self.levels[level][bucket]);
+            for (tick, item) in pending {
+                self.place(tick.max(self.current), item);
+            }
+        }
+        if self.current & ((1u64 << (SLOT_BITS * LEVELS as u32)) - 1) == 0 {
+            for (tick, item) in std::mem::take(&mut self.overflow) {
+                self.place(tick.max(self.current), item);
+            }
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        for level in &mut self.levels {
+            level.iter_mut().for_each(Vec::clear);
+        }
+        self.overflow.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fires_on_time_across_levels() {
+        let origin = Instant::now();
+        let mut wheel = TimerWheel::new(origin, Duration::from_millis(1));
+        // Niveau 0, niveau 1 (cascade), niveau 2 (double cascade).
+        for (item, ms) in [(1, 10), (2, 100), (3, 5_000)] {
+            wheel.schedule(origin + Duration::from_millis(ms), item);
+        }
+        let mut fired = Vec::new();
+        wheel.advance(origin + Duration::from_millis(99), &mut fired);
+        assert_eq!(fired, vec![1]);
+        wheel.advance(origin + Duration::from_millis(4_999), &mut fired);
+        assert_eq!(fired, vec![1, 2]);
+        wheel.advance(origin + Duration::from_millis(5_000), &mut fired);
+        assert_eq!(fired, vec![1, 2, 3]);
+    }
+}